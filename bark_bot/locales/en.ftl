@@ -0,0 +1,533 @@
+# Messages of the bot in English, every other catalog falls back to these.
+# Values marked as HTML go into messages sent with Telegram's HTML parse mode.
+
+language-name = English
+
+## Formatting
+
+number-group-separator = ,
+number-decimal-separator = .
+# chrono format of the times shown in proposals
+# Durations shown in reminders, limits and /pending
+duration-minutes = { $minutes }m
+duration-hours = { $hours }h { $minutes }m
+duration-days = { $days }d { $hours }h
+date-time-format = %d %b %H:%M UTC
+
+## /language
+
+language-current = This chat uses { $language }. Available: { $languages }
+language-following-members = This chat follows the language of each member's Telegram app ({ $language } for you). Available: { $languages }
+language-unknown = There is no { $language } translation. Available: { $languages }
+language-set = This chat now uses { $language }.
+language-reset = This chat now follows the language of each member's Telegram app.
+language-admin-only = Only admins can change the language of a group.
+
+## /start
+
+welcome =
+    Welcome to the BARK Bot!
+
+    BARK Bot simplifies multi-signature transactions and integrates with Dialect's Blink protocol for seamless treasury management and decision-making. Here's what you can do:
+
+    - Propose a transaction by sending a Blink URL
+    - Approve, reject or execute proposals with /approve, /reject and /execute
+    - Check a proposal with /status
+    - Pick the language of the chat with /language
+
+    Type /help to learn more about the commands available.
+shared-link-invalid = This Blink link is not valid anymore.
+
+## Proposing Blinks
+
+no-proposer-role = You don't have permission to propose transactions.
+send-blink-url = Please, share with me a valid Blink URL
+send-valid-action = Send me a valid action.
+send-plain-text = Send me plain text.
+choose-action = Choose an action to perform:
+processing-blink = Processing blink...
+blink-error = Error getting blink
+# HTML, $parameters is the list of parameters with a blank line after each one
+action-parameters =
+    The action <b>{ $action }</b> has the following params:
+
+    { $parameters }Please enter the values as they are requested.
+parameter-required = Required
+parameter-optional = Optional
+policy-refused = The proposal “{ $title }” was refused by the treasury policy
+proposal-not-sent = The proposal “{ $title }” could not be sent to the chain
+prepared-by-another-member = This proposal is being prepared by another member.
+receipt-sent = Transaction sent!
+# HTML, $parameters has a line per value
+confirm-proposal =
+    This link proposes <b>{ $title }</b> with your approval:
+
+    { $url }
+
+    { $parameters }Check the values before confirming.
+button-confirm-proposal = ✅ Confirm
+button-discard-proposal = Cancel
+proposal-discarded = The proposal was not created.
+confirm-or-cancel = Confirm or cancel the proposal with the buttons above.
+
+## Blink previews in the group
+
+propose-here = Propose here
+continue-in-dm = Continue in DM
+unfurl-link-gone = The original message with the link is gone.
+unfurl-group-busy = Another proposal is being prepared in this group, continue in DM instead.
+unfurl-load-failed = The Blink could not be loaded.
+unfurl-open-private-chat = Open a private chat with me and press Start, then try again.
+unfurl-pick-action = Pick an action below the preview.
+unfurl-continue-in-dm = Continue in our private chat.
+
+## Unfinished requests
+
+dialogue-expired = Your previous request was cancelled after { $duration } without an answer.
+dialogue-outdated = The bot was updated and your previous request could not be resumed, please start it again.
+
+## Proposal message (HTML)
+
+proposal-title = <b>Transaction request Nº{ $index }:</b>
+proposal-untitled = Untitled
+proposal-batch = <b>Batch { $position } of { $total }:</b> { $proposals }
+proposal-transfers = <b>Transfers</b>
+proposal-first-payment = 🆕 first payment
+proposal-lookalike = ⚠️ Looks like <b>{ $label }</b> ({ $address }) but is a different address
+proposal-proposed-by = <b>Proposed by:</b> { $name } · { $link }
+proposal-creation-tx = creation tx
+proposal-status = <b>Status:</b> { $state }
+proposal-expired = expired
+proposal-executed-in = <b>Executed in:</b> { $link }
+proposal-approved = <b>Approved ({ $count }/{ $threshold }):</b>
+proposal-rejected = <b>Rejected ({ $count }/{ $threshold }):</b>
+# HTML, $programs lists the program addresses
+proposal-policy-unchecked = ℹ️ Limits and approval rules only see direct SOL and token transfers, funds moved by { $programs } are not counted.
+proposal-policy = ⚠️ <b>Policy</b>
+proposal-timeline = <b>Timeline</b>
+proposal-failed = ⚠️ <b>Failed:</b> { $error }
+proposal-by = by { $name }
+proposal-user = user { $user_id }
+
+event-create = Created
+event-approve = Approved
+event-reject = Rejected
+event-execute = Executed
+event-cancel = Cancelled
+event-expired = Expired
+
+state-draft = Draft
+state-active = Active
+state-execute_ready = Ready to execute
+state-executed = Executed
+state-rejected = Rejected
+state-cancelled = Cancelled
+
+## Voting
+
+button-approve = Approve { $count }/{ $threshold }
+button-reject = Reject { $count }/{ $threshold }
+button-cancel = Cancel { $count }/{ $threshold }
+button-execute = Execute
+button-policy-blocked = ⛔ Blocked by policy
+button-invalid = This button is not valid.
+button-stale = This button was too old and has been refreshed, please try again.
+action-in-progress = Another action on this transaction is in progress, please wait.
+processing-request = Processing request...
+progress =
+    { $action ->
+        [Approve] Approving…
+        [Reject] Rejecting…
+        [Execute] Executing…
+       *[Cancel] Cancelling…
+    } ({ $name })
+action-failed =
+    { $action ->
+        [Approve] Approve
+        [Reject] Reject
+        [Execute] Execute
+       *[Cancel] Cancel
+    } by { $name }: { $error }
+unknown-action = Unknown action: { $action }
+role-required = You need the { $role } role to do this.
+not-a-signer = You are not a signer of this multisig.
+already-voted =
+    { $action ->
+        [Approve] You already voted to approve this transaction.
+        [Reject] You already voted to reject this transaction.
+       *[Cancel] You already voted to cancel this transaction.
+    }
+policy-blocked = Blocked by policy: { $reasons }
+policy-blocked-program = Program { $program } is blocked
+policy-missing-approvals = Transfers above { $above } { $token } need approval from { $approvers }
+policy-limit-exceeded = { $total } { $token } would exceed the limit of { $max } { $token } { $period }
+policy-limit-exceeded-new-recipients = { $total } { $token } would exceed the limit of { $max } { $token } to new recipients { $period }
+policy-per-proposal = per proposal
+policy-per-day = per day
+policy-per-week = per week
+policy-per-duration = per { $duration }
+policy-lock-timeout = The policy check is busy or unreachable, try again in a moment
+transaction-command-usage = Usage: /{ $command } <proposal number>
+proposal-not-found = There is no proposal Nº{ $index }.
+# HTML
+action-done =
+    { $action ->
+        [Approve] Approved
+        [Reject] Rejected
+        [Execute] Executed
+       *[Cancel] Cancelled
+    } proposal Nº{ $index }: <a href="{ $url }">transaction</a>
+# HTML
+action-command-failed =
+    Failed to { $action ->
+        [Approve] approve
+        [Reject] reject
+        [Execute] execute
+       *[Cancel] cancel
+    } proposal Nº{ $index }: { $error }
+view-proposal = view
+
+## Reminders
+
+# HTML, $link is empty or a link to the proposal after a blank line
+reminder-direct = ⏰ Transaction Nº{ $index } has been waiting for your vote for { $age }.{ $link }
+reminder-open-request = Open the request
+# HTML, $members mentions the members that haven't voted
+reminder-group = ⏰ Transaction Nº{ $index } has been open for { $age }. Still waiting for votes from: { $members }
+expiry-status = ⌛ Transaction Nº{ $index } expired after { $age } without being executed.
+expiry-vote-cancel = ⌛ Transaction Nº{ $index } expired after { $age } without being executed. Members can now vote to cancel it.
+expiry-cancel-voted = ⌛ Transaction Nº{ $index } expired after { $age } without being executed. The bot voted to cancel it, members can add their votes.
+expiry-reject-voted = ⌛ Transaction Nº{ $index } expired after { $age } without reaching the threshold. The bot voted to reject it, members can add their votes.
+expiry-vote-reject = ⌛ Transaction Nº{ $index } expired after { $age } without reaching the threshold. Members are asked to reject it.
+
+## /pending
+
+# HTML
+pending-title = <b>Pending proposals</b>
+pending-none = There are no pending proposals.
+pending-expired = ⌛ expired
+# HTML, $expired and $link are empty unless the proposal expired or its message is known
+pending-proposal =
+    <b>Nº{ $index }</b> { $title }
+    ✅ { $approved }/{ $threshold } · ❌ { $rejected }/{ $threshold } · { $state } · open for { $duration }{ $expired }{ $link }
+
+## /status
+
+# HTML, $approvers and $rejecters list the names or show a dash
+status-proposal =
+    <b>Nº{ $index }</b> { $title }
+    { $state }
+
+    ✅ { $approved }/{ $threshold }: { $approvers }
+    ❌ { $rejected }/{ $threshold }: { $rejecters }{ $link }
+status-view-proposal = View the proposal
+
+## /history
+
+history-usage =
+    { $error }
+
+    Usage: /history [status=executed] [proposer=me|<user id>] [from=YYYY-MM-DD] [to=YYYY-MM-DD]
+history-invalid-filter = Invalid filter: { $value }
+history-unknown-status = Unknown status: { $value }
+history-invalid-proposer = Invalid proposer: { $value }
+history-invalid-date = Invalid date: { $value }
+history-unknown-filter = Unknown filter: { $value }
+# HTML, $filters is empty or the active filters in parentheses
+history-title =
+    <b>Proposal history</b>{ $filters }
+    Page { $page }/{ $pages } · { $total ->
+        [one] { $total } proposal
+       *[other] { $total } proposals
+    }
+history-filter-status = status: { $state }
+history-filter-proposer = proposer: { $user_id }
+history-filter-from = from: { $date }
+history-filter-to = to: { $date }
+history-none = No proposals match these filters.
+# HTML
+history-proposal =
+    <b>Nº{ $index }</b> { $title } — { $state }
+    { $date } · by <a href="tg://user?id={ $user_id }">{ $user_id }</a>{ $link }
+history-state-button =
+    { $state ->
+        [active] Active
+        [execute_ready] Ready
+        [executed] Executed
+        [rejected] Rejected
+        [cancelled] Cancelled
+       *[other] All
+    }
+history-previous = ◀ Prev
+history-next = Next ▶
+
+## /treasury
+
+# HTML
+treasury-title = <b>Treasury</b>
+# HTML
+treasury-vault = <b>Vault { $index }</b> <code>{ $address }</code>
+treasury-no-price = n/a
+# HTML
+treasury-total = <b>Total:</b> ${ $value }
+
+## Transfers
+
+no-signer-key = You don't have a signer key configured.
+transfer-usage = Usage: /transfer <amount> <SOL or mint address> <recipient address or label>
+transfer-proposed = Transfer proposed: { $title }
+transfer-title = Transfer { $amount } { $token }
+transfer-description-label = To { $label } ({ $address })
+transfer-description = To { $address }
+transfer-missing-arguments = Missing amount, token or recipient.
+transfer-not-a-mint = { $token } is not a token mint.
+transfer-invalid-amount = { $amount } is not a valid amount with { $decimals } decimals.
+transfer-unknown-recipient = Unknown recipient: { $recipient }
+
+## Schedules
+
+schedule-usage =
+    Usage: /schedule <cadence> [until YYYY-MM-DD] transfer <amount> <SOL or mint> <recipient>
+    or: /schedule <cadence> [until YYYY-MM-DD] blink <action url>
+
+    Cadence: hourly, daily, weekly, monthly or a quoted cron expression in UTC, e.g. "0 9 1 * *".
+schedule-ends-before-first-run = The schedule ends before its first run.
+schedule-created =
+    Schedule #{ $id } created: { $title }
+    First proposal on { $date }.
+schedule-missing-quote = Missing closing quote.
+schedule-missing-action = Missing action.
+schedule-invalid-end-date = Invalid end date: { $date }
+schedule-invalid-url = Invalid URL: { $url }
+schedule-invalid-action = The action must be transfer or blink.
+schedule-blink-title = Blink { $host }
+cadence-invalid = Invalid cadence: { $cadence }
+cadence-too-frequent = The cadence { $cadence } runs more often than every { $minutes } minutes.
+# HTML
+schedule-paused-creator = Schedule #{ $id } ({ $title }) was paused because its creator can no longer propose transactions.
+schedule-proposal-description = Scheduled proposal #{ $id }
+schedule-invalid-stored-action = Invalid action: { $error }
+# HTML
+schedule-run-failed = Schedule #{ $id } ({ $title }) could not create its proposal: { $error }
+schedule-invalid-id = Missing or invalid schedule id, see /schedules.
+schedule-not-found = There is no schedule #{ $id }.
+schedule-not-managed = Only the creator of the schedule or an admin can change it.
+schedule-ended = Schedule #{ $id } has ended.
+schedule-paused = Schedule #{ $id } paused.
+schedule-resumed = Schedule #{ $id } resumed.
+schedule-deleted = Schedule #{ $id } deleted.
+
+## /schedules
+
+# HTML
+schedules-title = <b>Schedules</b>
+schedules-none = There are no schedules. Create one with /schedule.
+# HTML, $until is empty for schedules without an end date
+schedules-schedule = • <b>#{ $id }</b> { $title } · <code>{ $cadence }</code> · { $status }{ $until } · by { $creator }
+schedules-ended = ended
+schedules-paused = paused
+schedules-next-run = next on { $date }
+schedules-until = { " · " }until { $date }
+
+## Roles
+
+grant-admin-only = Only admins can grant roles.
+grant-usage =
+    Usage: /grant <user id> <role>, or reply to the user with /grant <role>.
+    Roles: proposer, voter, executor, admin, viewer.
+revoke-admin-only = Only admins can revoke roles.
+revoke-usage = Usage: /revoke <user id> <role>, or reply to the user with /revoke <role>.
+role-unknown-sender = Can't tell who sent the replied message.
+role-invalid-user = Invalid user id: { $user_id }
+role-missing-arguments = Missing user or role.
+role-unknown = Unknown role: { $role }
+role-granted = Granted { $role } to user { $user_id }.
+role-revoked = Revoked { $role } from user { $user_id }.
+role-not-held = User { $user_id } doesn't have the { $role } role.
+roles-admin-only = Only admins can list roles.
+roles-bot-admin = admin (BOT_ADMINS)
+roles-unknown-user = unknown
+# HTML
+roles-title = <b>Roles</b>
+roles-none = No roles have been granted yet.
+
+## /link
+
+link-private-only = Send /link to me in a private chat.
+# HTML
+link-current = Your linked wallet is <code>{ $address }</code>.
+# HTML
+link-usage = Usage: /link &lt;wallet address&gt;
+# HTML, $message is the text to sign
+link-sign-message =
+    Sign this exact message with your wallet and paste the signature here (base58 or base64) within { $minutes } minutes:
+
+    <code>{ $message }</code>
+
+    Send /cancel to stop.
+link-sign-button = Sign with wallet
+
+## Address book
+
+address-book-admin-only = Only admins can edit the address book.
+add-address-usage = Usage: /addaddress <address> <label>
+remove-address-usage = Usage: /removeaddress <address>
+# HTML
+address-saved = Saved <code>{ $address }</code> as <b>{ $label }</b>.
+# HTML
+address-lookalike = ⚠️ It looks like <b>{ $label }</b> (<code>{ $address }</code>), make sure this is intended.
+address-removed = Removed { $address } from the address book.
+address-not-found = { $address } is not in the address book.
+# HTML
+address-book-title = <b>Address book</b>
+address-book-empty = The address book is empty. Add entries with /addaddress or /importaddresses.
+import-addresses-usage = Usage: /importaddresses followed by address,label lines, or reply to a CSV file with /importaddresses.
+import-addresses-done =
+    { $count ->
+        [one] Imported { $count } address.
+       *[other] Imported { $count } addresses.
+    }
+import-addresses-skipped =
+    { $count ->
+        [one] Skipped { $count } invalid line.
+       *[other] Skipped { $count } invalid lines.
+    }
+import-addresses-lookalikes = ⚠️ Possible lookalikes of saved addresses:
+# HTML
+import-addresses-lookalike = • <b>{ $label }</b> looks like <b>{ $lookalike }</b> (<code>{ $address }</code>)
+
+## /deeplink
+
+deeplink-usage = Usage: /deeplink <blink url> [action] [parameter=value ...]
+deeplink-unknown-action =
+    Unknown action: { $action }
+    Available: { $available }
+deeplink-unknown-parameter =
+    Unknown parameter: { $parameter }
+    Available: { $available }
+# HTML, $action is empty or the chosen action after an arrow
+deeplink-created =
+    Link to propose <b>{ $title }</b>{ $action }:
+    { $url }
+
+## /cancel
+
+request-cancelled = Cancelling the Blink request.
+
+## Payouts
+
+payout-file-too-large = The payout file is too large.
+payout-line-missing-fields = Line { $line }: expected recipient, amount, token
+payout-line-unknown-recipient = Line { $line }: unknown recipient { $recipient }
+payout-line-unknown-token = Line { $line }: unknown token { $token }
+payout-line-not-a-mint = Line { $line }: { $token } is not a token mint
+payout-line-invalid-amount = Line { $line }: invalid amount { $amount }
+payout-line-total-overflow = Line { $line }: the total of { $token } is too large
+payout-too-many-rows = The file has { $count } transfers, the limit is { $limit }
+payout-no-rows = The file has no transfers
+# HTML
+payout-file-errors =
+    The payout file has errors, fix them and send it again:
+
+    { $errors }
+# HTML, followed by a line per token
+payout-summary =
+    <b>Payout { $file }</b>
+
+    { $transfers ->
+        [one] { $transfers } transfer
+       *[other] { $transfers } transfers
+    } to { $recipients ->
+        [one] { $recipients } recipient
+       *[other] { $recipients } recipients
+    } in { $proposals ->
+        [one] { $proposals } proposal
+       *[other] { $proposals } proposals
+    }.
+
+    <b>Totals</b>
+payout-new-accounts =
+    { $count ->
+        [one] The vault will pay the rent of { $count } new token account.
+       *[other] The vault will pay the rent of { $count } new token accounts.
+    }
+button-create-proposals =
+    { $count ->
+        [one] Create { $count } proposal
+       *[other] Create { $count } proposals
+    }
+payout-cancelled = Payout cancelled.
+payout-creating =
+    { $count ->
+        [one] Creating { $count } proposal...
+       *[other] Creating { $count } proposals...
+    }
+payout-proposal-title = Payout { $file } ({ $position }/{ $total })
+payout-proposal-description =
+    { $count ->
+        [one] { $count } transfer
+       *[other] { $count } transfers
+    }
+# HTML
+payout-refused =
+    Proposal { $position } of { $total } was refused by the treasury policy, the remaining ones were not created:
+
+    { $violations }
+# HTML
+payout-failed =
+    Proposal { $position } of { $total } could not be sent to the chain, the remaining ones were not created:
+
+    { $error }
+payout-created =
+    { $count ->
+        [one] Created { $count } proposal.
+       *[other] Created { $count } proposals.
+    }
+
+## Wallet links
+
+link-send-signature = Paste the signature as plain text.
+link-expired = The link request expired, send /link again to get a new message.
+link-invalid-signature = That signature doesn't match the message and wallet, try again or send /cancel.
+# HTML
+link-done = Linked wallet <code>{ $address }</code> to your account.
+
+## Inline mode
+
+# HTML
+inline-action = Action: <b>{ $action }</b>
+button-propose-with-treasury = Propose with the treasury
+
+## /help
+
+help =
+    These commands are supported:
+    { $commands }
+
+    Send a CSV file (recipient, amount, token, memo) in private to propose a batch payout.
+command-help = Shows all supported commands
+command-start = Initialize the flow.
+command-cancel = Cancel the Blink request.
+command-pending = List the proposals waiting for votes or execution.
+command-history = Browse past proposals. Filters: status=, proposer=, from=, to= (YYYY-MM-DD).
+command-treasury = Show the SOL and token balances of the multisig vaults.
+command-grant = Grant a role: /grant <user id> <role> (admins only).
+command-revoke = Revoke a role: /revoke <user id> <role> (admins only).
+command-roles = List the granted roles (admins only).
+command-link = Link your wallet by signing a message: /link <wallet address>.
+command-addaddress = Label an address: /addaddress <address> <label> (admins only).
+command-removeaddress = Remove an address from the address book (admins only).
+command-addresses = List the labelled addresses.
+command-importaddresses = Import address,label lines, or reply to a CSV file (admins only).
+command-transfer = Propose a transfer: /transfer <amount> <SOL or mint> <recipient>.
+command-schedule = Propose a transfer or a blink on a recurring cadence.
+command-schedules = List the recurring proposals.
+command-pauseschedule = Pause a schedule: /pauseschedule <id>.
+command-resumeschedule = Resume a paused schedule: /resumeschedule <id>.
+command-deleteschedule = Delete a schedule: /deleteschedule <id>.
+command-approve = Approve a proposal: /approve <proposal number>.
+command-reject = Reject a proposal: /reject <proposal number>.
+command-execute = Execute an approved proposal: /execute <proposal number>.
+command-status = Show the votes on a proposal: /status <proposal number>.
+command-deeplink = Make a link that opens a prefilled proposal: /deeplink <blink url> [action] [parameter=value ...].
+command-language = Show or pick the language of the chat: /language [code], or /language default.
@@ -0,0 +1,533 @@
+# Mensajes del bot en español, los que falten se muestran en inglés.
+# Los valores marcados como HTML van en mensajes enviados con el modo HTML de Telegram.
+
+language-name = Español
+
+## Formato
+
+number-group-separator = .
+number-decimal-separator = ,
+# Formato de chrono de las fechas de las propuestas
+# Duraciones de los recordatorios, los límites y /pending
+duration-minutes = { $minutes } min
+duration-hours = { $hours } h { $minutes } min
+duration-days = { $days } d { $hours } h
+date-time-format = %d/%m %H:%M UTC
+
+## /language
+
+language-current = Este chat usa { $language }. Disponibles: { $languages }
+language-following-members = Este chat sigue el idioma de la app de Telegram de cada miembro ({ $language } para ti). Disponibles: { $languages }
+language-unknown = No hay traducción para { $language }. Disponibles: { $languages }
+language-set = Este chat ahora usa { $language }.
+language-reset = Este chat ahora sigue el idioma de la app de Telegram de cada miembro.
+language-admin-only = Solo los administradores pueden cambiar el idioma de un grupo.
+
+## /start
+
+welcome =
+    ¡Bienvenido a BARK Bot!
+
+    BARK Bot simplifica las transacciones multifirma y se integra con el protocolo Blink de Dialect para gestionar la tesorería y tomar decisiones sin complicaciones. Esto es lo que puedes hacer:
+
+    - Proponer una transacción enviando una URL de Blink
+    - Aprobar, rechazar o ejecutar propuestas con /approve, /reject y /execute
+    - Consultar una propuesta con /status
+    - Elegir el idioma del chat con /language
+
+    Escribe /help para ver todos los comandos disponibles.
+shared-link-invalid = Este enlace de Blink ya no es válido.
+
+## Proponer Blinks
+
+no-proposer-role = No tienes permiso para proponer transacciones.
+send-blink-url = Por favor, compárteme una URL de Blink válida
+send-valid-action = Envíame una acción válida.
+send-plain-text = Envíame texto sin formato.
+choose-action = Elige una acción:
+processing-blink = Procesando el blink...
+blink-error = Error al obtener el blink
+# HTML, $parameters es la lista de parámetros con una línea en blanco después de cada uno
+action-parameters =
+    La acción <b>{ $action }</b> tiene los siguientes parámetros:
+
+    { $parameters }Introduce los valores a medida que se te pidan.
+parameter-required = Obligatorio
+parameter-optional = Opcional
+policy-refused = La política de tesorería rechazó la propuesta «{ $title }»
+proposal-not-sent = No se pudo enviar la propuesta «{ $title }» a la cadena
+prepared-by-another-member = Otro miembro está preparando esta propuesta.
+receipt-sent = ¡Transacción enviada!
+# HTML, $parameters tiene una línea por valor
+confirm-proposal =
+    Este enlace propone <b>{ $title }</b> con tu aprobación:
+
+    { $url }
+
+    { $parameters }Revisa los valores antes de confirmar.
+button-confirm-proposal = ✅ Confirmar
+button-discard-proposal = Cancelar
+proposal-discarded = La propuesta no se creó.
+confirm-or-cancel = Confirma o cancela la propuesta con los botones de arriba.
+
+## Vistas previas de Blinks en el grupo
+
+propose-here = Proponer aquí
+continue-in-dm = Seguir por privado
+unfurl-link-gone = El mensaje original con el enlace ya no existe.
+unfurl-group-busy = Ya se está preparando otra propuesta en este grupo, sigue por privado.
+unfurl-load-failed = No se pudo cargar el Blink.
+unfurl-open-private-chat = Abre un chat privado conmigo, pulsa Iniciar y vuelve a intentarlo.
+unfurl-pick-action = Elige una acción debajo de la vista previa.
+unfurl-continue-in-dm = Sigue en nuestro chat privado.
+
+## Solicitudes sin terminar
+
+dialogue-expired = Tu solicitud anterior se canceló tras { $duration } sin respuesta.
+dialogue-outdated = El bot se actualizó y no se pudo retomar tu solicitud anterior, vuelve a empezarla.
+
+## Mensaje de la propuesta (HTML)
+
+proposal-title = <b>Solicitud de transacción Nº{ $index }:</b>
+proposal-untitled = Sin título
+proposal-batch = <b>Lote { $position } de { $total }:</b> { $proposals }
+proposal-transfers = <b>Transferencias</b>
+proposal-first-payment = 🆕 primer pago
+proposal-lookalike = ⚠️ Se parece a <b>{ $label }</b> ({ $address }) pero es otra dirección
+proposal-proposed-by = <b>Propuesta por:</b> { $name } · { $link }
+proposal-creation-tx = tx de creación
+proposal-status = <b>Estado:</b> { $state }
+proposal-expired = vencida
+proposal-executed-in = <b>Ejecutada en:</b> { $link }
+proposal-approved = <b>Aprobada ({ $count }/{ $threshold }):</b>
+proposal-rejected = <b>Rechazada ({ $count }/{ $threshold }):</b>
+# HTML, $programs lists the program addresses
+proposal-policy-unchecked = ℹ️ Los límites y las reglas de aprobación solo ven las transferencias directas de SOL y tokens, los fondos que mueva { $programs } no se cuentan.
+proposal-policy = ⚠️ <b>Política</b>
+proposal-timeline = <b>Historial</b>
+proposal-failed = ⚠️ <b>Error:</b> { $error }
+proposal-by = por { $name }
+proposal-user = usuario { $user_id }
+
+event-create = Creada
+event-approve = Aprobada
+event-reject = Rechazada
+event-execute = Ejecutada
+event-cancel = Cancelada
+event-expired = Vencida
+
+state-draft = Borrador
+state-active = Activa
+state-execute_ready = Lista para ejecutar
+state-executed = Ejecutada
+state-rejected = Rechazada
+state-cancelled = Cancelada
+
+## Votaciones
+
+button-approve = Aprobar { $count }/{ $threshold }
+button-reject = Rechazar { $count }/{ $threshold }
+button-cancel = Cancelar { $count }/{ $threshold }
+button-execute = Ejecutar
+button-policy-blocked = ⛔ Bloqueada por la política
+button-invalid = Este botón no es válido.
+button-stale = Este botón era demasiado antiguo y se ha actualizado, vuelve a intentarlo.
+action-in-progress = Hay otra acción en curso sobre esta transacción, espera un momento.
+processing-request = Procesando la solicitud...
+progress =
+    { $action ->
+        [Approve] Aprobando…
+        [Reject] Rechazando…
+        [Execute] Ejecutando…
+       *[Cancel] Cancelando…
+    } ({ $name })
+action-failed =
+    { $action ->
+        [Approve] Aprobación
+        [Reject] Rechazo
+        [Execute] Ejecución
+       *[Cancel] Cancelación
+    } de { $name }: { $error }
+unknown-action = Acción desconocida: { $action }
+role-required = Necesitas el rol { $role } para hacer esto.
+not-a-signer = No eres firmante de esta multisig.
+already-voted =
+    { $action ->
+        [Approve] Ya votaste a favor de aprobar esta transacción.
+        [Reject] Ya votaste a favor de rechazar esta transacción.
+       *[Cancel] Ya votaste a favor de cancelar esta transacción.
+    }
+policy-blocked = Bloqueada por la política: { $reasons }
+policy-blocked-program = El programa { $program } está bloqueado
+policy-missing-approvals = Las transferencias de más de { $above } { $token } necesitan la aprobación de { $approvers }
+policy-limit-exceeded = { $total } { $token } superaría el límite de { $max } { $token } { $period }
+policy-limit-exceeded-new-recipients = { $total } { $token } superaría el límite de { $max } { $token } a destinatarios nuevos { $period }
+policy-per-proposal = por propuesta
+policy-per-day = por día
+policy-per-week = por semana
+policy-per-duration = cada { $duration }
+policy-lock-timeout = La comprobación de la política está ocupada o no responde, inténtalo de nuevo en un momento
+transaction-command-usage = Uso: /{ $command } <número de propuesta>
+proposal-not-found = No existe la propuesta Nº{ $index }.
+# HTML
+action-done =
+    Propuesta Nº{ $index } { $action ->
+        [Approve] aprobada
+        [Reject] rechazada
+        [Execute] ejecutada
+       *[Cancel] cancelada
+    }: <a href="{ $url }">transacción</a>
+# HTML
+action-command-failed =
+    No se pudo { $action ->
+        [Approve] aprobar
+        [Reject] rechazar
+        [Execute] ejecutar
+       *[Cancel] cancelar
+    } la propuesta Nº{ $index }: { $error }
+view-proposal = ver
+
+## Recordatorios
+
+# HTML, $link está vacío o es un enlace a la propuesta tras una línea en blanco
+reminder-direct = ⏰ La transacción Nº{ $index } lleva { $age } esperando tu voto.{ $link }
+reminder-open-request = Abrir la solicitud
+# HTML, $members menciona a los miembros que no han votado
+reminder-group = ⏰ La transacción Nº{ $index } lleva { $age } abierta. Aún faltan los votos de: { $members }
+expiry-status = ⌛ La transacción Nº{ $index } caducó tras { $age } sin ejecutarse.
+expiry-vote-cancel = ⌛ La transacción Nº{ $index } caducó tras { $age } sin ejecutarse. Los miembros ya pueden votar para cancelarla.
+expiry-cancel-voted = ⌛ La transacción Nº{ $index } caducó tras { $age } sin ejecutarse. El bot votó para cancelarla, los miembros pueden sumar sus votos.
+expiry-reject-voted = ⌛ La transacción Nº{ $index } caducó tras { $age } sin alcanzar el umbral. El bot votó para rechazarla, los miembros pueden sumar sus votos.
+expiry-vote-reject = ⌛ La transacción Nº{ $index } caducó tras { $age } sin alcanzar el umbral. Se pide a los miembros que la rechacen.
+
+## /pending
+
+# HTML
+pending-title = <b>Propuestas pendientes</b>
+pending-none = No hay propuestas pendientes.
+pending-expired = ⌛ caducada
+# HTML, $expired y $link quedan vacíos salvo que la propuesta haya caducado o se conozca su mensaje
+pending-proposal =
+    <b>Nº{ $index }</b> { $title }
+    ✅ { $approved }/{ $threshold } · ❌ { $rejected }/{ $threshold } · { $state } · abierta hace { $duration }{ $expired }{ $link }
+
+## /status
+
+# HTML, $approvers y $rejecters listan los nombres o muestran un guion
+status-proposal =
+    <b>Nº{ $index }</b> { $title }
+    { $state }
+
+    ✅ { $approved }/{ $threshold }: { $approvers }
+    ❌ { $rejected }/{ $threshold }: { $rejecters }{ $link }
+status-view-proposal = Ver la propuesta
+
+## /history
+
+history-usage =
+    { $error }
+
+    Uso: /history [status=executed] [proposer=me|<id de usuario>] [from=AAAA-MM-DD] [to=AAAA-MM-DD]
+history-invalid-filter = Filtro no válido: { $value }
+history-unknown-status = Estado desconocido: { $value }
+history-invalid-proposer = Proponente no válido: { $value }
+history-invalid-date = Fecha no válida: { $value }
+history-unknown-filter = Filtro desconocido: { $value }
+# HTML, $filters está vacío o lleva los filtros activos entre paréntesis
+history-title =
+    <b>Historial de propuestas</b>{ $filters }
+    Página { $page }/{ $pages } · { $total ->
+        [one] { $total } propuesta
+       *[other] { $total } propuestas
+    }
+history-filter-status = estado: { $state }
+history-filter-proposer = proponente: { $user_id }
+history-filter-from = desde: { $date }
+history-filter-to = hasta: { $date }
+history-none = Ninguna propuesta coincide con estos filtros.
+# HTML
+history-proposal =
+    <b>Nº{ $index }</b> { $title } — { $state }
+    { $date } · por <a href="tg://user?id={ $user_id }">{ $user_id }</a>{ $link }
+history-state-button =
+    { $state ->
+        [active] Activas
+        [execute_ready] Listas
+        [executed] Ejecutadas
+        [rejected] Rechazadas
+        [cancelled] Canceladas
+       *[other] Todas
+    }
+history-previous = ◀ Anterior
+history-next = Siguiente ▶
+
+## /treasury
+
+# HTML
+treasury-title = <b>Tesorería</b>
+# HTML
+treasury-vault = <b>Bóveda { $index }</b> <code>{ $address }</code>
+treasury-no-price = s/d
+# HTML
+treasury-total = <b>Total:</b> ${ $value }
+
+## Transferencias
+
+no-signer-key = No tienes una clave de firma configurada.
+transfer-usage = Uso: /transfer <cantidad> <SOL o dirección del mint> <dirección o etiqueta del destinatario>
+transfer-proposed = Transferencia propuesta: { $title }
+transfer-title = Transferir { $amount } { $token }
+transfer-description-label = A { $label } ({ $address })
+transfer-description = A { $address }
+transfer-missing-arguments = Falta la cantidad, el token o el destinatario.
+transfer-not-a-mint = { $token } no es un mint de token.
+transfer-invalid-amount = { $amount } no es una cantidad válida con { $decimals } decimales.
+transfer-unknown-recipient = Destinatario desconocido: { $recipient }
+
+## Programaciones
+
+schedule-usage =
+    Uso: /schedule <frecuencia> [until AAAA-MM-DD] transfer <cantidad> <SOL o mint> <destinatario>
+    o: /schedule <frecuencia> [until AAAA-MM-DD] blink <url de la acción>
+
+    Frecuencia: hourly, daily, weekly, monthly o una expresión cron entre comillas en UTC, p. ej. "0 9 1 * *".
+schedule-ends-before-first-run = La programación termina antes de su primera ejecución.
+schedule-created =
+    Programación #{ $id } creada: { $title }
+    Primera propuesta el { $date }.
+schedule-missing-quote = Falta la comilla de cierre.
+schedule-missing-action = Falta la acción.
+schedule-invalid-end-date = Fecha de fin no válida: { $date }
+schedule-invalid-url = URL no válida: { $url }
+schedule-invalid-action = La acción debe ser transfer o blink.
+schedule-blink-title = Blink { $host }
+cadence-invalid = Frecuencia no válida: { $cadence }
+cadence-too-frequent = La frecuencia { $cadence } se ejecuta más a menudo que cada { $minutes } minutos.
+# HTML
+schedule-paused-creator = La programación #{ $id } ({ $title }) se pausó porque su creador ya no puede proponer transacciones.
+schedule-proposal-description = Propuesta programada #{ $id }
+schedule-invalid-stored-action = Acción no válida: { $error }
+# HTML
+schedule-run-failed = La programación #{ $id } ({ $title }) no pudo crear su propuesta: { $error }
+schedule-invalid-id = Falta el id de la programación o no es válido, consulta /schedules.
+schedule-not-found = No existe la programación #{ $id }.
+schedule-not-managed = Solo quien creó la programación o un administrador puede cambiarla.
+schedule-ended = La programación #{ $id } ha terminado.
+schedule-paused = Programación #{ $id } pausada.
+schedule-resumed = Programación #{ $id } reanudada.
+schedule-deleted = Programación #{ $id } eliminada.
+
+## /schedules
+
+# HTML
+schedules-title = <b>Programaciones</b>
+schedules-none = No hay programaciones. Crea una con /schedule.
+# HTML, $until queda vacío en las programaciones sin fecha de fin
+schedules-schedule = • <b>#{ $id }</b> { $title } · <code>{ $cadence }</code> · { $status }{ $until } · por { $creator }
+schedules-ended = terminada
+schedules-paused = pausada
+schedules-next-run = próxima el { $date }
+schedules-until = { " · " }hasta { $date }
+
+## Roles
+
+grant-admin-only = Solo los administradores pueden conceder roles.
+grant-usage =
+    Uso: /grant <id de usuario> <rol>, o responde al usuario con /grant <rol>.
+    Roles: proposer, voter, executor, admin, viewer.
+revoke-admin-only = Solo los administradores pueden retirar roles.
+revoke-usage = Uso: /revoke <id de usuario> <rol>, o responde al usuario con /revoke <rol>.
+role-unknown-sender = No se sabe quién envió el mensaje respondido.
+role-invalid-user = Id de usuario no válido: { $user_id }
+role-missing-arguments = Falta el usuario o el rol.
+role-unknown = Rol desconocido: { $role }
+role-granted = Rol { $role } concedido al usuario { $user_id }.
+role-revoked = Rol { $role } retirado al usuario { $user_id }.
+role-not-held = El usuario { $user_id } no tiene el rol { $role }.
+roles-admin-only = Solo los administradores pueden ver los roles.
+roles-bot-admin = admin (BOT_ADMINS)
+roles-unknown-user = desconocido
+# HTML
+roles-title = <b>Roles</b>
+roles-none = Aún no se ha concedido ningún rol.
+
+## /link
+
+link-private-only = Envíame /link en un chat privado.
+# HTML
+link-current = Tu wallet vinculada es <code>{ $address }</code>.
+# HTML
+link-usage = Uso: /link &lt;dirección de la wallet&gt;
+# HTML, $message es el texto que hay que firmar
+link-sign-message =
+    Firma este mensaje exacto con tu wallet y pega aquí la firma (base58 o base64) en menos de { $minutes } minutos:
+
+    <code>{ $message }</code>
+
+    Envía /cancel para detenerlo.
+link-sign-button = Firmar con la wallet
+
+## Libreta de direcciones
+
+address-book-admin-only = Solo los administradores pueden editar la libreta de direcciones.
+add-address-usage = Uso: /addaddress <dirección> <etiqueta>
+remove-address-usage = Uso: /removeaddress <dirección>
+# HTML
+address-saved = <code>{ $address }</code> guardada como <b>{ $label }</b>.
+# HTML
+address-lookalike = ⚠️ Se parece a <b>{ $label }</b> (<code>{ $address }</code>), asegúrate de que es lo que quieres.
+address-removed = { $address } se quitó de la libreta de direcciones.
+address-not-found = { $address } no está en la libreta de direcciones.
+# HTML
+address-book-title = <b>Libreta de direcciones</b>
+address-book-empty = La libreta de direcciones está vacía. Añade entradas con /addaddress o /importaddresses.
+import-addresses-usage = Uso: /importaddresses seguido de líneas dirección,etiqueta, o responde a un archivo CSV con /importaddresses.
+import-addresses-done =
+    { $count ->
+        [one] Se importó { $count } dirección.
+       *[other] Se importaron { $count } direcciones.
+    }
+import-addresses-skipped =
+    { $count ->
+        [one] Se omitió { $count } línea no válida.
+       *[other] Se omitieron { $count } líneas no válidas.
+    }
+import-addresses-lookalikes = ⚠️ Posibles imitaciones de direcciones guardadas:
+# HTML
+import-addresses-lookalike = • <b>{ $label }</b> se parece a <b>{ $lookalike }</b> (<code>{ $address }</code>)
+
+## /deeplink
+
+deeplink-usage = Uso: /deeplink <url del blink> [acción] [parámetro=valor ...]
+deeplink-unknown-action =
+    Acción desconocida: { $action }
+    Disponibles: { $available }
+deeplink-unknown-parameter =
+    Parámetro desconocido: { $parameter }
+    Disponibles: { $available }
+# HTML, $action está vacío o lleva la acción elegida tras una flecha
+deeplink-created =
+    Enlace para proponer <b>{ $title }</b>{ $action }:
+    { $url }
+
+## /cancel
+
+request-cancelled = Cancelando la solicitud del Blink.
+
+## Pagos
+
+payout-file-too-large = El archivo de pagos es demasiado grande.
+payout-line-missing-fields = Línea { $line }: se esperaba destinatario, cantidad, token
+payout-line-unknown-recipient = Línea { $line }: destinatario desconocido { $recipient }
+payout-line-unknown-token = Línea { $line }: token desconocido { $token }
+payout-line-not-a-mint = Línea { $line }: { $token } no es un mint de token
+payout-line-invalid-amount = Línea { $line }: cantidad no válida { $amount }
+payout-line-total-overflow = Línea { $line }: el total de { $token } es demasiado grande
+payout-too-many-rows = El archivo tiene { $count } transferencias, el límite es { $limit }
+payout-no-rows = El archivo no tiene transferencias
+# HTML
+payout-file-errors =
+    El archivo de pagos tiene errores, corrígelos y vuelve a enviarlo:
+
+    { $errors }
+# HTML, le sigue una línea por token
+payout-summary =
+    <b>Pago { $file }</b>
+
+    { $transfers ->
+        [one] { $transfers } transferencia
+       *[other] { $transfers } transferencias
+    } a { $recipients ->
+        [one] { $recipients } destinatario
+       *[other] { $recipients } destinatarios
+    } en { $proposals ->
+        [one] { $proposals } propuesta
+       *[other] { $proposals } propuestas
+    }.
+
+    <b>Totales</b>
+payout-new-accounts =
+    { $count ->
+        [one] La bóveda pagará la renta de { $count } cuenta de token nueva.
+       *[other] La bóveda pagará la renta de { $count } cuentas de token nuevas.
+    }
+button-create-proposals =
+    { $count ->
+        [one] Crear { $count } propuesta
+       *[other] Crear { $count } propuestas
+    }
+payout-cancelled = Pago cancelado.
+payout-creating =
+    { $count ->
+        [one] Creando { $count } propuesta...
+       *[other] Creando { $count } propuestas...
+    }
+payout-proposal-title = Pago { $file } ({ $position }/{ $total })
+payout-proposal-description =
+    { $count ->
+        [one] { $count } transferencia
+       *[other] { $count } transferencias
+    }
+# HTML
+payout-refused =
+    La política de tesorería rechazó la propuesta { $position } de { $total }, las restantes no se crearon:
+
+    { $violations }
+# HTML
+payout-failed =
+    No se pudo enviar la propuesta { $position } de { $total } a la cadena, las restantes no se crearon:
+
+    { $error }
+payout-created =
+    { $count ->
+        [one] Se creó { $count } propuesta.
+       *[other] Se crearon { $count } propuestas.
+    }
+
+## Vinculación de wallets
+
+link-send-signature = Pega la firma como texto sin formato.
+link-expired = La solicitud de vinculación venció, envía /link otra vez para recibir un mensaje nuevo.
+link-invalid-signature = Esa firma no coincide con el mensaje y la wallet, vuelve a intentarlo o envía /cancel.
+# HTML
+link-done = Wallet <code>{ $address }</code> vinculada a tu cuenta.
+
+## Modo inline
+
+# HTML
+inline-action = Acción: <b>{ $action }</b>
+button-propose-with-treasury = Proponer con la tesorería
+
+## /help
+
+help =
+    Estos son los comandos disponibles:
+    { $commands }
+
+    Envía en privado un archivo CSV (destinatario, cantidad, token, memo) para proponer un pago por lotes.
+command-help = Muestra todos los comandos disponibles
+command-start = Inicia el flujo.
+command-cancel = Cancela la solicitud del Blink.
+command-pending = Lista las propuestas pendientes de votos o de ejecución.
+command-history = Consulta las propuestas anteriores. Filtros: status=, proposer=, from=, to= (AAAA-MM-DD).
+command-treasury = Muestra los saldos de SOL y tokens de las bóvedas de la multisig.
+command-grant = Concede un rol: /grant <id de usuario> <rol> (solo administradores).
+command-revoke = Retira un rol: /revoke <id de usuario> <rol> (solo administradores).
+command-roles = Lista los roles concedidos (solo administradores).
+command-link = Vincula tu wallet firmando un mensaje: /link <dirección de la wallet>.
+command-addaddress = Etiqueta una dirección: /addaddress <dirección> <etiqueta> (solo administradores).
+command-removeaddress = Quita una dirección de la libreta (solo administradores).
+command-addresses = Lista las direcciones etiquetadas.
+command-importaddresses = Importa líneas dirección,etiqueta, o responde a un archivo CSV (solo administradores).
+command-transfer = Propone una transferencia: /transfer <cantidad> <SOL o mint> <destinatario>.
+command-schedule = Propone una transferencia o un blink con una frecuencia fija.
+command-schedules = Lista las propuestas programadas.
+command-pauseschedule = Pausa una programación: /pauseschedule <id>.
+command-resumeschedule = Reanuda una programación pausada: /resumeschedule <id>.
+command-deleteschedule = Elimina una programación: /deleteschedule <id>.
+command-approve = Aprueba una propuesta: /approve <número de propuesta>.
+command-reject = Rechaza una propuesta: /reject <número de propuesta>.
+command-execute = Ejecuta una propuesta aprobada: /execute <número de propuesta>.
+command-status = Muestra los votos de una propuesta: /status <número de propuesta>.
+command-deeplink = Crea un enlace que abre una propuesta rellenada: /deeplink <url del blink> [acción] [parámetro=valor ...].
+command-language = Muestra o elige el idioma del chat: /language [código], o /language default.
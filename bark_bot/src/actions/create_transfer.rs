@@ -0,0 +1,55 @@
+use crate::{
+    actions::propose_instructions,
+    collections::{ProposalError, Transaction, TransferArguments},
+    requests::get_token_account_info,
+    utils::{
+        get_associated_token_address, get_group_chat_id, get_language,
+        get_multisig_authority_pubkey, get_transfer_instructions,
+    },
+};
+use solana_sdk::pubkey::Pubkey;
+use teloxide::types::UserId;
+
+/// Proposes a transfer from the vault, of SOL or of the given token
+pub async fn create_transfer(
+    multisig_pubkey: Pubkey,
+    user_id: UserId,
+    arguments: &TransferArguments,
+) -> Result<Transaction, ProposalError> {
+    let recipient = arguments.recipient;
+    let mint = &arguments.mint;
+    let vault_pubkey = get_multisig_authority_pubkey(multisig_pubkey, 1);
+
+    // The vault pays the rent of the recipient's token account when it has none yet
+    let create_account = match mint {
+        Some((mint, mint_info)) => {
+            let destination =
+                get_associated_token_address(recipient, *mint, mint_info.token_program);
+
+            get_token_account_info(destination).await.is_none()
+        }
+        None => false,
+    };
+
+    let instructions = get_transfer_instructions(
+        vault_pubkey,
+        recipient,
+        arguments.base_amount,
+        mint.as_ref().map(|(mint, mint_info)| (*mint, mint_info)),
+        create_account,
+    );
+
+    // Proposals are read in the group
+    let language = get_language(get_group_chat_id(), None).await;
+
+    propose_instructions(
+        multisig_pubkey,
+        user_id,
+        instructions,
+        &arguments.title(language),
+        &arguments.description(language),
+        &[],
+        None,
+    )
+    .await
+}
@@ -1,4 +1,5 @@
 use crate::{requests::send_and_confirm_transaction, utils::get_user_keypair};
+use solana_client::client_error::ClientError;
 use solana_sdk::{
     instruction::Instruction,
     message::Message,
@@ -12,7 +13,7 @@ pub async fn execute_transaction(
     multisig_pubkey: Pubkey,
     transaction_index: u32,
     user_id: UserId,
-) -> Signature {
+) -> Result<Signature, ClientError> {
     let instructions: Vec<Instruction> = vec![crate::instructions::execute_transaction(
         multisig_pubkey,
         transaction_index,
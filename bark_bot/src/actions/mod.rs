@@ -1,9 +1,17 @@
 pub mod approve_transaction;
+pub mod cancel_transaction;
 pub mod create_transaction;
+pub mod create_transfer;
 pub mod execute_transaction;
+pub mod perform_transaction_action;
+pub mod propose_instructions;
 pub mod reject_transaction;
 
 pub use approve_transaction::*;
+pub use cancel_transaction::*;
 pub use create_transaction::*;
+pub use create_transfer::*;
 pub use execute_transaction::*;
+pub use perform_transaction_action::*;
+pub use propose_instructions::*;
 pub use reject_transaction::*;
@@ -0,0 +1,169 @@
+use crate::collections::{RequestProgress, Transaction};
+use crate::messages::refresh_transaction_request;
+use crate::policies::{check_transaction_policy, lock_policy};
+use crate::requests::{
+    create_transaction_event, get_transaction_account, update_transaction_state, upsert_user,
+};
+use crate::utils::{
+    get_group_chat_id, get_language, get_multisig_pubkey, get_transaction_state, get_user_keypair,
+    translate,
+};
+use solana_sdk::{signature::Signature, signer::Signer};
+use teloxide::{prelude::*, types::User};
+
+/// Sends the member's vote or execution and keeps the proposal message in sync,
+/// the inner error is the chain failure already shown on the message
+pub async fn perform_transaction_action(
+    bot: &Bot,
+    user: &User,
+    transaction_entry: &Transaction,
+    action: &str,
+) -> ResponseResult<Result<Signature, String>> {
+    // Progress and failures are shown on the group message
+    let language = get_language(get_group_chat_id(), None).await;
+
+    if !matches!(action, "Approve" | "Reject" | "Execute" | "Cancel") {
+        return Ok(Err(translate(
+            language,
+            "unknown-action",
+            &[("action", action.into())],
+        )));
+    }
+
+    let multisig_pubkey = get_multisig_pubkey();
+    let transaction_index = transaction_entry.transaction_index;
+
+    // Held until the execution is recorded, so the next one sees it in the spending window
+    let _policy_guard = match action {
+        "Execute" => match lock_policy().await {
+            Some(guard) => Some(guard),
+            None => {
+                let reason = translate(language, "policy-lock-timeout", &[]);
+
+                refresh_transaction_request(
+                    bot,
+                    transaction_entry.id,
+                    &RequestProgress::Failed(translate(
+                        language,
+                        "policy-blocked",
+                        &[("reasons", reason.clone().into())],
+                    )),
+                )
+                .await?;
+
+                return Ok(Err(reason));
+            }
+        },
+        _ => None,
+    };
+
+    if action == "Execute" {
+        let transaction_account = get_transaction_account(multisig_pubkey, transaction_index).await;
+        let violations =
+            check_transaction_policy(multisig_pubkey, transaction_entry.id, &transaction_account)
+                .await
+                .violations;
+
+        if !violations.is_empty() {
+            let reasons = violations
+                .iter()
+                .map(|violation| violation.message(language))
+                .collect::<Vec<String>>()
+                .join("; ");
+
+            refresh_transaction_request(
+                bot,
+                transaction_entry.id,
+                &RequestProgress::Failed(translate(
+                    language,
+                    "policy-blocked",
+                    &[("reasons", reasons.clone().into())],
+                )),
+            )
+            .await?;
+
+            return Ok(Err(reasons));
+        }
+    }
+
+    refresh_transaction_request(
+        bot,
+        transaction_entry.id,
+        &RequestProgress::InFlight(translate(
+            language,
+            "progress",
+            &[("action", action.into()), ("name", user.full_name().into())],
+        )),
+    )
+    .await?;
+
+    let result = match action {
+        "Approve" => {
+            crate::actions::approve_transaction(multisig_pubkey, transaction_index, user.id).await
+        }
+        "Reject" => {
+            crate::actions::reject_transaction(multisig_pubkey, transaction_index, user.id).await
+        }
+        "Execute" => {
+            crate::actions::execute_transaction(multisig_pubkey, transaction_index, user.id).await
+        }
+        _ => crate::actions::cancel_transaction(multisig_pubkey, transaction_index, user.id).await,
+    };
+
+    let signature = match result {
+        Ok(signature) => signature,
+        Err(e) => {
+            log::warn!(
+                "{} transaction Nº{} failed: {}",
+                action,
+                transaction_index,
+                e
+            );
+
+            let error: String = e.to_string().chars().take(200).collect();
+            refresh_transaction_request(
+                bot,
+                transaction_entry.id,
+                &RequestProgress::Failed(translate(
+                    language,
+                    "action-failed",
+                    &[
+                        ("action", action.into()),
+                        ("name", user.full_name().into()),
+                        ("error", error.clone().into()),
+                    ],
+                )),
+            )
+            .await?;
+
+            return Ok(Err(error));
+        }
+    };
+
+    upsert_user(
+        user.id,
+        user.full_name(),
+        Some(get_user_keypair(user.id).pubkey().to_string()),
+    )
+    .await;
+
+    create_transaction_event(
+        transaction_entry.id,
+        &action.to_lowercase(),
+        user.id,
+        signature.to_string(),
+    )
+    .await;
+
+    let transaction_account = get_transaction_account(multisig_pubkey, transaction_index).await;
+
+    update_transaction_state(
+        transaction_entry.id,
+        get_transaction_state(&transaction_account.status),
+    )
+    .await;
+
+    refresh_transaction_request(bot, transaction_entry.id, &RequestProgress::Idle).await?;
+
+    Ok(Ok(signature))
+}
@@ -0,0 +1,99 @@
+use crate::{
+    collections::{PolicyViolation, ProposalError, Transaction},
+    policies::{decode_transfers, evaluate_policy},
+    requests::{create_transfers, get_multisig_account, send_and_confirm_transaction},
+    utils::get_user_keypair,
+};
+use solana_sdk::{
+    instruction::Instruction, message::Message, pubkey::Pubkey, signature::Keypair, signer::Signer,
+};
+use teloxide::types::UserId;
+
+/// Wraps the instructions in a new Squads transaction that the creator activates and
+/// approves right away, then records it. Used by Blinks and native transfers alike.
+pub async fn propose_instructions(
+    multisig_pubkey: Pubkey,
+    user_id: UserId,
+    proposed_instructions: Vec<Instruction>,
+    title: &str,
+    description: &str,
+    parameters: &[(String, String)],
+    batch_id: Option<&str>,
+) -> Result<Transaction, ProposalError> {
+    let multisig_account = get_multisig_account(multisig_pubkey).await;
+    let transaction_index = multisig_account.transaction_index + 1;
+
+    let mut instructions: Vec<Instruction> = vec![];
+
+    instructions.push(crate::instructions::create_transaction(
+        multisig_pubkey,
+        transaction_index,
+        user_id,
+    ));
+
+    let mut instruction_index: u8 = 1;
+
+    let creator_keypair = get_user_keypair(user_id);
+    let creator_pubkey = creator_keypair.pubkey();
+
+    // The creator approves right away, so their approval already counts
+    let violations: Vec<PolicyViolation> =
+        evaluate_policy(&proposed_instructions, &[creator_pubkey], None)
+            .await
+            .into_iter()
+            .filter(|violation| violation.blocks_proposal())
+            .collect();
+
+    if !violations.is_empty() {
+        return Err(ProposalError::Refused(violations));
+    }
+
+    let transfers = decode_transfers(&proposed_instructions).await;
+
+    for instruction in proposed_instructions {
+        instructions.push(crate::instructions::add_instruction(
+            multisig_pubkey,
+            transaction_index,
+            instruction_index,
+            instruction,
+            user_id,
+        ));
+        instruction_index += 1;
+    }
+
+    instructions.push(crate::instructions::activate_transaction(
+        multisig_pubkey,
+        transaction_index,
+        user_id,
+    ));
+
+    instructions.push(crate::instructions::approve_transaction(
+        multisig_pubkey,
+        transaction_index,
+        user_id,
+    ));
+
+    let message = Message::new(&instructions, Some(&creator_pubkey));
+    let signers: Vec<&Keypair> = vec![&creator_keypair];
+    let signature = send_and_confirm_transaction(message, signers)
+        .await
+        .map_err(|e| {
+            log::warn!("Creating transaction Nº{} failed: {}", transaction_index, e);
+            ProposalError::Failed(e)
+        })?;
+
+    let transaction_entry = crate::requests::create_transaction(
+        transaction_index.try_into().unwrap(),
+        user_id,
+        signature.to_string(),
+        title.to_string(),
+        description.to_string(),
+        parameters,
+        batch_id,
+    )
+    .await;
+
+    create_transfers(transaction_entry.id, &transfers).await;
+
+    Ok(transaction_entry)
+}
@@ -0,0 +1,8 @@
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AddressBookEntry {
+    pub chat_id: String,
+    pub address: String,
+    pub label: String,
+    pub added_by: Option<String>,
+    pub created_at: i64,
+}
@@ -1,5 +1,22 @@
+use crate::utils::{get_callback_settings, get_group_chat_id, get_multisig_pubkey, get_timestamp};
+use base64::prelude::*;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use solana_sdk::pubkey::Pubkey;
+use std::time::Duration;
+use teloxide::types::ChatId;
+
+const VERSION: u8 = 1;
+const PAYLOAD_LENGTH: usize = 14; // version, transaction id, action and issue time
+const TAG_LENGTH: usize = 12;
+const ACTIONS: [&str; 5] = ["Approve", "Reject", "Execute", "Cancel", "Busy"];
+
 #[derive(Debug)]
-pub struct InvalidButtonMetadataError {}
+pub enum InvalidButtonMetadataError {
+    Malformed,  // Not a proposal button, or one from an older version of the bot
+    Forged,     // The tag doesn't match this bot, multisig and group
+    Stale(i64), // Older than the configured maximum age, carries the transaction id
+}
 
 #[derive(Debug)]
 pub struct ButtonMetadata {
@@ -7,14 +24,103 @@ pub struct ButtonMetadata {
     pub value: String,
 }
 
+/// What a tag is bound to, the callback secret plus the multisig and group the button
+/// was issued for, so a button can't be replayed against another deployment either
+#[cfg_attr(test, derive(Clone))]
+struct Binding {
+    secret: Vec<u8>,
+    multisig_pubkey: Pubkey,
+    group_chat_id: ChatId,
+}
+
+impl Binding {
+    fn current() -> Self {
+        Binding {
+            secret: get_callback_settings().secret,
+            multisig_pubkey: get_multisig_pubkey(),
+            group_chat_id: get_group_chat_id(),
+        }
+    }
+}
+
+fn sign(binding: &Binding, payload: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(&binding.secret).unwrap();
+
+    mac.update(payload);
+    mac.update(&binding.multisig_pubkey.to_bytes());
+    mac.update(&binding.group_chat_id.0.to_be_bytes());
+
+    mac
+}
+
+fn encode(transaction_data: &ButtonMetadata, binding: &Binding, issued_at: u32) -> String {
+    // Unknown actions get 0, which is rejected when decoding
+    let action = ACTIONS
+        .iter()
+        .position(|action| *action == transaction_data.value)
+        .map_or(0, |index| index as u8 + 1);
+
+    let mut data = Vec::with_capacity(PAYLOAD_LENGTH + TAG_LENGTH);
+    data.push(VERSION);
+    data.extend_from_slice(&transaction_data.transaction_id.to_be_bytes());
+    data.push(action);
+    data.extend_from_slice(&issued_at.to_be_bytes());
+
+    let tag = sign(binding, &data).finalize().into_bytes();
+    data.extend_from_slice(&tag[..TAG_LENGTH]);
+
+    BASE64_URL_SAFE_NO_PAD.encode(data)
+}
+
+fn decode(
+    value: String,
+    binding: &Binding,
+    max_age: Option<Duration>,
+    now: i64,
+) -> Result<ButtonMetadata, InvalidButtonMetadataError> {
+    let data = BASE64_URL_SAFE_NO_PAD
+        .decode(value)
+        .map_err(|_| InvalidButtonMetadataError::Malformed)?;
+
+    if data.len() != PAYLOAD_LENGTH + TAG_LENGTH || data[0] != VERSION {
+        return Err(InvalidButtonMetadataError::Malformed);
+    }
+
+    let (payload, tag) = data.split_at(PAYLOAD_LENGTH);
+
+    sign(binding, payload)
+        .verify_truncated_left(tag)
+        .map_err(|_| InvalidButtonMetadataError::Forged)?;
+
+    let transaction_id = i64::from_be_bytes(payload[1..9].try_into().unwrap());
+    let value = match payload[9] {
+        0 => return Err(InvalidButtonMetadataError::Malformed),
+        action => ACTIONS
+            .get(usize::from(action) - 1)
+            .ok_or(InvalidButtonMetadataError::Malformed)?
+            .to_string(),
+    };
+    let issued_at = u32::from_be_bytes(payload[10..14].try_into().unwrap());
+
+    if let Some(max_age) = max_age {
+        if now - i64::from(issued_at) > max_age.as_secs() as i64 {
+            return Err(InvalidButtonMetadataError::Stale(transaction_id));
+        }
+    }
+
+    Ok(ButtonMetadata {
+        transaction_id,
+        value,
+    })
+}
+
 impl From<&ButtonMetadata> for String {
     fn from(transaction_data: &ButtonMetadata) -> Self {
-        let result = format!(
-            "{},{}",
-            transaction_data.transaction_id, transaction_data.value,
-        );
-
-        result
+        encode(
+            transaction_data,
+            &Binding::current(),
+            get_timestamp() as u32,
+        )
     }
 }
 
@@ -22,20 +128,147 @@ impl TryFrom<String> for ButtonMetadata {
     type Error = InvalidButtonMetadataError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        let parts: Vec<&str> = value.split(',').collect();
-        if parts.len() != 2 {
-            return Err(InvalidButtonMetadataError {});
-        }
+        decode(
+            value,
+            &Binding::current(),
+            get_callback_settings().max_age,
+            get_timestamp(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let transaction_id = parts[0]
-            .parse::<i64>()
-            .map_err(|_| InvalidButtonMetadataError {})?;
+    const MAX_AGE: Option<Duration> = Some(Duration::from_secs(30 * 24 * 60 * 60));
 
-        let value = String::from(parts[1].trim());
+    fn binding(secret: &[u8]) -> Binding {
+        Binding {
+            secret: secret.to_vec(),
+            multisig_pubkey: Pubkey::new_from_array([1; 32]),
+            group_chat_id: ChatId(-100),
+        }
+    }
 
-        Ok(ButtonMetadata {
+    fn button(transaction_id: i64, value: &str) -> ButtonMetadata {
+        ButtonMetadata {
             transaction_id,
-            value,
-        })
+            value: value.to_string(),
+        }
+    }
+
+    fn encode_payload(payload: &[u8], mac: Hmac<Sha256>) -> String {
+        let tag = mac.finalize().into_bytes();
+
+        BASE64_URL_SAFE_NO_PAD.encode([payload, &tag[..TAG_LENGTH]].concat())
+    }
+
+    fn payload(transaction_id: i64, action: u8, issued_at: u32) -> Vec<u8> {
+        let mut data = vec![VERSION];
+        data.extend_from_slice(&transaction_id.to_be_bytes());
+        data.push(action);
+        data.extend_from_slice(&issued_at.to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn decodes_its_own_buttons() {
+        let binding = binding(b"test-secret");
+        let now = get_timestamp();
+        let value = encode(&button(42, "Execute"), &binding, now as u32);
+
+        let metadata = decode(value, &binding, MAX_AGE, now).unwrap();
+        assert_eq!(metadata.transaction_id, 42);
+        assert_eq!(metadata.value, "Execute");
+    }
+
+    #[test]
+    fn rejects_a_tampered_payload() {
+        let binding = binding(b"test-secret");
+        let now = get_timestamp();
+        let value = encode(&button(42, "Approve"), &binding, now as u32);
+
+        // Point the signed button at another proposal
+        let mut data = BASE64_URL_SAFE_NO_PAD.decode(value).unwrap();
+        data[8] = 43;
+
+        assert!(matches!(
+            decode(BASE64_URL_SAFE_NO_PAD.encode(data), &binding, MAX_AGE, now),
+            Err(InvalidButtonMetadataError::Forged)
+        ));
+    }
+
+    #[test]
+    fn rejects_a_tag_from_another_secret_or_deployment() {
+        let binding = binding(b"test-secret");
+        let now = get_timestamp();
+        let from_another_secret = encode(
+            &button(42, "Approve"),
+            &Binding {
+                secret: b"another-secret".to_vec(),
+                ..binding.clone()
+            },
+            now as u32,
+        );
+        let from_another_group = encode(
+            &button(42, "Approve"),
+            &Binding {
+                group_chat_id: ChatId(-200),
+                ..binding.clone()
+            },
+            now as u32,
+        );
+
+        for value in [from_another_secret, from_another_group] {
+            assert!(matches!(
+                decode(value, &binding, MAX_AGE, now),
+                Err(InvalidButtonMetadataError::Forged)
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_a_stale_button() {
+        let binding = binding(b"test-secret");
+        let now = get_timestamp();
+        let value = encode(
+            &button(42, "Approve"),
+            &binding,
+            (now - 31 * 24 * 60 * 60) as u32,
+        );
+
+        assert!(matches!(
+            decode(value.clone(), &binding, MAX_AGE, now),
+            Err(InvalidButtonMetadataError::Stale(42))
+        ));
+        // A maximum age of 0 turns the check off
+        assert!(decode(value, &binding, None, now).is_ok());
+    }
+
+    #[test]
+    fn rejects_unknown_actions_and_versions() {
+        let binding = binding(b"test-secret");
+        let now = get_timestamp();
+        let unknown_action = payload(42, 0, now as u32);
+        let out_of_range = payload(42, ACTIONS.len() as u8 + 1, now as u32);
+        let mut old_version = payload(42, 1, now as u32);
+        old_version[0] = VERSION + 1;
+
+        for payload in [unknown_action, out_of_range, old_version] {
+            assert!(matches!(
+                decode(
+                    encode_payload(&payload, sign(&binding, &payload)),
+                    &binding,
+                    MAX_AGE,
+                    now
+                ),
+                Err(InvalidButtonMetadataError::Malformed)
+            ));
+        }
+        assert!(matches!(
+            decode("not a button".to_string(), &binding, MAX_AGE, now),
+            Err(InvalidButtonMetadataError::Malformed)
+        ));
     }
 }
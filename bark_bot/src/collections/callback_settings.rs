@@ -0,0 +1,7 @@
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct CallbackSettings {
+    pub secret: Vec<u8>,           // HMAC key for the button callback data
+    pub max_age: Option<Duration>, // Age after which a button is stale, or None to accept any
+}
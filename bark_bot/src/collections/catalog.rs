@@ -0,0 +1,124 @@
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource, FluentValue};
+use unic_langid::LanguageIdentifier;
+
+/// Fluent files built into the bot, English has every message and fills in the ones
+/// missing from the others
+const LOCALES: &[(&str, &str)] = &[
+    ("en", include_str!("../../locales/en.ftl")),
+    ("es", include_str!("../../locales/es.ftl")),
+];
+
+pub const FALLBACK_LANGUAGE: &str = "en";
+
+/// Translations of the bot's messages, one bundle per language
+pub struct Catalog {
+    bundles: Vec<(&'static str, FluentBundle<FluentResource>)>,
+}
+
+impl Catalog {
+    pub fn load() -> Self {
+        let bundles = LOCALES
+            .iter()
+            .map(|(language, source)| {
+                let resource =
+                    FluentResource::try_new(source.to_string()).unwrap_or_else(|(_, errors)| {
+                        panic!("Invalid {} catalog: {:?}", language, errors)
+                    });
+                let mut bundle = FluentBundle::new_concurrent(vec![language
+                    .parse::<LanguageIdentifier>()
+                    .unwrap()]);
+                // Telegram would show the marks isolating each argument
+                bundle.set_use_isolating(false);
+                bundle.add_resource(resource).unwrap();
+
+                (*language, bundle)
+            })
+            .collect();
+
+        Catalog { bundles }
+    }
+
+    pub fn languages(&self) -> Vec<&'static str> {
+        self.bundles.iter().map(|(language, _)| *language).collect()
+    }
+
+    /// Language of the catalog matching a code like `es` or Telegram's `pt-br`
+    pub fn find_language(&self, code: &str) -> Option<&'static str> {
+        let code = code.trim().to_lowercase();
+        let primary = code.split(['-', '_']).next().unwrap_or_default();
+
+        self.languages()
+            .into_iter()
+            .find(|language| *language == code)
+            .or_else(|| {
+                self.languages()
+                    .into_iter()
+                    .find(|language| *language == primary)
+            })
+    }
+
+    pub fn translate(&self, language: &str, key: &str, args: &[(&str, FluentValue)]) -> String {
+        let args: FluentArgs = args.iter().cloned().collect();
+
+        [language, FALLBACK_LANGUAGE]
+            .iter()
+            .find_map(|language| {
+                let (_, bundle) = self.bundles.iter().find(|(name, _)| name == language)?;
+                let pattern = bundle.get_message(key)?.value()?;
+                let mut errors = vec![];
+                let text = bundle.format_pattern(pattern, Some(&args), &mut errors);
+
+                if !errors.is_empty() {
+                    log::warn!(
+                        "Message {} in {} is incomplete: {:?}",
+                        key,
+                        language,
+                        errors
+                    );
+                }
+
+                Some(text.to_string())
+            })
+            .unwrap_or_else(|| {
+                log::warn!("Message {} is missing from the catalog", key);
+                key.to_string()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::Command;
+    use teloxide::utils::command::BotCommands;
+
+    fn keys(source: &str) -> Vec<&str> {
+        source
+            .lines()
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_lowercase()))
+            .filter_map(|line| line.split_once(" =").map(|(key, _)| key))
+            .collect()
+    }
+
+    #[test]
+    fn every_language_has_every_message() {
+        let (_, english) = LOCALES[0];
+
+        for (language, source) in LOCALES {
+            assert_eq!(keys(source), keys(english), "{} differs from en", language);
+        }
+    }
+
+    #[test]
+    fn every_command_has_a_description() {
+        let catalog = Catalog::load();
+
+        for command in Command::bot_commands() {
+            let key = format!("command-{}", command.command.trim_start_matches('/'));
+
+            for language in catalog.languages() {
+                assert_ne!(catalog.translate(language, &key, &[]), key);
+            }
+        }
+    }
+}
@@ -0,0 +1,8 @@
+/// Language chosen for a chat with `/language`, overriding the one of each member's app
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ChatLanguage {
+    pub chat_id: String,
+    pub language: String,
+    pub updated_by: Option<String>,
+    pub updated_at: i64,
+}
@@ -1,15 +1,33 @@
 use teloxide::utils::command::BotCommands;
 
+/// The descriptions shown by /help are in the catalog, as `command-<name>`
 #[derive(BotCommands, Clone)]
-#[command(
-    rename_rule = "lowercase",
-    description = "These commands are supported:"
-)]
+#[command(rename_rule = "lowercase")]
 pub enum Command {
-    #[command(description = "Shows all supported commands")]
     Help,
-    #[command(description = "Initialize the flow.")]
-    Start,
-    #[command(description = "Cancel the Blink request.")]
+    Start(String),
     Cancel,
+    Pending,
+    History(String),
+    Treasury,
+    Grant(String),
+    Revoke(String),
+    Roles,
+    Link(String),
+    AddAddress(String),
+    RemoveAddress(String),
+    Addresses,
+    ImportAddresses(String),
+    Transfer(String),
+    Schedule(String),
+    Schedules,
+    PauseSchedule(String),
+    ResumeSchedule(String),
+    DeleteSchedule(String),
+    Approve(String),
+    Reject(String),
+    Execute(String),
+    Status(String),
+    Deeplink(String),
+    Language(String),
 }
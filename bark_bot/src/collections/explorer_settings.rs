@@ -0,0 +1,28 @@
+use std::str::FromStr;
+
+/// Block explorer used for the signature and account links
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Explorer {
+    Solana,
+    Solscan,
+    SolanaFm,
+}
+
+#[derive(Clone, Debug)]
+pub struct ExplorerSettings {
+    pub explorer: Explorer,
+    pub cluster: String, // mainnet-beta, devnet or testnet
+}
+
+impl FromStr for Explorer {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "solana" => Ok(Explorer::Solana),
+            "solscan" => Ok(Explorer::Solscan),
+            "solanafm" => Ok(Explorer::SolanaFm),
+            other => Err(format!("Unknown explorer: {}", other)),
+        }
+    }
+}
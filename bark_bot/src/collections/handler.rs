@@ -1,4 +1,4 @@
-use crate::collections::Action;
+use crate::collections::{Action, PayoutRow};
 use serde::{Deserialize, Serialize};
 use teloxide::types::UserId;
 
@@ -9,7 +9,9 @@ pub struct InternalActionData {
     pub url: String,
     pub action_title: String,
     pub action_description: String,
+    pub action_icon: String,
     pub user_id: UserId,
+    pub updated_at: i64, // Last step of the flow, for the idle timeout
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -21,8 +23,27 @@ pub struct ParametersData {
     pub parameters_values: Vec<String>,
     pub action_title: String,
     pub action_description: String,
+    pub action_icon: String,
     pub user_id: UserId,
+    pub confirm: bool, // Opened from a shared link, the values are confirmed before submitting
+    pub updated_at: i64, // Last step of the flow, for the idle timeout
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WalletLinkData {
+    pub pubkey: String,
+    pub message: String, // Text the user has to sign, includes the nonce
+    pub expires_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PayoutData {
+    pub rows: Vec<PayoutRow>,
+    pub file_name: String,
+    pub updated_at: i64,
+}
+
 #[derive(Clone, Default, Deserialize, Serialize)]
 pub enum Handler {
     // Start,
@@ -34,4 +55,24 @@ pub enum Handler {
     Parameters {
         data: ParametersData,
     },
+    WalletLink {
+        data: WalletLinkData,
+    },
+    Payout {
+        data: PayoutData,
+    },
+    Outdated, // Stored by an older bot and impossible to migrate, reset on the next update
+}
+
+impl Handler {
+    /// Time of the last step of the flow in progress, None when the user is idle
+    pub fn updated_at(&self) -> Option<i64> {
+        match self {
+            Handler::InternalAction { data } => Some(data.updated_at),
+            Handler::Parameters { data } => Some(data.updated_at),
+            Handler::WalletLink { data } => Some(data.updated_at),
+            Handler::Payout { data } => Some(data.updated_at),
+            Handler::BlinkUrl | Handler::Outdated => None,
+        }
+    }
 }
@@ -0,0 +1,68 @@
+use chrono::NaiveDate;
+
+const HISTORY_FILTER_PREFIX: &str = "hist";
+const HISTORY_FILTER_DATE_FORMAT: &str = "%Y%m%d";
+
+#[derive(Debug)]
+pub struct InvalidHistoryFilterError {}
+
+/// Page and filters of the `/history` listing, carried by its inline buttons
+#[derive(Clone, Debug, Default)]
+pub struct HistoryFilter {
+    pub page: u32,
+    pub state: Option<String>,   // Transaction state, as stored by the API
+    pub user_id: Option<String>, // Telegram id of the proposer
+    pub since: Option<NaiveDate>,
+    pub until: Option<NaiveDate>, // Inclusive
+}
+
+impl From<&HistoryFilter> for String {
+    fn from(filter: &HistoryFilter) -> Self {
+        let format_date = |date: &Option<NaiveDate>| {
+            date.map(|date| date.format(HISTORY_FILTER_DATE_FORMAT).to_string())
+                .unwrap_or_default()
+        };
+
+        format!(
+            "{}|{}|{}|{}|{}|{}",
+            HISTORY_FILTER_PREFIX,
+            filter.page,
+            filter.state.clone().unwrap_or_default(),
+            filter.user_id.clone().unwrap_or_default(),
+            format_date(&filter.since),
+            format_date(&filter.until),
+        )
+    }
+}
+
+impl TryFrom<String> for HistoryFilter {
+    type Error = InvalidHistoryFilterError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let parts: Vec<&str> = value.split('|').collect();
+        if parts.len() != 6 || parts[0] != HISTORY_FILTER_PREFIX {
+            return Err(InvalidHistoryFilterError {});
+        }
+
+        let optional = |part: &str| (!part.is_empty()).then(|| part.to_string());
+        let date = |part: &str| {
+            if part.is_empty() {
+                return Ok(None);
+            }
+
+            NaiveDate::parse_from_str(part, HISTORY_FILTER_DATE_FORMAT)
+                .map(Some)
+                .map_err(|_| InvalidHistoryFilterError {})
+        };
+
+        Ok(HistoryFilter {
+            page: parts[1]
+                .parse::<u32>()
+                .map_err(|_| InvalidHistoryFilterError {})?,
+            state: optional(parts[2]),
+            user_id: optional(parts[3]),
+            since: date(parts[4])?,
+            until: date(parts[5])?,
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use crate::requests::{claim_lease, release_lease};
+use crate::utils::IN_FLIGHT_LEASE_TTL;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+/// Transactions with a chain operation in flight, shared by the callback handlers. The
+/// other replicas of the bot are kept out through a lease on each transaction.
+#[derive(Clone, Default)]
+pub struct InFlight(Arc<Mutex<HashSet<i64>>>);
+
+/// Releases the transaction once the operation is over, even if the handler bails out
+pub struct InFlightGuard {
+    in_flight: InFlight,
+    transaction_id: i64,
+}
+
+impl InFlight {
+    pub async fn try_lock(&self, transaction_id: i64) -> Option<InFlightGuard> {
+        if !self.0.lock().unwrap().insert(transaction_id) {
+            return None;
+        }
+
+        // Another replica may be working on it, only the local slot is given back then
+        if !claim_lease(&lease_name(transaction_id), IN_FLIGHT_LEASE_TTL).await {
+            self.0.lock().unwrap().remove(&transaction_id);
+            return None;
+        }
+
+        Some(InFlightGuard {
+            in_flight: self.clone(),
+            transaction_id,
+        })
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        let in_flight = self.in_flight.clone();
+        let transaction_id = self.transaction_id;
+
+        // Freed locally once the lease is gone, so a new click can't renew it just before
+        tokio::spawn(async move {
+            release_lease(&lease_name(transaction_id)).await;
+            in_flight.0.lock().unwrap().remove(&transaction_id);
+        });
+    }
+}
+
+fn lease_name(transaction_id: i64) -> String {
+    format!("transaction:{}", transaction_id)
+}
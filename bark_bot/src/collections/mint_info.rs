@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MintInfo {
+    pub token_program: Pubkey, // Token or Token-2022, whichever owns the mint
+    pub decimals: u8,
+}
@@ -1,15 +1,87 @@
+pub mod address_book_entry;
 pub mod blink_metadata;
 pub mod button_metadata;
+pub mod callback_settings;
+pub mod catalog;
+pub mod chat_language;
 pub mod command;
 pub mod config;
+pub mod explorer_settings;
 pub mod handler;
+pub mod history_filter;
+pub mod in_flight;
+pub mod mint_info;
+pub mod payout_row;
+pub mod policy;
+pub mod policy_check;
+pub mod policy_violation;
+pub mod price_source;
+pub mod proposal_details;
+pub mod proposal_error;
+pub mod recipient_check;
+pub mod reminder_settings;
+pub mod request_progress;
+pub mod role;
+pub mod role_grant;
+pub mod schedule;
+pub mod shared_action;
+pub mod storage_settings;
+pub mod template;
+pub mod token_account_info;
+pub mod token_holding;
+pub mod token_metadata;
+pub mod tracked_storage;
 pub mod transaction;
+pub mod transaction_event;
+pub mod transaction_page;
+pub mod transfer;
+pub mod transfer_arguments;
 pub mod types;
+pub mod user_profile;
+pub mod versioned;
+pub mod wallet_link;
+pub mod webhook_settings;
 
+pub use address_book_entry::*;
 pub use blink_metadata::*;
 pub use button_metadata::*;
+pub use callback_settings::*;
+pub use catalog::*;
+pub use chat_language::*;
 pub use command::*;
 pub use config::*;
+pub use explorer_settings::*;
 pub use handler::*;
+pub use history_filter::*;
+pub use in_flight::*;
+pub use mint_info::*;
+pub use payout_row::*;
+pub use policy::*;
+pub use policy_check::*;
+pub use policy_violation::*;
+pub use price_source::*;
+pub use proposal_details::*;
+pub use proposal_error::*;
+pub use recipient_check::*;
+pub use reminder_settings::*;
+pub use request_progress::*;
+pub use role::*;
+pub use role_grant::*;
+pub use schedule::*;
+pub use shared_action::*;
+pub use storage_settings::*;
+pub use template::*;
+pub use token_account_info::*;
+pub use token_holding::*;
+pub use token_metadata::*;
+pub use tracked_storage::*;
 pub use transaction::*;
+pub use transaction_event::*;
+pub use transaction_page::*;
+pub use transfer::*;
+pub use transfer_arguments::*;
 pub use types::*;
+pub use user_profile::*;
+pub use versioned::*;
+pub use wallet_link::*;
+pub use webhook_settings::*;
@@ -0,0 +1,15 @@
+use crate::collections::MintInfo;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// Validated line of a payout CSV
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PayoutRow {
+    pub recipient: Pubkey,
+    pub label: Option<String>, // Address book label of the recipient
+    pub amount: String,        // As written in the file, for the summary
+    pub base_amount: u64,
+    pub mint: Option<(Pubkey, MintInfo)>, // None for SOL
+    pub create_account: bool,             // The recipient has no token account yet
+    pub memo: String,
+}
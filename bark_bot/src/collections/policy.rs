@@ -0,0 +1,30 @@
+use serde::Deserialize;
+
+/// Treasury rules loaded from the file at `POLICY_PATH`. Tokens are "SOL" or a mint
+/// address and amounts are in token units.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Policy {
+    #[serde(default)]
+    pub blocked_programs: Vec<String>, // Proposals calling these programs are refused
+    #[serde(default)]
+    pub limits: Vec<SpendingLimit>,
+    #[serde(default)]
+    pub approvals: Vec<ApprovalRule>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SpendingLimit {
+    pub token: String,
+    pub max_amount: f64,
+    pub window: Option<u64>, // Rolling window in seconds, or None to limit each proposal
+    #[serde(default)]
+    pub new_recipients_only: bool, // Only count recipients that were never paid before
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ApprovalRule {
+    pub token: String,
+    pub above: f64,
+    pub approvers: Vec<String>, // Member pubkeys that must all approve
+    pub label: Option<String>,  // Shown instead of the keys, e.g. "CFO"
+}
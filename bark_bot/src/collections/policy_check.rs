@@ -0,0 +1,9 @@
+use crate::collections::PolicyViolation;
+use solana_sdk::pubkey::Pubkey;
+
+/// Outcome of checking a proposal against the treasury policy
+#[derive(Debug, Default)]
+pub struct PolicyCheck {
+    pub violations: Vec<PolicyViolation>, // Any of them blocks the execution
+    pub unchecked_programs: Vec<Pubkey>,  // See `find_unchecked_programs`
+}
@@ -0,0 +1,137 @@
+use crate::utils::{
+    format_base_amount, format_duration, format_localized_amount, format_token, translate,
+};
+use solana_sdk::pubkey::Pubkey;
+
+/// Rule of the treasury policy a proposal breaks, rendered in the reader's language
+#[derive(Clone, Debug)]
+pub enum PolicyViolation {
+    BlockedProgram(Pubkey),
+    MissingApprovals {
+        token: String,
+        above: u64, // In base units, like the other amounts
+        decimals: u8,
+        approvers: String, // Label of the rule, or the keys that haven't approved yet
+    },
+    LimitExceeded {
+        token: String,
+        total: u128, // Including what was already sent in the window
+        max_amount: u64,
+        decimals: u8,
+        window: Option<u64>,
+        new_recipients_only: bool,
+    },
+}
+
+impl PolicyViolation {
+    /// Refused at creation instead of only blocking the execution
+    pub fn blocks_proposal(&self) -> bool {
+        matches!(self, PolicyViolation::BlockedProgram(_))
+    }
+
+    pub fn message(&self, language: &str) -> String {
+        match self {
+            PolicyViolation::BlockedProgram(program) => translate(
+                language,
+                "policy-blocked-program",
+                &[("program", program.to_string().into())],
+            ),
+            PolicyViolation::MissingApprovals {
+                token,
+                above,
+                decimals,
+                approvers,
+            } => translate(
+                language,
+                "policy-missing-approvals",
+                &[
+                    (
+                        "above",
+                        format_localized_amount(
+                            &format_base_amount(u128::from(*above), *decimals),
+                            language,
+                        )
+                        .into(),
+                    ),
+                    ("token", format_token(token).into()),
+                    ("approvers", approvers.clone().into()),
+                ],
+            ),
+            PolicyViolation::LimitExceeded {
+                token,
+                total,
+                max_amount,
+                decimals,
+                window,
+                new_recipients_only,
+            } => {
+                let period = match window {
+                    None => translate(language, "policy-per-proposal", &[]),
+                    Some(86400) => translate(language, "policy-per-day", &[]),
+                    Some(604800) => translate(language, "policy-per-week", &[]),
+                    Some(window) => translate(
+                        language,
+                        "policy-per-duration",
+                        &[("duration", format_duration(language, *window as i64).into())],
+                    ),
+                };
+                let key = if *new_recipients_only {
+                    "policy-limit-exceeded-new-recipients"
+                } else {
+                    "policy-limit-exceeded"
+                };
+
+                translate(
+                    language,
+                    key,
+                    &[
+                        (
+                            "total",
+                            format_localized_amount(
+                                &format_base_amount(*total, *decimals),
+                                language,
+                            )
+                            .into(),
+                        ),
+                        (
+                            "max",
+                            format_localized_amount(
+                                &format_base_amount(u128::from(*max_amount), *decimals),
+                                language,
+                            )
+                            .into(),
+                        ),
+                        ("token", format_token(token).into()),
+                        ("period", period.into()),
+                    ],
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_limits_in_the_language() {
+        let violation = PolicyViolation::LimitExceeded {
+            token: "SOL".to_string(),
+            total: 1_500_500_000_000,
+            max_amount: 1_000_000_000_000,
+            decimals: 9,
+            window: Some(604800),
+            new_recipients_only: true,
+        };
+
+        assert_eq!(
+            violation.message("en"),
+            "1,500.5 SOL would exceed the limit of 1,000 SOL to new recipients per week"
+        );
+        assert_eq!(
+            violation.message("es"),
+            "1.500,5 SOL superaría el límite de 1.000 SOL a destinatarios nuevos por semana"
+        );
+    }
+}
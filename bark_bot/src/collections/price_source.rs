@@ -0,0 +1,25 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Where USD prices for the treasury valuation come from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PriceSource {
+    File(PathBuf), // JSON file mapping mint addresses to prices
+    Http(String),  // Endpoint returning the same JSON map
+}
+
+impl FromStr for PriceSource {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+
+        if let Some(path) = value.strip_prefix("file:") {
+            Ok(PriceSource::File(PathBuf::from(path)))
+        } else if value.starts_with("http://") || value.starts_with("https://") {
+            Ok(PriceSource::Http(value.to_string()))
+        } else {
+            Err(format!("Unknown price source: {}", value))
+        }
+    }
+}
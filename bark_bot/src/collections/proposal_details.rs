@@ -0,0 +1,10 @@
+use crate::collections::{PolicyCheck, RecipientCheck, Transaction};
+
+/// Everything a proposal message shows besides the on-chain state and the votes
+#[derive(Debug, Default)]
+pub struct ProposalDetails {
+    pub policy: PolicyCheck,
+    pub recipients: Vec<RecipientCheck>,
+    pub batch: Vec<Transaction>, // Proposals of the same payout, including this one
+    pub language: &'static str,  // Of the group, every member reads the same message
+}
@@ -0,0 +1,8 @@
+use crate::collections::PolicyViolation;
+use solana_client::client_error::ClientError;
+
+#[derive(Debug)]
+pub enum ProposalError {
+    Refused(Vec<PolicyViolation>), // By the treasury policy, nothing was sent
+    Failed(ClientError),           // The chain didn't take the creation transaction
+}
@@ -0,0 +1,10 @@
+use crate::collections::{AddressBookEntry, TransferRecord};
+
+/// Recipient of a proposal transfer, with what the address book knows about it
+#[derive(Clone, Debug)]
+pub struct RecipientCheck {
+    pub transfer: TransferRecord,
+    pub label: Option<String>,
+    pub first_time: bool, // Never received an executed transfer before
+    pub lookalike_of: Option<AddressBookEntry>, // Saved entry sharing its prefix or suffix
+}
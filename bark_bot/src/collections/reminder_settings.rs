@@ -0,0 +1,54 @@
+use std::str::FromStr;
+use std::time::Duration;
+use teloxide::types::UserId;
+
+/// Where reminders for pending votes are delivered
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReminderTarget {
+    Direct, // Private message to every member that hasn't voted
+    Group,  // Reply to the proposal message mentioning those members
+    Both,
+}
+
+/// What happens to a proposal once it outlives the deadline
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpiryAction {
+    None,   // Only mark the proposal as stale and stop the reminders
+    Status, // Post an "expired" status and remove the vote buttons
+    Cancel, // Vote to cancel (or reject) the proposal on-chain as `expiry_executor`
+}
+
+#[derive(Clone, Debug)]
+pub struct ReminderSettings {
+    pub interval: Option<Duration>, // Time between reminders, or None to disable them
+    pub deadline: Option<Duration>, // Age after which a proposal is stale, or None to never expire
+    pub target: ReminderTarget,
+    pub expiry_action: ExpiryAction,
+    pub expiry_executor: Option<UserId>, // Whitelisted member whose key casts the expiry votes
+}
+
+impl FromStr for ReminderTarget {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "dm" | "direct" => Ok(ReminderTarget::Direct),
+            "group" => Ok(ReminderTarget::Group),
+            "both" => Ok(ReminderTarget::Both),
+            other => Err(format!("Unknown reminder target: {}", other)),
+        }
+    }
+}
+
+impl FromStr for ExpiryAction {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "none" => Ok(ExpiryAction::None),
+            "status" => Ok(ExpiryAction::Status),
+            "cancel" => Ok(ExpiryAction::Cancel),
+            other => Err(format!("Unknown expiry action: {}", other)),
+        }
+    }
+}
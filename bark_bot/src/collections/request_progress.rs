@@ -0,0 +1,7 @@
+/// Transient state of a proposal message while a member's action goes through
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RequestProgress {
+    Idle,
+    InFlight(String), // e.g. "Approving…", shown instead of the vote buttons
+    Failed(String),   // Error line shown next to the restored buttons
+}
@@ -0,0 +1,42 @@
+use std::str::FromStr;
+
+/// Permissions of a Telegram user in the group, independent from Squads membership
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    Proposer, // Can turn blinks into proposals
+    Voter,    // Can approve, reject and cancel proposals
+    Executor, // Can execute approved proposals
+    Admin,    // Has every role and manages the others
+    Viewer,   // Can list proposals and balances, implied by any other role
+}
+
+pub const ROLES: [Role; 5] = [
+    Role::Proposer,
+    Role::Voter,
+    Role::Executor,
+    Role::Admin,
+    Role::Viewer,
+];
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Proposer => "proposer",
+            Role::Voter => "voter",
+            Role::Executor => "executor",
+            Role::Admin => "admin",
+            Role::Viewer => "viewer",
+        }
+    }
+}
+
+impl FromStr for Role {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        ROLES
+            .into_iter()
+            .find(|role| role.as_str() == value.trim().to_lowercase())
+            .ok_or(format!("Unknown role: {}", value.trim()))
+    }
+}
@@ -0,0 +1,8 @@
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RoleGrant {
+    pub chat_id: String,
+    pub user_id: String,
+    pub role: String,
+    pub granted_by: Option<String>,
+    pub created_at: i64,
+}
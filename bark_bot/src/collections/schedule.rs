@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// Recurring proposal, created by the scheduler on behalf of `user_id`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Schedule {
+    pub id: i64,
+    pub chat_id: String,
+    pub user_id: String,
+    pub cadence: String, // Cron expression with seconds
+    pub action: String,  // JSON of a ScheduledAction
+    pub title: String,
+    pub next_run_at: Option<i64>, // None once the schedule has ended
+    pub ends_at: Option<i64>,
+    pub paused: bool,
+    pub last_run_at: Option<i64>,
+    pub last_transaction_id: Option<i64>,
+    pub created_at: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum ScheduledAction {
+    Transfer { arguments: String }, // Same arguments as /transfer, checked again at each run
+    Blink { url: String },          // Action URL posted to like a chosen Blink action
+}
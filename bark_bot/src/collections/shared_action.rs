@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Blink shared through inline mode, its id is the payload of the `/start` deep link
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SharedAction {
+    pub id: i64,
+    pub url: String,
+    pub action: String, // Label of the chosen action, empty for the whole Blink
+    pub parameters: String, // JSON list of [name, value] pairs filled in beforehand
+    pub user_id: String,
+    pub created_at: i64,
+}
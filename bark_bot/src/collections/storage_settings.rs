@@ -0,0 +1,35 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Where the dialogue state is kept, Redis lets several replicas share it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StorageBackend {
+    Memory,
+    Sqlite(PathBuf),
+    Redis(String), // Connection URL, e.g. redis://127.0.0.1:6379/1
+}
+
+/// Encoding of the dialogue state in SQLite or Redis
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StorageSerializer {
+    Json,
+    Cbor,
+}
+
+#[derive(Clone, Debug)]
+pub struct StorageSettings {
+    pub backend: StorageBackend,
+    pub serializer: StorageSerializer,
+}
+
+impl FromStr for StorageSerializer {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "json" => Ok(StorageSerializer::Json),
+            "cbor" => Ok(StorageSerializer::Cbor),
+            other => Err(format!("Unknown storage serializer: {}", other)),
+        }
+    }
+}
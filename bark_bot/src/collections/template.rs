@@ -0,0 +1,29 @@
+/// Messages whose wording operators can change per chat
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Template {
+    Preview,  // Blink shared in a chat or about to be proposed
+    Proposal, // Proposal message in the group
+    Receipt,  // Confirmation for the proposer once the proposal is posted
+    Error,    // A Blink that couldn't be loaded or proposed
+}
+
+impl Template {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Template::Preview => "preview",
+            Template::Proposal => "proposal",
+            Template::Receipt => "receipt",
+            Template::Error => "error",
+        }
+    }
+
+    /// Built-in source, used unless an operator overrides it
+    pub fn default_source(&self) -> &'static str {
+        match self {
+            Template::Preview => include_str!("../../templates/preview.hbs"),
+            Template::Proposal => include_str!("../../templates/proposal.hbs"),
+            Template::Receipt => include_str!("../../templates/receipt.hbs"),
+            Template::Error => include_str!("../../templates/error.hbs"),
+        }
+    }
+}
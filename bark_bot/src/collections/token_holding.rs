@@ -0,0 +1,8 @@
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Clone, Debug)]
+pub struct TokenHolding {
+    pub mint: Pubkey,
+    pub amount: String, // Already scaled by the mint decimals
+    pub ui_amount: f64,
+}
@@ -0,0 +1,5 @@
+#[derive(Clone, Debug)]
+pub struct TokenMetadata {
+    pub name: String,
+    pub symbol: String,
+}
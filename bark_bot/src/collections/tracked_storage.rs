@@ -0,0 +1,51 @@
+use crate::collections::{Handler, JoinStorage};
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use teloxide::{dispatching::dialogue::Storage, types::ChatId};
+
+type StorageFuture<T> =
+    Pin<Box<dyn Future<Output = Result<T, Box<dyn std::error::Error + Send + Sync>>> + Send>>;
+
+/// Chats with a flow in progress since the bot started, walked by the idle sweep when
+/// the dialogues are kept in memory
+#[derive(Clone, Default)]
+pub struct ActiveDialogues(Arc<Mutex<HashSet<ChatId>>>);
+
+/// Dialogue storage that keeps `ActiveDialogues` up to date, the in-memory storage
+/// can't list its dialogues
+pub struct TrackedStorage {
+    pub inner: JoinStorage,
+    pub active: ActiveDialogues,
+}
+
+impl ActiveDialogues {
+    pub fn chat_ids(&self) -> Vec<ChatId> {
+        self.0.lock().unwrap().iter().copied().collect()
+    }
+}
+
+impl Storage<Handler> for TrackedStorage {
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    fn remove_dialogue(self: Arc<Self>, chat_id: ChatId) -> StorageFuture<()> {
+        self.active.0.lock().unwrap().remove(&chat_id);
+
+        self.inner.clone().remove_dialogue(chat_id)
+    }
+
+    fn update_dialogue(self: Arc<Self>, chat_id: ChatId, dialogue: Handler) -> StorageFuture<()> {
+        if dialogue.updated_at().is_some() {
+            self.active.0.lock().unwrap().insert(chat_id);
+        } else {
+            self.active.0.lock().unwrap().remove(&chat_id);
+        }
+
+        self.inner.clone().update_dialogue(chat_id, dialogue)
+    }
+
+    fn get_dialogue(self: Arc<Self>, chat_id: ChatId) -> StorageFuture<Option<Handler>> {
+        self.inner.clone().get_dialogue(chat_id)
+    }
+}
@@ -6,4 +6,14 @@ pub struct Transaction {
     pub signature: String,
     pub status: u8,
     pub message_id: Option<String>,
+    pub created_at: i64,
+    pub state: String,
+    pub reminder_count: u32,
+    pub last_reminder_at: Option<i64>,
+    pub expired_at: Option<i64>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub parameters: Option<String>, // JSON list of [label, value] pairs
+    pub batch_id: Option<String>,   // Shared by the proposals of a split payout
+    pub icon_url: Option<String>,   // Blink icon shown above the proposal message
 }
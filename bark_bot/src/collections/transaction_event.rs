@@ -0,0 +1,9 @@
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct TransactionEvent {
+    pub id: i64,
+    pub transaction_id: i64,
+    pub kind: String, // create, approve, reject, execute or cancel
+    pub user_id: Option<String>,
+    pub signature: Option<String>,
+    pub created_at: i64,
+}
@@ -0,0 +1,7 @@
+use crate::collections::Transaction;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct TransactionPage {
+    pub transactions: Vec<Transaction>,
+    pub total: i64,
+}
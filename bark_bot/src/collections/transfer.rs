@@ -0,0 +1,43 @@
+use crate::utils::format_base_amount;
+use serde::{Deserialize, Serialize};
+
+/// Transfer decoded from the instructions of a proposal
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Transfer {
+    pub token: String, // SOL or the mint address
+    pub recipient: String,
+    pub base_amount: u64,
+    pub decimals: u8,
+}
+
+/// Transfer as recorded by the API, with the time its proposal was executed
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TransferRecord {
+    pub id: i64,
+    pub transaction_id: i64,
+    pub token: String,
+    pub recipient: String,
+    pub amount: f64,              // In token units, rounded
+    pub base_amount: Option<u64>, // Missing on token transfers recorded before it was kept
+    pub decimals: Option<u8>,
+    pub created_at: i64,
+    pub executed_at: Option<i64>,
+}
+
+impl TransferRecord {
+    /// Exact amount in base units, older records are scaled back with the mint's decimals
+    pub fn base_units(&self, decimals: u8) -> u64 {
+        self.base_amount
+            .unwrap_or_else(|| (self.amount * 10f64.powi(i32::from(decimals))).round() as u64)
+    }
+
+    /// Amount in token units, exact when the base units were recorded
+    pub fn display_amount(&self) -> String {
+        match (self.base_amount, self.decimals) {
+            (Some(base_amount), Some(decimals)) => {
+                format_base_amount(u128::from(base_amount), decimals)
+            }
+            _ => self.amount.to_string(),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::collections::MintInfo;
+use crate::utils::{format_localized_amount, format_pubkey, format_token, translate};
+use solana_sdk::pubkey::Pubkey;
+
+/// Validated arguments of a `/transfer` or a scheduled transfer
+#[derive(Clone, Debug)]
+pub struct TransferArguments {
+    pub recipient: Pubkey,
+    pub label: Option<String>, // Address book label of the recipient
+    pub amount: String,        // As written by the user
+    pub base_amount: u64,
+    pub mint: Option<(Pubkey, MintInfo)>, // None for SOL
+}
+
+impl TransferArguments {
+    pub fn title(&self, language: &str) -> String {
+        let token = self.mint.as_ref().map_or("SOL".to_string(), |(mint, _)| {
+            format_token(&mint.to_string())
+        });
+
+        translate(
+            language,
+            "transfer-title",
+            &[
+                (
+                    "amount",
+                    format_localized_amount(&self.amount, language).into(),
+                ),
+                ("token", token.into()),
+            ],
+        )
+    }
+
+    pub fn description(&self, language: &str) -> String {
+        match &self.label {
+            Some(label) => translate(
+                language,
+                "transfer-description-label",
+                &[
+                    ("label", label.clone().into()),
+                    ("address", format_pubkey(&self.recipient).into()),
+                ],
+            ),
+            None => translate(
+                language,
+                "transfer-description",
+                &[("address", self.recipient.to_string().into())],
+            ),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+/// Telegram name, signer key and linked wallet of a member, as last seen by the bot
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct UserProfile {
+    pub user_id: String,
+    pub name: String,
+    pub pubkey: Option<String>, // Held by the bot, signs the member's votes
+    pub updated_at: i64,
+    pub wallet: Option<String>, // Proven with /link
+}
@@ -0,0 +1,54 @@
+use crate::collections::Handler;
+use crate::utils::{migrate_dialogue, DIALOGUE_VERSION};
+use serde_json::{json, Value};
+use std::fmt::Display;
+use teloxide::dispatching::dialogue::serializer::Serializer;
+
+/// Stores dialogues as `{"version", "state"}` so they survive changes to `Handler`.
+/// Anything that can't be read or migrated comes back as `Handler::Outdated`.
+pub struct Versioned<S>(pub S);
+
+impl<S> Serializer<Handler> for Versioned<S>
+where
+    S: Serializer<Value>,
+    S::Error: Display,
+{
+    type Error = String;
+
+    fn serialize(&self, handler: &Handler) -> Result<Vec<u8>, Self::Error> {
+        let state = serde_json::to_value(handler).map_err(|e| e.to_string())?;
+
+        self.0
+            .serialize(&json!({ "version": DIALOGUE_VERSION, "state": state }))
+            .map_err(|e| e.to_string())
+    }
+
+    fn deserialize(&self, data: &[u8]) -> Result<Handler, Self::Error> {
+        let stored = match self.0.deserialize(data) {
+            Ok(stored) => stored,
+            Err(e) => {
+                log::warn!("Dropping an unreadable dialogue: {}", e);
+                return Ok(Handler::Outdated);
+            }
+        };
+
+        // Dialogues written before the envelope are the bare state
+        let (version, state) = match stored {
+            Value::Object(mut envelope)
+                if envelope.contains_key("version") && envelope.contains_key("state") =>
+            {
+                let version = envelope["version"].as_u64().unwrap_or(u64::MAX) as u32;
+                (version, envelope.remove("state").unwrap())
+            }
+            state => (0, state),
+        };
+
+        let handler = migrate_dialogue(version, state)
+            .and_then(|state| serde_json::from_value::<Handler>(state).ok());
+
+        Ok(handler.unwrap_or_else(|| {
+            log::warn!("Dropping a dialogue stored with version {}", version);
+            Handler::Outdated
+        }))
+    }
+}
@@ -0,0 +1,7 @@
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct WalletLink {
+    pub user_id: String,
+    pub pubkey: String,
+    pub signature: String,
+    pub linked_at: i64,
+}
@@ -0,0 +1,9 @@
+use std::net::SocketAddr;
+use url::Url;
+
+#[derive(Clone)]
+pub struct WebhookSettings {
+    pub url: Url,                     // Public HTTPS address Telegram posts the updates to
+    pub address: SocketAddr,          // Local address to listen on, usually behind a TLS proxy
+    pub secret_token: Option<String>, // Checked against the header Telegram sends with each update
+}
@@ -0,0 +1,68 @@
+use crate::collections::{HandlerResult, Role};
+use crate::requests::{get_address_book, save_address};
+use crate::utils::{find_lookalike, get_group_chat_id, get_language, has_role, translate};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use teloxide::{prelude::*, types::ParseMode, utils::html};
+
+pub async fn add_address(bot: Bot, msg: Message, args: String) -> HandlerResult {
+    let Some(admin) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(admin)).await;
+
+    if !has_role(admin.id, Role::Admin).await {
+        bot.send_message(
+            msg.chat.id,
+            translate(language, "address-book-admin-only", &[]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let (address, label) = match args.trim().split_once(char::is_whitespace) {
+        Some((address, label)) if Pubkey::from_str(address).is_ok() => {
+            (address.to_string(), label.trim().to_string())
+        }
+        _ => {
+            bot.send_message(msg.chat.id, translate(language, "add-address-usage", &[]))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let chat_id = get_group_chat_id();
+    let address_book = get_address_book(chat_id).await;
+    let entry = save_address(chat_id, &address, &label, admin.id).await;
+
+    let mut text = translate(
+        language,
+        "address-saved",
+        &[
+            ("address", entry.address.clone().into()),
+            ("label", html::escape(&entry.label).into()),
+        ],
+    );
+
+    // Saving a poisoned address would make its lookalikes look trusted
+    if let Some(lookalike) = find_lookalike(&address, &address_book) {
+        text.push_str(&format!(
+            "\n\n{}",
+            translate(
+                language,
+                "address-lookalike",
+                &[
+                    ("label", html::escape(&lookalike.label).into()),
+                    ("address", lookalike.address.clone().into()),
+                ],
+            )
+        ));
+    }
+
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+
+    Ok(())
+}
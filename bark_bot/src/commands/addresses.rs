@@ -0,0 +1,53 @@
+use crate::collections::{HandlerResult, Role};
+use crate::requests::get_address_book;
+use crate::utils::{get_group_chat_id, get_language, has_role, translate};
+use teloxide::{prelude::*, types::ParseMode, utils::html};
+
+pub async fn addresses(bot: Bot, msg: Message) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    if !has_role(user.id, Role::Viewer).await {
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "role-required",
+                &[("role", Role::Viewer.as_str().into())],
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let lines: Vec<String> = get_address_book(get_group_chat_id())
+        .await
+        .iter()
+        .map(|entry| {
+            format!(
+                "• <b>{}</b>: <code>{}</code>",
+                html::escape(&entry.label),
+                entry.address
+            )
+        })
+        .collect();
+
+    let text = if lines.is_empty() {
+        translate(language, "address-book-empty", &[])
+    } else {
+        format!(
+            "{}\n\n{}",
+            translate(language, "address-book-title", &[]),
+            lines.join("\n")
+        )
+    };
+
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+
+    Ok(())
+}
@@ -1,8 +1,11 @@
 use crate::collections::{HandlerResult, MyDialogue};
+use crate::utils::{get_language, translate};
 use teloxide::prelude::*;
 
 pub async fn cancel(bot: Bot, dialogue: MyDialogue, msg: Message) -> HandlerResult {
-    bot.send_message(msg.chat.id, "Cancelling the Blink request.")
+    let language = get_language(msg.chat.id, msg.from.as_ref()).await;
+
+    bot.send_message(msg.chat.id, translate(language, "request-cancelled", &[]))
         .await?;
     dialogue.exit().await?;
     Ok(())
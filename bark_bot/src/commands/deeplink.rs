@@ -0,0 +1,138 @@
+use crate::collections::{HandlerResult, Role};
+use crate::requests::{create_shared_action, get_blink_metadata};
+use crate::utils::{
+    get_language, has_role, parse_action_url, translate, SHARED_ACTION_START_PREFIX,
+};
+use teloxide::{
+    prelude::*,
+    types::{Me, ParseMode},
+    utils::html,
+};
+
+pub async fn deeplink(bot: Bot, me: Me, msg: Message, args: String) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    if !has_role(user.id, Role::Proposer).await {
+        bot.send_message(msg.chat.id, translate(language, "no-proposer-role", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    let mut words = args.split_whitespace();
+    let Some(url) = words.next().and_then(parse_action_url) else {
+        bot.send_message(msg.chat.id, translate(language, "deeplink-usage", &[]))
+            .await?;
+        return Ok(());
+    };
+
+    // Words with an equal sign are presets, the others make up the action label
+    let (presets, label_words): (Vec<&str>, Vec<&str>) = words.partition(|word| word.contains('='));
+    let label = label_words.join(" ");
+    let presets: Vec<(String, String)> = presets
+        .iter()
+        .filter_map(|preset| preset.split_once('='))
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+
+    let metadata = match get_blink_metadata(&url.to_string()).await {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            bot.send_message(
+                msg.chat.id,
+                format!("{}: {}", translate(language, "blink-error", &[]), e),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let actions = metadata
+        .links
+        .map(|links| links.actions)
+        .unwrap_or_default();
+    let action = actions.iter().find(|action| action.label == label);
+
+    if !label.is_empty() && action.is_none() {
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "deeplink-unknown-action",
+                &[
+                    ("action", label.clone().into()),
+                    (
+                        "available",
+                        actions
+                            .iter()
+                            .map(|action| action.label.as_str())
+                            .collect::<Vec<&str>>()
+                            .join(", ")
+                            .into(),
+                    ),
+                ],
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let names: Vec<&str> = action
+        .and_then(|action| action.parameters.as_ref())
+        .map(|parameters| parameters.iter().map(|p| p.name.as_str()).collect())
+        .unwrap_or_default();
+    if let Some((name, _)) = presets
+        .iter()
+        .find(|(name, _)| !names.contains(&name.as_str()))
+    {
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "deeplink-unknown-parameter",
+                &[
+                    ("parameter", name.clone().into()),
+                    ("available", names.join(", ").into()),
+                ],
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let shared_action = create_shared_action(url.as_str(), &label, &presets, user.id).await;
+
+    let mut start_url = me.tme_url();
+    start_url.set_query(Some(&format!(
+        "start={}{}",
+        SHARED_ACTION_START_PREFIX, shared_action.id
+    )));
+
+    bot.send_message(
+        msg.chat.id,
+        translate(
+            language,
+            "deeplink-created",
+            &[
+                ("title", html::escape(&metadata.title).into()),
+                (
+                    "action",
+                    if label.is_empty() {
+                        "".to_string()
+                    } else {
+                        format!(" → {}", html::escape(&label))
+                    }
+                    .into(),
+                ),
+                ("url", start_url.to_string().into()),
+            ],
+        ),
+    )
+    .parse_mode(ParseMode::Html)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use crate::collections::HandlerResult;
+use crate::utils::{get_language, get_managed_schedule, translate};
+use teloxide::prelude::*;
+
+pub async fn delete_schedule(bot: Bot, msg: Message, args: String) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    let schedule = match get_managed_schedule(user.id, &args, language).await {
+        Ok(schedule) => schedule,
+        Err(e) => {
+            bot.send_message(msg.chat.id, e).await?;
+            return Ok(());
+        }
+    };
+
+    crate::requests::delete_schedule(schedule.id).await;
+
+    log::info!("{} deleted schedule {}", user.id, schedule.id);
+
+    bot.send_message(
+        msg.chat.id,
+        translate(language, "schedule-deleted", &[("id", schedule.id.into())]),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -1,8 +1,23 @@
 use crate::collections::{Command, HandlerResult};
+use crate::utils::{get_language, translate};
 use teloxide::{prelude::*, utils::command::BotCommands};
 
 pub async fn help(bot: Bot, msg: Message) -> HandlerResult {
-    bot.send_message(msg.chat.id, Command::descriptions().to_string())
-        .await?;
+    let language = get_language(msg.chat.id, msg.from.as_ref()).await;
+    let commands = Command::bot_commands()
+        .iter()
+        .map(|command| {
+            let key = format!("command-{}", command.command.trim_start_matches('/'));
+
+            format!("{} — {}", command.command, translate(language, &key, &[]))
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    bot.send_message(
+        msg.chat.id,
+        translate(language, "help", &[("commands", commands.into())]),
+    )
+    .await?;
     Ok(())
 }
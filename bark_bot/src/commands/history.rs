@@ -0,0 +1,326 @@
+use crate::collections::{HandlerResult, HistoryFilter, Role};
+use crate::requests::{get_transactions, GetTransactionsQuery};
+use crate::utils::{
+    format_transaction_state, get_disabled_link_preview, get_language, get_transaction_message_url,
+    has_role, translate,
+};
+use chrono::{DateTime, Days, NaiveDate};
+use teloxide::{
+    prelude::*,
+    types::{InlineKeyboardButton, InlineKeyboardMarkup, ParseMode},
+    utils::html,
+};
+
+const HISTORY_PAGE_SIZE: u32 = 5;
+// English names accepted by `status=`, next to the stored state
+const HISTORY_STATES: [(&str, &str); 6] = [
+    ("All", ""),
+    ("Active", "active"),
+    ("Ready", "execute_ready"),
+    ("Executed", "executed"),
+    ("Rejected", "rejected"),
+    ("Cancelled", "cancelled"),
+];
+
+pub async fn history(bot: Bot, msg: Message, args: String) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    if !has_role(user.id, Role::Viewer).await {
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "role-required",
+                &[("role", Role::Viewer.as_str().into())],
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let filter = match parse_history_filter(&args, &msg, language) {
+        Ok(filter) => filter,
+        Err(e) => {
+            bot.send_message(
+                msg.chat.id,
+                translate(language, "history-usage", &[("error", e.into())]),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let (text, keyboard) = get_history_page(&filter, language).await;
+
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .link_preview_options(get_disabled_link_preview())
+        .reply_markup(keyboard)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn history_page(bot: Bot, q: CallbackQuery, filter: HistoryFilter) -> HandlerResult {
+    let Some(message) = &q.message else {
+        return Ok(());
+    };
+    let language = get_language(message.chat().id, Some(&q.from)).await;
+
+    if !has_role(q.from.id, Role::Viewer).await {
+        bot.answer_callback_query(&q.id)
+            .text(translate(
+                language,
+                "role-required",
+                &[("role", Role::Viewer.as_str().into())],
+            ))
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    }
+
+    let _ = bot.answer_callback_query(&q.id).await;
+
+    let (text, keyboard) = get_history_page(&filter, language).await;
+
+    let _ = bot
+        .edit_message_text(message.chat().id, message.id(), text)
+        .parse_mode(ParseMode::Html)
+        .link_preview_options(get_disabled_link_preview())
+        .reply_markup(keyboard)
+        .await;
+
+    Ok(())
+}
+
+fn parse_history_filter(
+    args: &str,
+    msg: &Message,
+    language: &str,
+) -> Result<HistoryFilter, String> {
+    let mut filter = HistoryFilter::default();
+    let invalid =
+        |key: &str, value: &str| translate(language, key, &[("value", value.to_string().into())]);
+
+    for argument in args.split_whitespace() {
+        let (key, value) = argument
+            .split_once('=')
+            .ok_or_else(|| invalid("history-invalid-filter", argument))?;
+
+        match key {
+            "status" | "state" => {
+                let state = HISTORY_STATES
+                    .iter()
+                    .find(|(label, state)| {
+                        !state.is_empty() && (label.eq_ignore_ascii_case(value) || *state == value)
+                    })
+                    .ok_or_else(|| invalid("history-unknown-status", value))?;
+                filter.state = Some(state.1.to_string());
+            }
+            "proposer" => {
+                filter.user_id = match value {
+                    "me" => msg.from.as_ref().map(|user| user.id.to_string()),
+                    _ => Some(
+                        value
+                            .parse::<u64>()
+                            .map_err(|_| invalid("history-invalid-proposer", value))?
+                            .to_string(),
+                    ),
+                };
+            }
+            "from" | "since" => {
+                filter.since = Some(
+                    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                        .map_err(|_| invalid("history-invalid-date", value))?,
+                );
+            }
+            "to" | "until" => {
+                filter.until = Some(
+                    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                        .map_err(|_| invalid("history-invalid-date", value))?,
+                );
+            }
+            _ => return Err(invalid("history-unknown-filter", key)),
+        }
+    }
+
+    Ok(filter)
+}
+
+async fn get_history_page(
+    filter: &HistoryFilter,
+    language: &str,
+) -> (String, InlineKeyboardMarkup) {
+    let start_of_day = |date: NaiveDate| date.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp();
+
+    let page = get_transactions(&GetTransactionsQuery {
+        state: filter.state.clone(),
+        user_id: filter.user_id.clone(),
+        since: filter.since.map(start_of_day),
+        until: filter
+            .until
+            .and_then(|until| until.checked_add_days(Days::new(1)))
+            .map(start_of_day),
+        limit: Some(HISTORY_PAGE_SIZE.into()),
+        offset: Some((filter.page * HISTORY_PAGE_SIZE).into()),
+        ..Default::default()
+    })
+    .await;
+
+    let pages = (page.total as u32).div_ceil(HISTORY_PAGE_SIZE).max(1);
+
+    let lines: Vec<String> =
+        page.transactions
+            .iter()
+            .map(|transaction_entry| {
+                let date = DateTime::from_timestamp(transaction_entry.created_at, 0)
+                    .map(|date| date.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default();
+                let link = get_transaction_message_url(transaction_entry)
+                    .map(|url| {
+                        format!(
+                            " · <a href=\"{}\">{}</a>",
+                            url,
+                            translate(language, "view-proposal", &[])
+                        )
+                    })
+                    .unwrap_or_default();
+
+                translate(
+                    language,
+                    "history-proposal",
+                    &[
+                        ("index", transaction_entry.transaction_index.into()),
+                        (
+                            "title",
+                            html::escape(
+                                &transaction_entry.title.clone().unwrap_or_else(|| {
+                                    translate(language, "proposal-untitled", &[])
+                                }),
+                            )
+                            .into(),
+                        ),
+                        (
+                            "state",
+                            format_transaction_state(language, &transaction_entry.state).into(),
+                        ),
+                        ("date", date.into()),
+                        ("user_id", transaction_entry.user_id.clone().into()),
+                        ("link", link.into()),
+                    ],
+                )
+            })
+            .collect();
+
+    let mut filters: Vec<String> = vec![];
+    if let Some(state) = &filter.state {
+        filters.push(translate(
+            language,
+            "history-filter-status",
+            &[("state", format_transaction_state(language, state).into())],
+        ));
+    }
+    if let Some(user_id) = &filter.user_id {
+        filters.push(translate(
+            language,
+            "history-filter-proposer",
+            &[("user_id", user_id.clone().into())],
+        ));
+    }
+    if let Some(since) = filter.since {
+        filters.push(translate(
+            language,
+            "history-filter-from",
+            &[("date", since.to_string().into())],
+        ));
+    }
+    if let Some(until) = filter.until {
+        filters.push(translate(
+            language,
+            "history-filter-to",
+            &[("date", until.to_string().into())],
+        ));
+    }
+
+    let header = translate(
+        language,
+        "history-title",
+        &[
+            (
+                "filters",
+                if filters.is_empty() {
+                    "".to_string()
+                } else {
+                    format!(" ({})", filters.join(", "))
+                }
+                .into(),
+            ),
+            ("page", (filter.page + 1).into()),
+            ("pages", pages.into()),
+            ("total", page.total.into()),
+        ],
+    );
+
+    let text = if lines.is_empty() {
+        format!("{}\n\n{}", header, translate(language, "history-none", &[]))
+    } else {
+        format!("{}\n\n{}", header, lines.join("\n\n"))
+    };
+
+    let state_buttons: Vec<InlineKeyboardButton> = HISTORY_STATES
+        .iter()
+        .map(|(_, state)| {
+            let selected = filter.state.as_deref().unwrap_or("") == *state;
+            let label = translate(
+                language,
+                "history-state-button",
+                &[("state", state.to_string().into())],
+            );
+
+            InlineKeyboardButton::callback(
+                if selected {
+                    format!("• {}", label)
+                } else {
+                    label
+                },
+                &HistoryFilter {
+                    page: 0,
+                    state: (!state.is_empty()).then(|| state.to_string()),
+                    ..filter.clone()
+                },
+            )
+        })
+        .collect();
+
+    let mut page_buttons: Vec<InlineKeyboardButton> = vec![];
+    if filter.page > 0 {
+        page_buttons.push(InlineKeyboardButton::callback(
+            translate(language, "history-previous", &[]),
+            &HistoryFilter {
+                page: filter.page - 1,
+                ..filter.clone()
+            },
+        ));
+    }
+    if filter.page + 1 < pages {
+        page_buttons.push(InlineKeyboardButton::callback(
+            translate(language, "history-next", &[]),
+            &HistoryFilter {
+                page: filter.page + 1,
+                ..filter.clone()
+            },
+        ));
+    }
+
+    let mut keyboard: Vec<Vec<InlineKeyboardButton>> = state_buttons
+        .chunks(3)
+        .map(|buttons| buttons.to_vec())
+        .collect();
+    keyboard.push(page_buttons);
+
+    (text, InlineKeyboardMarkup::new(keyboard))
+}
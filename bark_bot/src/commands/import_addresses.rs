@@ -0,0 +1,121 @@
+use crate::collections::{HandlerResult, Role};
+use crate::requests::{get_address_book, save_address};
+use crate::utils::{
+    download_text_file, find_lookalike, get_group_chat_id, get_language, has_role, translate,
+};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use teloxide::{prelude::*, types::ParseMode, utils::html};
+
+/// Imports `address,label` lines sent after the command or in a replied CSV file
+pub async fn import_addresses(bot: Bot, msg: Message, args: String) -> HandlerResult {
+    let Some(admin) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(admin)).await;
+
+    if !has_role(admin.id, Role::Admin).await {
+        bot.send_message(
+            msg.chat.id,
+            translate(language, "address-book-admin-only", &[]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let csv = match msg.reply_to_message().and_then(|reply| reply.document()) {
+        Some(document) if args.trim().is_empty() => download_text_file(&bot, document).await?,
+        _ => args,
+    };
+
+    let (rows, skipped) = parse_csv(&csv);
+
+    if rows.is_empty() {
+        bot.send_message(
+            msg.chat.id,
+            translate(language, "import-addresses-usage", &[]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let chat_id = get_group_chat_id();
+    let address_book = get_address_book(chat_id).await;
+    let mut warnings: Vec<String> = vec![];
+
+    for (address, label) in &rows {
+        if let Some(lookalike) = find_lookalike(address, &address_book) {
+            warnings.push(translate(
+                language,
+                "import-addresses-lookalike",
+                &[
+                    ("label", html::escape(label).into()),
+                    ("lookalike", html::escape(&lookalike.label).into()),
+                    ("address", lookalike.address.clone().into()),
+                ],
+            ));
+        }
+
+        save_address(chat_id, address, label, admin.id).await;
+    }
+
+    let mut text = translate(
+        language,
+        "import-addresses-done",
+        &[("count", rows.len().into())],
+    );
+
+    if skipped > 0 {
+        text.push_str(&format!(
+            " {}",
+            translate(
+                language,
+                "import-addresses-skipped",
+                &[("count", skipped.into())],
+            )
+        ));
+    }
+
+    if !warnings.is_empty() {
+        text.push_str(&format!(
+            "\n\n{}\n{}",
+            translate(language, "import-addresses-lookalikes", &[]),
+            warnings.join("\n")
+        ));
+    }
+
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+
+    Ok(())
+}
+
+/// Valid rows and the number of skipped lines, headers and blank lines aside
+fn parse_csv(csv: &str) -> (Vec<(String, String)>, usize) {
+    let mut rows: Vec<(String, String)> = vec![];
+    let mut skipped = 0;
+
+    for line in csv.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let Some((address, label)) = line.split_once(',') else {
+            skipped += 1;
+            continue;
+        };
+        let address = address.trim().trim_matches('"');
+        let label = label.trim().trim_matches('"');
+
+        if address.eq_ignore_ascii_case("address") {
+            continue;
+        }
+
+        if Pubkey::from_str(address).is_err() || label.is_empty() {
+            skipped += 1;
+            continue;
+        }
+
+        rows.push((address.to_string(), label.to_string()));
+    }
+
+    (rows, skipped)
+}
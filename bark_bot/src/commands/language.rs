@@ -0,0 +1,99 @@
+use crate::collections::{HandlerResult, Role};
+use crate::requests::{get_chat_language, reset_chat_language, set_chat_language};
+use crate::utils::{get_catalog, get_language, has_role, translate};
+use teloxide::prelude::*;
+
+pub async fn language(bot: Bot, msg: Message, args: String) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let catalog = get_catalog();
+    let language = get_language(msg.chat.id, Some(user)).await;
+    let languages = catalog
+        .languages()
+        .into_iter()
+        .map(|code| format!("{} ({})", translate(code, "language-name", &[]), code))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let code = args.trim();
+
+    if code.is_empty() {
+        let key = if get_chat_language(msg.chat.id).await.is_some() {
+            "language-current"
+        } else {
+            "language-following-members"
+        };
+
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                key,
+                &[
+                    ("language", translate(language, "language-name", &[]).into()),
+                    ("languages", languages.into()),
+                ],
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    if !msg.chat.is_private() && !has_role(user.id, Role::Admin).await {
+        bot.send_message(msg.chat.id, translate(language, "language-admin-only", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    if code.eq_ignore_ascii_case("default") || code.eq_ignore_ascii_case("reset") {
+        reset_chat_language(msg.chat.id).await;
+
+        log::info!("{} reset the language of {}", user.id, msg.chat.id);
+
+        let language = get_language(msg.chat.id, Some(user)).await;
+        bot.send_message(msg.chat.id, translate(language, "language-reset", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    let Some(new_language) = catalog.find_language(code) else {
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "language-unknown",
+                &[
+                    ("language", code.to_string().into()),
+                    ("languages", languages.into()),
+                ],
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    set_chat_language(msg.chat.id, new_language, user.id).await;
+
+    log::info!(
+        "{} set the language of {} to {}",
+        user.id,
+        msg.chat.id,
+        new_language
+    );
+
+    bot.send_message(
+        msg.chat.id,
+        translate(
+            new_language,
+            "language-set",
+            &[(
+                "language",
+                translate(new_language, "language-name", &[]).into(),
+            )],
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
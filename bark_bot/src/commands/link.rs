@@ -0,0 +1,97 @@
+use crate::collections::{Handler, HandlerResult, MyDialogue, WalletLinkData};
+use crate::requests::get_wallet_link;
+use crate::utils::{get_language, get_sign_message_url, get_timestamp, translate};
+use rand::{distributions::Alphanumeric, Rng};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use teloxide::{
+    prelude::*,
+    types::{InlineKeyboardButton, InlineKeyboardMarkup, ParseMode},
+    utils::html,
+};
+
+const LINK_NONCE_TTL: i64 = 10 * 60;
+
+pub async fn link(bot: Bot, dialogue: MyDialogue, msg: Message, args: String) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    if !msg.chat.is_private() {
+        bot.send_message(msg.chat.id, translate(language, "link-private-only", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    let pubkey = match Pubkey::from_str(args.trim()) {
+        Ok(pubkey) => pubkey,
+        Err(_) => {
+            let current = match get_wallet_link(user.id).await {
+                Some(link) => format!(
+                    "{}\n\n",
+                    translate(language, "link-current", &[("address", link.pubkey.into())],)
+                ),
+                None => String::new(),
+            };
+
+            bot.send_message(
+                msg.chat.id,
+                format!("{}{}", current, translate(language, "link-usage", &[])),
+            )
+            .parse_mode(ParseMode::Html)
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let nonce: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(24)
+        .map(char::from)
+        .collect();
+
+    // The user id ties the signature to this account, the nonce keeps it from being reused.
+    // Not translated, wallets show it as is and the signature covers these exact bytes.
+    let message = format!(
+        "Link wallet {} to Telegram user {} on Bark bot.\nNonce: {}",
+        pubkey, user.id, nonce
+    );
+
+    let mut request = bot
+        .send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "link-sign-message",
+                &[
+                    ("minutes", (LINK_NONCE_TTL / 60).into()),
+                    ("message", html::escape(&message).into()),
+                ],
+            ),
+        )
+        .parse_mode(ParseMode::Html);
+
+    if let Some(url) = get_sign_message_url(&pubkey, &message) {
+        request = request.reply_markup(InlineKeyboardMarkup::new([[InlineKeyboardButton::url(
+            translate(language, "link-sign-button", &[]),
+            url,
+        )]]));
+    }
+
+    request.await?;
+
+    dialogue
+        .update(Handler::WalletLink {
+            data: WalletLinkData {
+                pubkey: pubkey.to_string(),
+                message,
+                expires_at: get_timestamp() + LINK_NONCE_TTL,
+                updated_at: get_timestamp(),
+            },
+        })
+        .await?;
+
+    Ok(())
+}
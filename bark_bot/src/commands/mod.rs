@@ -1,5 +1,53 @@
+pub mod add_address;
+pub mod addresses;
+pub mod approve;
 pub mod cancel;
+pub mod deeplink;
+pub mod delete_schedule;
+pub mod execute;
+pub mod grant;
 pub mod help;
+pub mod history;
+pub mod import_addresses;
+pub mod language;
+pub mod link;
+pub mod pause_schedule;
+pub mod pending;
+pub mod reject;
+pub mod remove_address;
+pub mod resume_schedule;
+pub mod revoke;
+pub mod roles;
+pub mod schedule;
+pub mod schedules;
+pub mod start;
+pub mod status;
+pub mod transfer;
+pub mod treasury;
 
+pub use add_address::*;
+pub use addresses::*;
+pub use approve::*;
 pub use cancel::*;
+pub use deeplink::*;
+pub use delete_schedule::*;
+pub use execute::*;
+pub use grant::*;
 pub use help::*;
+pub use history::*;
+pub use import_addresses::*;
+pub use language::*;
+pub use link::*;
+pub use pause_schedule::*;
+pub use pending::*;
+pub use reject::*;
+pub use remove_address::*;
+pub use resume_schedule::*;
+pub use revoke::*;
+pub use roles::*;
+pub use schedule::*;
+pub use schedules::*;
+pub use start::*;
+pub use status::*;
+pub use transfer::*;
+pub use treasury::*;
@@ -0,0 +1,39 @@
+use crate::collections::HandlerResult;
+use crate::requests::update_schedule;
+use crate::utils::{get_language, get_managed_schedule, translate};
+use teloxide::prelude::*;
+
+pub async fn pause_schedule(bot: Bot, msg: Message, args: String) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    let schedule = match get_managed_schedule(user.id, &args, language).await {
+        Ok(schedule) => schedule,
+        Err(e) => {
+            bot.send_message(msg.chat.id, e).await?;
+            return Ok(());
+        }
+    };
+
+    if schedule.next_run_at.is_none() {
+        bot.send_message(
+            msg.chat.id,
+            translate(language, "schedule-ended", &[("id", schedule.id.into())]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    update_schedule(schedule.id, true, schedule.next_run_at).await;
+
+    bot.send_message(
+        msg.chat.id,
+        translate(language, "schedule-paused", &[("id", schedule.id.into())]),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use crate::collections::{HandlerResult, RequestProgress, Role};
+use crate::messages::refresh_transaction_request;
+use crate::requests::{
+    get_multisig_account, get_open_transactions, get_transaction_account, update_transaction_state,
+};
+use crate::utils::{
+    format_duration, format_transaction_state, get_disabled_link_preview, get_language,
+    get_multisig_pubkey, get_timestamp, get_transaction_message_url, get_transaction_state,
+    has_role, split_message, translate,
+};
+use squads_mpl::state::MsTransactionStatus;
+use teloxide::{prelude::*, types::ParseMode, utils::html};
+
+pub async fn pending(bot: Bot, msg: Message) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    if !has_role(user.id, Role::Viewer).await {
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "role-required",
+                &[("role", Role::Viewer.as_str().into())],
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let multisig_pubkey = get_multisig_pubkey();
+    let multisig_account = get_multisig_account(multisig_pubkey).await;
+    let threshold = multisig_account.threshold;
+
+    // Not paged, the listing is split across messages instead
+    let transactions = get_open_transactions().await;
+
+    let mut lines: Vec<String> = vec![];
+
+    for transaction_entry in transactions.iter() {
+        let transaction_account =
+            get_transaction_account(multisig_pubkey, transaction_entry.transaction_index).await;
+        let state = get_transaction_state(&transaction_account.status);
+
+        // The stored state may lag behind votes cast outside of the bot
+        if state != transaction_entry.state {
+            update_transaction_state(transaction_entry.id, state).await;
+            refresh_transaction_request(&bot, transaction_entry.id, &RequestProgress::Idle).await?;
+        }
+
+        if transaction_account.status != MsTransactionStatus::Active
+            && transaction_account.status != MsTransactionStatus::ExecuteReady
+        {
+            continue;
+        }
+
+        let link = get_transaction_message_url(transaction_entry)
+            .map(|url| {
+                format!(
+                    " · <a href=\"{}\">{}</a>",
+                    url,
+                    translate(language, "view-proposal", &[])
+                )
+            })
+            .unwrap_or_default();
+
+        let expired = transaction_entry
+            .expired_at
+            .map(|_| format!(" · {}", translate(language, "pending-expired", &[])))
+            .unwrap_or_default();
+
+        lines.push(translate(
+            language,
+            "pending-proposal",
+            &[
+                ("index", transaction_entry.transaction_index.into()),
+                (
+                    "title",
+                    html::escape(
+                        &transaction_entry
+                            .title
+                            .clone()
+                            .unwrap_or_else(|| translate(language, "proposal-untitled", &[])),
+                    )
+                    .into(),
+                ),
+                ("approved", transaction_account.approved.len().into()),
+                ("rejected", transaction_account.rejected.len().into()),
+                ("threshold", threshold.into()),
+                ("state", format_transaction_state(language, state).into()),
+                (
+                    "duration",
+                    format_duration(language, get_timestamp() - transaction_entry.created_at)
+                        .into(),
+                ),
+                ("expired", expired.into()),
+                ("link", link.into()),
+            ],
+        ));
+    }
+
+    if lines.is_empty() {
+        bot.send_message(msg.chat.id, translate(language, "pending-none", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    for text in split_message(&translate(language, "pending-title", &[]), &lines) {
+        bot.send_message(msg.chat.id, text)
+            .parse_mode(ParseMode::Html)
+            .link_preview_options(get_disabled_link_preview())
+            .await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+use crate::collections::{HandlerResult, InFlight};
+use crate::handlers::handle_transaction_command;
+use teloxide::prelude::*;
+
+pub async fn reject(bot: Bot, in_flight: InFlight, msg: Message, args: String) -> HandlerResult {
+    handle_transaction_command(bot, in_flight, msg, args, "Reject").await
+}
@@ -0,0 +1,42 @@
+use crate::collections::{HandlerResult, Role};
+use crate::utils::{get_group_chat_id, get_language, has_role, translate};
+use teloxide::prelude::*;
+
+pub async fn remove_address(bot: Bot, msg: Message, args: String) -> HandlerResult {
+    let Some(admin) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(admin)).await;
+
+    if !has_role(admin.id, Role::Admin).await {
+        bot.send_message(
+            msg.chat.id,
+            translate(language, "address-book-admin-only", &[]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let address = args.trim();
+
+    if address.is_empty() {
+        bot.send_message(
+            msg.chat.id,
+            translate(language, "remove-address-usage", &[]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let args = [("address", address.to_string().into())];
+    let text = if crate::requests::remove_address(get_group_chat_id(), address).await {
+        translate(language, "address-removed", &args)
+    } else {
+        translate(language, "address-not-found", &args)
+    };
+
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
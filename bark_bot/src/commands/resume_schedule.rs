@@ -0,0 +1,41 @@
+use crate::collections::HandlerResult;
+use crate::requests::update_schedule;
+use crate::utils::{get_language, get_managed_schedule, get_next_run, get_timestamp, translate};
+use teloxide::prelude::*;
+
+pub async fn resume_schedule(bot: Bot, msg: Message, args: String) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    let schedule = match get_managed_schedule(user.id, &args, language).await {
+        Ok(schedule) => schedule,
+        Err(e) => {
+            bot.send_message(msg.chat.id, e).await?;
+            return Ok(());
+        }
+    };
+
+    // Runs missed while paused are skipped
+    let Some(next_run_at) = get_next_run(&schedule.cadence, get_timestamp(), schedule.ends_at)
+    else {
+        bot.send_message(
+            msg.chat.id,
+            translate(language, "schedule-ended", &[("id", schedule.id.into())]),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    update_schedule(schedule.id, false, Some(next_run_at)).await;
+
+    bot.send_message(
+        msg.chat.id,
+        translate(language, "schedule-resumed", &[("id", schedule.id.into())]),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::collections::{HandlerResult, Role};
+use crate::requests::revoke_role;
+use crate::utils::{get_group_chat_id, get_language, has_role, parse_role_arguments, translate};
+use teloxide::prelude::*;
+
+pub async fn revoke(bot: Bot, msg: Message, args: String) -> HandlerResult {
+    let Some(admin) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(admin)).await;
+
+    if !has_role(admin.id, Role::Admin).await {
+        bot.send_message(msg.chat.id, translate(language, "revoke-admin-only", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    let (user_id, role) = match parse_role_arguments(&msg, &args, language) {
+        Ok(arguments) => arguments,
+        Err(e) => {
+            bot.send_message(
+                msg.chat.id,
+                format!("{}\n\n{}", e, translate(language, "revoke-usage", &[])),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let args = [
+        ("role", role.as_str().into()),
+        ("user_id", user_id.to_string().into()),
+    ];
+    let text = if revoke_role(get_group_chat_id(), user_id, role).await {
+        log::info!("{} revoked {} from {}", admin.id, role.as_str(), user_id);
+        translate(language, "role-revoked", &args)
+    } else {
+        translate(language, "role-not-held", &args)
+    };
+
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
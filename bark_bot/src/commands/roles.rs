@@ -0,0 +1,68 @@
+use crate::collections::{HandlerResult, Role};
+use crate::requests::{get_roles, get_users};
+use crate::utils::{get_bot_admins, get_group_chat_id, get_language, has_role, translate};
+use std::collections::BTreeMap;
+use teloxide::{prelude::*, types::ParseMode, utils::html};
+
+pub async fn roles(bot: Bot, msg: Message) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    if !has_role(user.id, Role::Admin).await {
+        bot.send_message(msg.chat.id, translate(language, "roles-admin-only", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    let users = get_users().await;
+    let mut grants: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for admin in get_bot_admins() {
+        grants.entry(admin.to_string()).or_default().push(translate(
+            language,
+            "roles-bot-admin",
+            &[],
+        ));
+    }
+
+    for grant in get_roles(get_group_chat_id(), None).await {
+        grants.entry(grant.user_id).or_default().push(grant.role);
+    }
+
+    let lines: Vec<String> = grants
+        .iter()
+        .map(|(user_id, roles)| {
+            let name = users
+                .iter()
+                .find(|user| &user.user_id == user_id)
+                .map(|user| html::escape(&user.name))
+                .unwrap_or_else(|| translate(language, "roles-unknown-user", &[]));
+
+            format!(
+                "• {} (<code>{}</code>): {}",
+                name,
+                user_id,
+                roles.join(", ")
+            )
+        })
+        .collect();
+
+    let text = if lines.is_empty() {
+        translate(language, "roles-none", &[])
+    } else {
+        format!(
+            "{}\n\n{}",
+            translate(language, "roles-title", &[]),
+            lines.join("\n")
+        )
+    };
+
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use crate::collections::{HandlerResult, Role, ScheduledAction};
+use crate::requests::create_schedule;
+use crate::utils::{
+    find_user_keypair, get_group_chat_id, get_language, get_next_run, get_timestamp, has_role,
+    parse_cadence, parse_transfer_arguments, translate,
+};
+use chrono::{DateTime, NaiveDate};
+use teloxide::prelude::*;
+use url::Url;
+
+pub async fn schedule(bot: Bot, msg: Message, args: String) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    if !has_role(user.id, Role::Proposer).await {
+        bot.send_message(msg.chat.id, translate(language, "no-proposer-role", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    if find_user_keypair(user.id).is_none() {
+        bot.send_message(msg.chat.id, translate(language, "no-signer-key", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    let (cadence, ends_at, action, title) = match parse_schedule_arguments(&args, language).await {
+        Ok(arguments) => arguments,
+        Err(e) => {
+            bot.send_message(
+                msg.chat.id,
+                format!("{}\n\n{}", e, translate(language, "schedule-usage", &[])),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let Some(next_run_at) = get_next_run(&cadence, get_timestamp(), ends_at) else {
+        bot.send_message(
+            msg.chat.id,
+            translate(language, "schedule-ends-before-first-run", &[]),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let schedule = create_schedule(
+        get_group_chat_id(),
+        user.id,
+        &cadence,
+        &action,
+        &title,
+        next_run_at,
+        ends_at,
+    )
+    .await;
+
+    log::info!("{} created schedule {}: {}", user.id, schedule.id, title);
+
+    bot.send_message(
+        msg.chat.id,
+        translate(
+            language,
+            "schedule-created",
+            &[
+                ("id", schedule.id.into()),
+                ("title", title.into()),
+                (
+                    "date",
+                    DateTime::from_timestamp(next_run_at, 0)
+                        .map(|time| time.format("%Y-%m-%d %H:%M UTC").to_string())
+                        .unwrap_or_default()
+                        .into(),
+                ),
+            ],
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Cadence, end date, action and title of a new schedule. The title is in the language
+/// of the group, where the proposals are posted.
+async fn parse_schedule_arguments(
+    args: &str,
+    language: &str,
+) -> Result<(String, Option<i64>, ScheduledAction, String), String> {
+    let args = args.trim();
+    let missing_action = || translate(language, "schedule-missing-action", &[]);
+
+    let (cadence, rest) = match args.strip_prefix('"') {
+        Some(quoted) => quoted
+            .split_once('"')
+            .ok_or_else(|| translate(language, "schedule-missing-quote", &[]))?,
+        None => args
+            .split_once(char::is_whitespace)
+            .ok_or_else(missing_action)?,
+    };
+    let cadence = parse_cadence(cadence, language)?;
+    let mut rest = rest.trim();
+
+    let mut ends_at = None;
+    if let Some(until) = rest.strip_prefix("until ") {
+        let (date, remaining) = until
+            .trim()
+            .split_once(char::is_whitespace)
+            .ok_or_else(missing_action)?;
+        let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+            translate(
+                language,
+                "schedule-invalid-end-date",
+                &[("date", date.to_string().into())],
+            )
+        })?;
+
+        // The end date is included
+        ends_at = Some(date.and_hms_opt(23, 59, 59).unwrap().and_utc().timestamp());
+        rest = remaining.trim();
+    }
+
+    let (kind, arguments) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+
+    match kind.to_lowercase().as_str() {
+        "transfer" => {
+            let transfer = parse_transfer_arguments(arguments, language).await?;
+            let group_language = get_language(get_group_chat_id(), None).await;
+
+            Ok((
+                cadence,
+                ends_at,
+                ScheduledAction::Transfer {
+                    arguments: arguments.trim().to_string(),
+                },
+                transfer.title(group_language),
+            ))
+        }
+        "blink" => {
+            let url = arguments.trim();
+            let url = url.strip_prefix("solana-action:").unwrap_or(url);
+            let invalid_url = || {
+                translate(
+                    language,
+                    "schedule-invalid-url",
+                    &[("url", url.to_string().into())],
+                )
+            };
+            let parsed_url = Url::parse(url).map_err(|_| invalid_url())?;
+
+            if parsed_url.scheme() != "https" && parsed_url.scheme() != "http" {
+                return Err(invalid_url());
+            }
+            let group_language = get_language(get_group_chat_id(), None).await;
+
+            Ok((
+                cadence,
+                ends_at,
+                ScheduledAction::Blink {
+                    url: url.to_string(),
+                },
+                translate(
+                    group_language,
+                    "schedule-blink-title",
+                    &[(
+                        "host",
+                        parsed_url.host_str().unwrap_or_default().to_string().into(),
+                    )],
+                ),
+            ))
+        }
+        _ => Err(translate(language, "schedule-invalid-action", &[])),
+    }
+}
@@ -0,0 +1,105 @@
+use crate::collections::{HandlerResult, Role};
+use crate::requests::{get_schedules, get_users, GetSchedulesQuery};
+use crate::utils::{get_group_chat_id, get_language, has_role, translate};
+use chrono::DateTime;
+use teloxide::{prelude::*, types::ParseMode, utils::html};
+
+pub async fn schedules(bot: Bot, msg: Message) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    if !has_role(user.id, Role::Viewer).await {
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "role-required",
+                &[("role", Role::Viewer.as_str().into())],
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let users = get_users().await;
+    let format_time = |timestamp: i64| {
+        DateTime::from_timestamp(timestamp, 0)
+            .map(|time| time.format("%Y-%m-%d %H:%M UTC").to_string())
+            .unwrap_or_default()
+    };
+
+    let lines: Vec<String> = get_schedules(&GetSchedulesQuery {
+        chat_id: Some(get_group_chat_id().to_string()),
+        ..Default::default()
+    })
+    .await
+    .iter()
+    .map(|schedule| {
+        let status = match (schedule.paused, schedule.next_run_at) {
+            (_, None) => translate(language, "schedules-ended", &[]),
+            (true, _) => translate(language, "schedules-paused", &[]),
+            (false, Some(next_run_at)) => translate(
+                language,
+                "schedules-next-run",
+                &[("date", format_time(next_run_at).into())],
+            ),
+        };
+        let creator = users
+            .iter()
+            .find(|user| user.user_id == schedule.user_id)
+            .map(|user| html::escape(&user.name))
+            .unwrap_or_else(|| {
+                translate(
+                    language,
+                    "proposal-user",
+                    &[("user_id", schedule.user_id.clone().into())],
+                )
+            });
+
+        translate(
+            language,
+            "schedules-schedule",
+            &[
+                ("id", schedule.id.into()),
+                ("title", html::escape(&schedule.title).into()),
+                ("cadence", html::escape(&schedule.cadence).into()),
+                ("status", status.into()),
+                (
+                    "until",
+                    schedule
+                        .ends_at
+                        .map(|ends_at| {
+                            translate(
+                                language,
+                                "schedules-until",
+                                &[("date", format_time(ends_at).into())],
+                            )
+                        })
+                        .unwrap_or_default()
+                        .into(),
+                ),
+                ("creator", creator.into()),
+            ],
+        )
+    })
+    .collect();
+
+    let text = if lines.is_empty() {
+        translate(language, "schedules-none", &[])
+    } else {
+        format!(
+            "{}\n\n{}",
+            translate(language, "schedules-title", &[]),
+            lines.join("\n")
+        )
+    };
+
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::collections::{HandlerResult, MyDialogue, Role};
+use crate::handlers::start_blink_flow;
+use crate::requests::{get_shared_action, upsert_user};
+use crate::utils::{get_language, has_role, translate, SHARED_ACTION_START_PREFIX};
+use teloxide::prelude::*;
+
+pub async fn start(bot: Bot, dialogue: MyDialogue, msg: Message, payload: String) -> HandlerResult {
+    // Deep links from inline mode and /deeplink carry a shared action
+    if let Some(shared_action_id) = payload
+        .strip_prefix(SHARED_ACTION_START_PREFIX)
+        .and_then(|id| id.parse::<i64>().ok())
+    {
+        return start_shared_action(bot, dialogue, msg, shared_action_id).await;
+    }
+
+    let language = get_language(msg.chat.id, msg.from.as_ref()).await;
+    bot.send_message(msg.chat.id, translate(language, "welcome", &[]))
+        .await?;
+    Ok(())
+}
+
+async fn start_shared_action(
+    bot: Bot,
+    dialogue: MyDialogue,
+    msg: Message,
+    shared_action_id: i64,
+) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    if !has_role(user.id, Role::Proposer).await {
+        bot.send_message(msg.chat.id, translate(language, "no-proposer-role", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    let Some(shared_action) = get_shared_action(shared_action_id).await else {
+        bot.send_message(msg.chat.id, translate(language, "shared-link-invalid", &[]))
+            .await?;
+        return Ok(());
+    };
+
+    upsert_user(user.id, user.full_name(), None).await;
+
+    // The action may have been renamed since, the proposer then picks again
+    let action_label = Some(shared_action.action.as_str()).filter(|label| !label.is_empty());
+    let presets: Vec<(String, String)> =
+        serde_json::from_str(&shared_action.parameters).unwrap_or_default();
+
+    start_blink_flow(
+        &bot,
+        &dialogue,
+        user,
+        &shared_action.url,
+        action_label,
+        Some(&presets),
+        None,
+    )
+    .await
+}
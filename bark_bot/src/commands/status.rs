@@ -0,0 +1,124 @@
+use crate::collections::{HandlerResult, RequestProgress, Role};
+use crate::messages::refresh_transaction_request;
+use crate::requests::{
+    get_multisig_account, get_transaction_account, get_transaction_by_index, get_users,
+    update_transaction_state,
+};
+use crate::utils::{
+    find_user_name, format_transaction_state, get_disabled_link_preview, get_language,
+    get_multisig_pubkey, get_transaction_message_url, get_transaction_state, has_role,
+    parse_transaction_index, translate,
+};
+use teloxide::{prelude::*, types::ParseMode, utils::html};
+
+pub async fn status(bot: Bot, msg: Message, args: String) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    if !has_role(user.id, Role::Viewer).await {
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "role-required",
+                &[("role", Role::Viewer.as_str().into())],
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let Some(transaction_index) = parse_transaction_index(&args) else {
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "transaction-command-usage",
+                &[("command", "status".into())],
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let Some(transaction_entry) = get_transaction_by_index(transaction_index).await else {
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "proposal-not-found",
+                &[("index", transaction_index.into())],
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let multisig_pubkey = get_multisig_pubkey();
+    let multisig_account = get_multisig_account(multisig_pubkey).await;
+    let transaction_account = get_transaction_account(multisig_pubkey, transaction_index).await;
+    let state = get_transaction_state(&transaction_account.status);
+
+    // The stored state may lag behind votes cast outside of the bot
+    if state != transaction_entry.state {
+        update_transaction_state(transaction_entry.id, state).await;
+        refresh_transaction_request(&bot, transaction_entry.id, &RequestProgress::Idle).await?;
+    }
+
+    let users = get_users().await;
+    let names = |keys: &[solana_sdk::pubkey::Pubkey]| {
+        if keys.is_empty() {
+            "—".to_string()
+        } else {
+            keys.iter()
+                .map(|key| html::escape(&find_user_name(&users, key)))
+                .collect::<Vec<String>>()
+                .join(", ")
+        }
+    };
+
+    let link = get_transaction_message_url(&transaction_entry)
+        .map(|url| {
+            format!(
+                "\n\n<a href=\"{}\">{}</a>",
+                url,
+                translate(language, "status-view-proposal", &[])
+            )
+        })
+        .unwrap_or_default();
+
+    let text = translate(
+        language,
+        "status-proposal",
+        &[
+            ("index", transaction_index.into()),
+            (
+                "title",
+                html::escape(
+                    &transaction_entry
+                        .title
+                        .clone()
+                        .unwrap_or_else(|| translate(language, "proposal-untitled", &[])),
+                )
+                .into(),
+            ),
+            ("state", format_transaction_state(language, state).into()),
+            ("approved", transaction_account.approved.len().into()),
+            ("approvers", names(&transaction_account.approved).into()),
+            ("rejected", transaction_account.rejected.len().into()),
+            ("rejecters", names(&transaction_account.rejected).into()),
+            ("threshold", multisig_account.threshold.into()),
+            ("link", link.into()),
+        ],
+    );
+
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .link_preview_options(get_disabled_link_preview())
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::collections::{HandlerResult, Role};
+use crate::messages::{post_transaction_request, send_proposal_error};
+use crate::requests::upsert_user;
+use crate::utils::{
+    find_user_keypair, get_language, get_multisig_pubkey, has_role, parse_transfer_arguments,
+    translate,
+};
+use teloxide::prelude::*;
+
+pub async fn transfer(bot: Bot, msg: Message, args: String) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    if !has_role(user.id, Role::Proposer).await {
+        bot.send_message(msg.chat.id, translate(language, "no-proposer-role", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    if find_user_keypair(user.id).is_none() {
+        bot.send_message(msg.chat.id, translate(language, "no-signer-key", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    let arguments = match parse_transfer_arguments(&args, language).await {
+        Ok(arguments) => arguments,
+        Err(e) => {
+            bot.send_message(
+                msg.chat.id,
+                format!("{}\n\n{}", e, translate(language, "transfer-usage", &[])),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    upsert_user(user.id, user.full_name(), None).await;
+
+    let transaction_entry =
+        match crate::actions::create_transfer(get_multisig_pubkey(), user.id, &arguments).await {
+            Ok(transaction_entry) => transaction_entry,
+            Err(error) => {
+                send_proposal_error(
+                    &bot,
+                    msg.chat.id,
+                    language,
+                    &arguments.title(language),
+                    &error,
+                )
+                .await?;
+                return Ok(());
+            }
+        };
+
+    post_transaction_request(&bot, &transaction_entry).await?;
+
+    bot.send_message(
+        msg.chat.id,
+        translate(
+            language,
+            "transfer-proposed",
+            &[("title", arguments.title(language).into())],
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use crate::collections::{HandlerResult, Role};
+use crate::requests::{
+    get_multisig_account, get_token_holdings, get_token_metadata, get_token_prices,
+};
+use crate::utils::{
+    format_localized_amount, format_pubkey, get_language, get_multisig_authority_pubkey,
+    get_multisig_pubkey, get_price_source, has_role, translate, NATIVE_MINT, RPC,
+};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::native_token::lamports_to_sol;
+use std::collections::HashMap;
+use teloxide::{prelude::*, types::ParseMode, utils::html};
+
+pub async fn treasury(bot: Bot, msg: Message) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    if !has_role(user.id, Role::Viewer).await {
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "role-required",
+                &[("role", Role::Viewer.as_str().into())],
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let solana_client = RpcClient::new(RPC.to_string());
+    let multisig_pubkey = get_multisig_pubkey();
+    let multisig_account = get_multisig_account(multisig_pubkey).await;
+
+    let prices = match get_price_source() {
+        Some(price_source) => Some(get_token_prices(&price_source).await),
+        None => None,
+    };
+
+    let value_of = |mint: String, ui_amount: f64| {
+        prices
+            .as_ref()
+            .and_then(|prices| prices.get(&mint))
+            .map(|price| price * ui_amount)
+    };
+
+    let mut symbols: HashMap<String, String> = HashMap::new();
+    let mut sections: Vec<String> = vec![];
+    let mut total = 0.0;
+
+    // Authority 0 is reserved for the multisig itself, vaults start at 1
+    for authority in 1..=multisig_account.authority_index.max(1) {
+        let authority_pubkey = get_multisig_authority_pubkey(multisig_pubkey, authority.into());
+        let lamports = solana_client.get_balance(&authority_pubkey).await.unwrap();
+        let sol = lamports_to_sol(lamports);
+
+        let mut rows = vec![(
+            "SOL".to_string(),
+            sol.to_string(),
+            value_of(NATIVE_MINT.to_string(), sol),
+        )];
+
+        for holding in get_token_holdings(authority_pubkey).await {
+            let mint = holding.mint.to_string();
+
+            if !symbols.contains_key(&mint) {
+                let symbol = get_token_metadata(holding.mint)
+                    .await
+                    .map(|metadata| {
+                        if metadata.symbol.is_empty() {
+                            metadata.name
+                        } else {
+                            metadata.symbol
+                        }
+                    })
+                    .filter(|symbol| !symbol.is_empty())
+                    .unwrap_or_else(|| format_pubkey(&holding.mint));
+
+                symbols.insert(mint.clone(), symbol);
+            }
+
+            rows.push((
+                symbols[&mint].clone(),
+                holding.amount,
+                value_of(mint, holding.ui_amount),
+            ));
+        }
+
+        let lines: Vec<String> = rows
+            .into_iter()
+            .map(|(symbol, amount, value)| {
+                total += value.unwrap_or_default();
+
+                match (&prices, value) {
+                    (Some(_), Some(value)) => format!(
+                        "{} {} · ${}",
+                        format_localized_amount(&amount, language),
+                        html::escape(&symbol),
+                        format_localized_amount(&format!("{:.2}", value), language)
+                    ),
+                    (Some(_), None) => format!(
+                        "{} {} · {}",
+                        format_localized_amount(&amount, language),
+                        html::escape(&symbol),
+                        translate(language, "treasury-no-price", &[])
+                    ),
+                    (None, _) => format!(
+                        "{} {}",
+                        format_localized_amount(&amount, language),
+                        html::escape(&symbol)
+                    ),
+                }
+            })
+            .collect();
+
+        sections.push(format!(
+            "{}\n{}",
+            translate(
+                language,
+                "treasury-vault",
+                &[
+                    ("index", authority.into()),
+                    ("address", authority_pubkey.to_string().into()),
+                ],
+            ),
+            lines.join("\n")
+        ));
+    }
+
+    let mut text = format!(
+        "{}\n\n{}",
+        translate(language, "treasury-title", &[]),
+        sections.join("\n\n")
+    );
+
+    if prices.is_some() {
+        text.push_str(&format!(
+            "\n\n{}",
+            translate(
+                language,
+                "treasury-total",
+                &[(
+                    "value",
+                    format_localized_amount(&format!("{:.2}", total), language).into()
+                )],
+            )
+        ));
+    }
+
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+
+    Ok(())
+}
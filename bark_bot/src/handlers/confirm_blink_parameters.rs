@@ -0,0 +1,62 @@
+use crate::{
+    collections::{Handler, HandlerResult, MyDialogue, ParametersData},
+    utils::{fill_action_url, get_timestamp, translate, CONFIRM_CALLBACK_PREFIX},
+};
+use teloxide::{
+    prelude::*,
+    types::{InlineKeyboardButton, InlineKeyboardMarkup, ParseMode},
+    utils::html,
+};
+
+/// Shows what a shared link is about to propose and waits for the proposer to confirm,
+/// the proposal is created with their approval so nothing is submitted unseen
+pub async fn confirm_blink_parameters(
+    bot: &Bot,
+    dialogue: &MyDialogue,
+    data: ParametersData,
+    language: &str,
+) -> HandlerResult {
+    let request_url = fill_action_url(&data.url, &data.parameter_names, &data.parameters_values);
+    let parameters: String = data
+        .parameter_labels
+        .iter()
+        .zip(&data.parameters_values)
+        .map(|(label, value)| format!("<b>{}:</b> {}\n", html::escape(label), html::escape(value)))
+        .collect();
+
+    bot.send_message(
+        dialogue.chat_id(),
+        translate(
+            language,
+            "confirm-proposal",
+            &[
+                ("title", html::escape(&data.action_title).into()),
+                ("url", html::escape(&request_url).into()),
+                ("parameters", parameters.into()),
+            ],
+        ),
+    )
+    .parse_mode(ParseMode::Html)
+    .reply_markup(InlineKeyboardMarkup::new([[
+        InlineKeyboardButton::callback(
+            translate(language, "button-confirm-proposal", &[]),
+            format!("{}confirm", CONFIRM_CALLBACK_PREFIX),
+        ),
+        InlineKeyboardButton::callback(
+            translate(language, "button-discard-proposal", &[]),
+            format!("{}cancel", CONFIRM_CALLBACK_PREFIX),
+        ),
+    ]]))
+    .await?;
+
+    dialogue
+        .update(Handler::Parameters {
+            data: ParametersData {
+                updated_at: get_timestamp(),
+                ..data
+            },
+        })
+        .await?;
+
+    Ok(())
+}
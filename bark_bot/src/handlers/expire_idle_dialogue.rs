@@ -0,0 +1,36 @@
+use crate::collections::{Handler, MyDialogue};
+use crate::utils::{format_duration, get_dialogue_timeout, get_language, get_timestamp, translate};
+use teloxide::prelude::*;
+
+/// Abandons a flow that has been idle for longer than the timeout, so the next
+/// message isn't taken as one of its answers
+pub async fn expire_idle_dialogue(bot: Bot, dialogue: MyDialogue, handler: Handler) -> Handler {
+    let (Some(timeout), Some(updated_at)) = (get_dialogue_timeout(), handler.updated_at()) else {
+        return handler;
+    };
+
+    if get_timestamp() - updated_at < timeout.as_secs() as i64 {
+        return handler;
+    }
+
+    if let Err(e) = dialogue.exit().await {
+        log::warn!("Failed to remove an idle dialogue: {}", e);
+    }
+
+    // Mostly sent by the sweep, which has no update to tell the member's language
+    let language = get_language(dialogue.chat_id(), None).await;
+    let text = translate(
+        language,
+        "dialogue-expired",
+        &[(
+            "duration",
+            format_duration(language, timeout.as_secs() as i64).into(),
+        )],
+    );
+
+    if let Err(e) = bot.send_message(dialogue.chat_id(), text).await {
+        log::warn!("Failed to send the dialogue expiry notice: {}", e);
+    }
+
+    Handler::default()
+}
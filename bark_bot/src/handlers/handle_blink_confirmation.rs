@@ -0,0 +1,63 @@
+use crate::collections::{HandlerResult, MyDialogue, ParametersData, Role};
+use crate::handlers::submit_blink_parameters;
+use crate::utils::{get_language, has_role, translate, CONFIRM_CALLBACK_PREFIX};
+use teloxide::prelude::*;
+
+/// Submits the values of a shared link once the proposer confirmed them
+pub async fn handle_blink_confirmation(
+    bot: Bot,
+    dialogue: MyDialogue,
+    data: ParametersData,
+    q: CallbackQuery,
+) -> HandlerResult {
+    let language = get_language(dialogue.chat_id(), Some(&q.from)).await;
+
+    if q.from.id != data.user_id {
+        bot.answer_callback_query(&q.id)
+            .text(translate(language, "prepared-by-another-member", &[]))
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    }
+
+    // Buttons left from an earlier flow of the same chat
+    if !data.confirm || data.parameters_values.len() != data.parameter_names.len() {
+        bot.answer_callback_query(&q.id)
+            .text(translate(language, "button-invalid", &[]))
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    }
+
+    bot.answer_callback_query(&q.id).await?;
+
+    if let Some(message) = &q.message {
+        bot.edit_message_reply_markup(message.chat().id, message.id())
+            .await?;
+    }
+
+    if q.data.as_deref() != Some(&format!("{}confirm", CONFIRM_CALLBACK_PREFIX)) {
+        bot.send_message(
+            dialogue.chat_id(),
+            translate(language, "proposal-discarded", &[]),
+        )
+        .await?;
+        dialogue.exit().await?;
+        return Ok(());
+    }
+
+    // Roles can change while the dialogue is open
+    if !has_role(q.from.id, Role::Proposer).await {
+        bot.send_message(
+            dialogue.chat_id(),
+            translate(language, "no-proposer-role", &[]),
+        )
+        .await?;
+        dialogue.exit().await?;
+        return Ok(());
+    }
+
+    let parameters_values = data.parameters_values.clone();
+
+    submit_blink_parameters(&bot, &dialogue, data, parameters_values, language).await
+}
@@ -1,86 +1,38 @@
 use crate::{
-    collections::{Handler, HandlerResult, InternalActionData, MyDialogue},
-    requests::{get_blink_metadata, get_blink_transaction},
-    utils::get_multisig_pubkey,
+    collections::{HandlerResult, MyDialogue, Role},
+    handlers::start_blink_flow,
+    requests::upsert_user,
+    utils::{get_language, has_role, translate},
 };
-use teloxide::{
-    prelude::*,
-    types::{InlineKeyboardButton, InlineKeyboardMarkup, ParseMode},
-};
-use url::Url;
+use teloxide::prelude::*;
 
 pub async fn handle_blink_url(bot: Bot, dialogue: MyDialogue, msg: Message) -> HandlerResult {
+    let language = get_language(msg.chat.id, msg.from.as_ref()).await;
+
     match msg.text() {
         Some(url) => {
-            let response = get_blink_metadata(&url.to_string()).await;
-            let parsed_url = Url::parse(url)?;
-            let base_url = format!("{}://{}", parsed_url.scheme(), parsed_url.host().unwrap());
-
-            match response {
-                Ok(res) => {
-                    let links = res.links;
-
-                    match links {
-                        Some(links_res) => {
-                            let actions = links_res.actions;
-                            let action_names: Vec<String> =
-                                actions.iter().map(|a| a.label.clone()).collect();
-                            let action_buttons = action_names
-                                .iter()
-                                .map(|action| InlineKeyboardButton::callback(action, action));
-
-                            let template = format!(
-                                "<b>{}</b> \n\n{} \n\n{}\n\n Choose an action to perform:",
-                                res.title, res.description, res.icon
-                            );
-
-                            bot.send_message(msg.chat.id, template)
-                                .parse_mode(ParseMode::Html)
-                                .reply_markup(InlineKeyboardMarkup::new([action_buttons]))
-                                .await?;
-
-                            let actions_data = InternalActionData {
-                                actions,
-                                url: url.to_string(),
-                                action_title: res.title,
-                                action_description: res.description,
-                                user_id: msg.from.clone().unwrap().id,
-                                base_url,
-                            };
-
-                            dialogue
-                                .update(Handler::InternalAction { data: actions_data })
-                                .await?;
-                        }
-                        None => {
-                            let multisig_pubkey = get_multisig_pubkey();
-                            let transaction_response =
-                                get_blink_transaction(multisig_pubkey, &url.to_string()).await?;
-
-                            let template = format!(
-                                "<b>{}</b> \n\n{} \n\n{} \n\n{}",
-                                res.title,
-                                res.description,
-                                res.icon,
-                                transaction_response.transaction
-                            );
+            let Some(user) = &msg.from else {
+                return Ok(());
+            };
+
+            if !has_role(user.id, Role::Proposer).await {
+                log::warn!(
+                    "Unauthorized proposal attempt by {} ({})",
+                    user.full_name(),
+                    user.id
+                );
+
+                bot.send_message(msg.chat.id, translate(language, "no-proposer-role", &[]))
+                    .await?;
+                return Ok(());
+            }
 
-                            bot.send_message(msg.chat.id, template)
-                                .parse_mode(ParseMode::Html)
-                                .await?;
+            upsert_user(user.id, user.full_name(), None).await;
 
-                            dialogue.exit().await?;
-                        }
-                    }
-                }
-                Err(e) => {
-                    bot.send_message(msg.chat.id, format!("Error getting blink: {}", e))
-                        .await?;
-                }
-            }
+            start_blink_flow(&bot, &dialogue, user, url, None, None, None).await?;
         }
         None => {
-            bot.send_message(msg.chat.id, "Please, share with me a valid Blink URL")
+            bot.send_message(msg.chat.id, translate(language, "send-blink-url", &[]))
                 .await?;
         }
     }
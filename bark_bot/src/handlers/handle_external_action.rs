@@ -1,74 +1,101 @@
-use crate::collections::{ButtonMetadata, HandlerResult, JoinStorage};
-use crate::requests::{get_multisig_account, get_transaction_account};
-use crate::utils::{get_group_chat_id, get_multisig_pubkey, get_transaction_request_buttons};
-use teloxide::{prelude::*, types::InlineKeyboardMarkup};
+use crate::actions::perform_transaction_action;
+use crate::collections::{
+    ButtonMetadata, HandlerResult, InFlight, InvalidButtonMetadataError, JoinStorage,
+    RequestProgress,
+};
+use crate::messages::refresh_transaction_request;
+use crate::utils::{check_transaction_action, get_language, translate};
+use teloxide::prelude::*;
 
 pub async fn handle_external_action(
     bot: Bot,
     _storage: JoinStorage,
+    in_flight: InFlight,
     q: CallbackQuery,
 ) -> HandlerResult {
-    let button_metadata: ButtonMetadata = match q.data.unwrap().try_into() {
+    let language = match q.regular_message() {
+        Some(message) => get_language(message.chat.id, Some(&q.from)).await,
+        None => get_language(ChatId::from(q.from.id), Some(&q.from)).await,
+    };
+
+    let button_metadata: ButtonMetadata = match q.data.clone().unwrap().try_into() {
         Ok(button_metadata) => button_metadata,
-        _ => return Ok(()),
+        Err(InvalidButtonMetadataError::Forged) => {
+            log::warn!(
+                "Forged callback data from {} ({}): {:?}",
+                q.from.full_name(),
+                q.from.id,
+                q.data
+            );
+
+            bot.answer_callback_query(&q.id)
+                .text(translate(language, "button-invalid", &[]))
+                .show_alert(true)
+                .await?;
+            return Ok(());
+        }
+        Err(InvalidButtonMetadataError::Stale(transaction_id)) => {
+            // Rendering the message again issues fresh buttons
+            refresh_transaction_request(&bot, transaction_id, &RequestProgress::Idle).await?;
+
+            bot.answer_callback_query(&q.id)
+                .text(translate(language, "button-stale", &[]))
+                .show_alert(true)
+                .await?;
+            return Ok(());
+        }
+        Err(InvalidButtonMetadataError::Malformed) => return Ok(()),
+    };
+
+    // Busy buttons are only shown while another action is in flight
+    if button_metadata.value == "Busy" {
+        bot.answer_callback_query(&q.id)
+            .text(translate(language, "action-in-progress", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    let transaction_entry = crate::requests::get_transaction(button_metadata.transaction_id).await;
+
+    if let Err(reason) = check_transaction_action(
+        &bot,
+        &q.from,
+        &transaction_entry,
+        &button_metadata.value,
+        language,
+    )
+    .await?
+    {
+        // Telegram cuts callback alerts at 200 characters
+        let text: String = reason.chars().take(200).collect();
+
+        bot.answer_callback_query(&q.id)
+            .text(text)
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    }
+
+    // Holding the guard until the end keeps concurrent clicks from sending duplicate transactions
+    let Some(_guard) = in_flight.try_lock(button_metadata.transaction_id).await else {
+        bot.answer_callback_query(&q.id)
+            .text(translate(language, "action-in-progress", &[]))
+            .await?;
+        return Ok(());
     };
 
     if let Err(e) = bot
         .answer_callback_query(&q.id)
-        .text("Processing request...")
+        .text(translate(language, "processing-request", &[]))
         .show_alert(false)
         .await
     {
         eprintln!("Failed to answer callback query: {}", e);
     }
 
-    let transaction_entry = crate::requests::get_transaction(button_metadata.transaction_id).await;
-    let multisig_pubkey = get_multisig_pubkey();
-
-    match button_metadata.value.as_str() {
-        "Approve" => {
-            crate::actions::approve_transaction(
-                multisig_pubkey,
-                transaction_entry.transaction_index,
-                q.from.id,
-            )
-            .await
-        }
-        "Reject" => {
-            crate::actions::reject_transaction(
-                multisig_pubkey,
-                transaction_entry.transaction_index,
-                q.from.id,
-            )
-            .await
-        }
-        _ => {
-            crate::actions::execute_transaction(
-                multisig_pubkey,
-                transaction_entry.transaction_index,
-                q.from.id,
-            )
-            .await
-        }
-    };
-
-    let multisig_account = get_multisig_account(multisig_pubkey).await;
-    let transaction_account =
-        get_transaction_account(multisig_pubkey, transaction_entry.transaction_index).await;
-
-    let buttons = get_transaction_request_buttons(
-        transaction_entry.id,
-        multisig_account.threshold,
-        transaction_account.approved.len().try_into().unwrap(),
-        transaction_account.rejected.len().try_into().unwrap(),
-        &transaction_account.status,
-    );
-
-    let group_chat_id = get_group_chat_id();
-    let _ = bot
-        .edit_message_reply_markup(group_chat_id, q.message.unwrap().id())
-        .reply_markup(InlineKeyboardMarkup::new([buttons]))
-        .await;
+    // Failures are shown on the proposal message itself
+    let _ = perform_transaction_action(&bot, &q.from, &transaction_entry, &button_metadata.value)
+        .await?;
 
     Ok(())
 }
@@ -0,0 +1,77 @@
+use crate::collections::{HandlerResult, Template};
+use crate::messages::send_blink_preview;
+use crate::requests::get_public_blink_metadata;
+use crate::utils::{
+    find_action_url, get_language, render_template, translate, UNFURL_CALLBACK_PREFIX,
+};
+use serde_json::json;
+use teloxide::{
+    prelude::*,
+    types::{InlineKeyboardButton, InlineKeyboardMarkup},
+};
+
+/// Replies to Blink links shared in the group with a preview, everything else is
+/// ordinary conversation and left alone
+pub async fn handle_group_message(bot: Bot, msg: Message) -> HandlerResult {
+    let Some(url) = find_action_url(&msg) else {
+        return Ok(());
+    };
+
+    // Most links aren't Blinks, there's nothing to say about those. Any member can post a
+    // link, so it's only fetched from public HTTPS hosts
+    let metadata = match get_public_blink_metadata(url.as_str()).await {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            log::debug!("{} is not a Blink: {}", url, e);
+            return Ok(());
+        }
+    };
+
+    let actions: Vec<&str> = metadata
+        .links
+        .as_ref()
+        .map(|links| {
+            links
+                .actions
+                .iter()
+                .map(|action| action.label.as_str())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Everyone in the group reads the preview, not only who shared the link
+    let language = get_language(msg.chat.id, None).await;
+    let text = render_template(
+        Template::Preview,
+        msg.chat.id,
+        language,
+        &json!({
+            "title": metadata.title,
+            "description": metadata.description,
+            "actions": actions,
+        }),
+    );
+
+    let buttons = [
+        InlineKeyboardButton::callback(
+            translate(language, "propose-here", &[]),
+            format!("{}here", UNFURL_CALLBACK_PREFIX),
+        ),
+        InlineKeyboardButton::callback(
+            translate(language, "continue-in-dm", &[]),
+            format!("{}dm", UNFURL_CALLBACK_PREFIX),
+        ),
+    ];
+
+    send_blink_preview(
+        &bot,
+        msg.chat.id,
+        &metadata.icon,
+        text,
+        Some(InlineKeyboardMarkup::new([buttons])),
+        Some(msg.id),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::collections::{HandlerResult, Role};
+use crate::requests::{create_shared_action, get_blink_metadata};
+use crate::utils::{
+    get_language, has_role, parse_action_url, translate, SHARED_ACTION_START_PREFIX,
+};
+use teloxide::{
+    prelude::*,
+    types::{
+        InlineKeyboardButton, InlineKeyboardMarkup, InlineQueryResult, InlineQueryResultArticle,
+        InputMessageContent, InputMessageContentText, Me, ParseMode,
+    },
+    utils::html,
+};
+use url::Url;
+
+/// Answers `@bot <blink url>` with a card per action, each with a deep link that
+/// opens the proposal flow on that action
+pub async fn handle_inline_query(bot: Bot, me: Me, q: InlineQuery) -> HandlerResult {
+    let results = match parse_action_url(&q.query) {
+        Some(url) if has_role(q.from.id, Role::Proposer).await => {
+            // Inline queries come without a chat, the private one shares the user's id
+            let language = get_language(ChatId(q.from.id.0 as i64), Some(&q.from)).await;
+
+            get_inline_results(&me, q.from.id, &url, language).await
+        }
+        _ => vec![],
+    };
+
+    bot.answer_inline_query(&q.id, results)
+        .is_personal(true)
+        .await?;
+
+    Ok(())
+}
+
+async fn get_inline_results(
+    me: &Me,
+    user_id: UserId,
+    url: &Url,
+    language: &str,
+) -> Vec<InlineQueryResult> {
+    let metadata = match get_blink_metadata(&url.to_string()).await {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            log::debug!("{} is not a Blink: {}", url, e);
+            return vec![];
+        }
+    };
+
+    // A Blink without actions is shared as a whole
+    let mut labels: Vec<String> = metadata
+        .links
+        .as_ref()
+        .map(|links| {
+            links
+                .actions
+                .iter()
+                .map(|action| action.label.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+    if labels.is_empty() {
+        labels.push("".to_string());
+    }
+
+    let mut results = vec![];
+
+    for label in labels {
+        let shared_action = create_shared_action(url.as_str(), &label, &[], user_id).await;
+
+        let mut start_url = me.tme_url();
+        start_url.set_query(Some(&format!(
+            "start={}{}",
+            SHARED_ACTION_START_PREFIX, shared_action.id
+        )));
+
+        let text = format!(
+            "<b>{}</b>\n\n{}{}",
+            html::escape(&metadata.title),
+            html::escape(&metadata.description),
+            if label.is_empty() {
+                "".to_string()
+            } else {
+                format!(
+                    "\n\n{}",
+                    translate(
+                        language,
+                        "inline-action",
+                        &[("action", html::escape(&label).into())],
+                    )
+                )
+            }
+        );
+
+        let mut article = InlineQueryResultArticle::new(
+            shared_action.id.to_string(),
+            if label.is_empty() {
+                metadata.title.clone()
+            } else {
+                label.clone()
+            },
+            InputMessageContent::Text(
+                InputMessageContentText::new(text).parse_mode(ParseMode::Html),
+            ),
+        )
+        .description(metadata.description.clone())
+        .reply_markup(InlineKeyboardMarkup::new([[InlineKeyboardButton::url(
+            translate(language, "button-propose-with-treasury", &[]),
+            start_url,
+        )]]));
+
+        if let Ok(icon) = Url::parse(&metadata.icon) {
+            article = article.thumbnail_url(icon);
+        }
+
+        results.push(InlineQueryResult::Article(article));
+    }
+
+    results
+}
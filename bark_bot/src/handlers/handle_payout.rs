@@ -0,0 +1,189 @@
+use crate::collections::{
+    HandlerResult, MyDialogue, PayoutData, ProposalError, RequestProgress, Role,
+};
+use crate::messages::{post_transaction_request, refresh_transaction_request};
+use crate::utils::{
+    format_policy_violations, format_pubkey, get_group_chat_id, get_language,
+    get_multisig_authority_pubkey, get_multisig_pubkey, get_transfer_instructions, has_role,
+    split_into_batches, translate, PAYOUT_CALLBACK_PREFIX,
+};
+use rand::Rng;
+use teloxide::{prelude::*, types::ParseMode, utils::html};
+
+/// Creates the proposals of a confirmed payout, linked together through a batch id
+pub async fn handle_payout(
+    bot: Bot,
+    dialogue: MyDialogue,
+    data: PayoutData,
+    q: CallbackQuery,
+) -> HandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+
+    let language = get_language(dialogue.chat_id(), Some(&q.from)).await;
+
+    if let Some(message) = &q.message {
+        bot.edit_message_reply_markup(message.chat().id, message.id())
+            .await?;
+    }
+
+    if q.data.as_deref() != Some(&format!("{}confirm", PAYOUT_CALLBACK_PREFIX)) {
+        bot.send_message(
+            dialogue.chat_id(),
+            translate(language, "payout-cancelled", &[]),
+        )
+        .await?;
+        dialogue.exit().await?;
+        return Ok(());
+    }
+
+    // Roles can change while the dialogue is open
+    if !has_role(q.from.id, Role::Proposer).await {
+        bot.send_message(
+            dialogue.chat_id(),
+            translate(language, "no-proposer-role", &[]),
+        )
+        .await?;
+        dialogue.exit().await?;
+        return Ok(());
+    }
+
+    dialogue.exit().await?;
+
+    let multisig_pubkey = get_multisig_pubkey();
+    let vault_pubkey = get_multisig_authority_pubkey(multisig_pubkey, 1);
+    let groups: Vec<_> = data
+        .rows
+        .iter()
+        .map(|row| {
+            get_transfer_instructions(
+                vault_pubkey,
+                row.recipient,
+                row.base_amount,
+                row.mint
+                    .as_ref()
+                    .map(|(mint, mint_info)| (*mint, mint_info)),
+                row.create_account,
+            )
+        })
+        .collect();
+    let batches = split_into_batches(multisig_pubkey, q.from.id, &groups);
+    let batch_id = format!("{:016x}", rand::thread_rng().gen::<u64>());
+    let mut transaction_ids: Vec<i64> = vec![];
+    // Proposals are read in the group
+    let group_language = get_language(get_group_chat_id(), None).await;
+
+    bot.send_message(
+        dialogue.chat_id(),
+        translate(
+            language,
+            "payout-creating",
+            &[("count", batches.len().into())],
+        ),
+    )
+    .await?;
+
+    for (number, batch) in batches.iter().enumerate() {
+        let instructions = batch
+            .iter()
+            .flat_map(|index| groups[*index].clone())
+            .collect();
+        let memos: Vec<(String, String)> = batch
+            .iter()
+            .map(|index| &data.rows[*index])
+            .filter(|row| !row.memo.is_empty())
+            .map(|row| {
+                (
+                    row.label
+                        .clone()
+                        .unwrap_or_else(|| format_pubkey(&row.recipient)),
+                    row.memo.clone(),
+                )
+            })
+            .collect();
+
+        let result = crate::actions::propose_instructions(
+            multisig_pubkey,
+            q.from.id,
+            instructions,
+            &translate(
+                group_language,
+                "payout-proposal-title",
+                &[
+                    ("file", data.file_name.clone().into()),
+                    ("position", (number + 1).into()),
+                    ("total", batches.len().into()),
+                ],
+            ),
+            &translate(
+                group_language,
+                "payout-proposal-description",
+                &[("count", batch.len().into())],
+            ),
+            &memos,
+            Some(&batch_id),
+        )
+        .await;
+
+        match result {
+            Ok(transaction_entry) => {
+                post_transaction_request(&bot, &transaction_entry).await?;
+                transaction_ids.push(transaction_entry.id);
+            }
+            Err(error) => {
+                let position = (number + 1).into();
+                let total = batches.len().into();
+                let text = match error {
+                    ProposalError::Refused(violations) => translate(
+                        language,
+                        "payout-refused",
+                        &[
+                            ("position", position),
+                            ("total", total),
+                            (
+                                "violations",
+                                format_policy_violations(language, &violations).into(),
+                            ),
+                        ],
+                    ),
+                    ProposalError::Failed(e) => translate(
+                        language,
+                        "payout-failed",
+                        &[
+                            ("position", position),
+                            ("total", total),
+                            (
+                                "error",
+                                html::escape(&e.to_string().chars().take(200).collect::<String>())
+                                    .into(),
+                            ),
+                        ],
+                    ),
+                };
+
+                bot.send_message(dialogue.chat_id(), text)
+                    .parse_mode(ParseMode::Html)
+                    .await?;
+                break;
+            }
+        }
+    }
+
+    // Each message links to the others, which only exist once all of them are posted
+    if transaction_ids.len() > 1 {
+        for transaction_id in &transaction_ids {
+            refresh_transaction_request(&bot, *transaction_id, &RequestProgress::Idle).await?;
+        }
+    }
+
+    bot.send_message(
+        dialogue.chat_id(),
+        translate(
+            language,
+            "payout-created",
+            &[("count", transaction_ids.len().into())],
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
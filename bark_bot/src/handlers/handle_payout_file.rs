@@ -0,0 +1,348 @@
+use crate::collections::{
+    Handler, HandlerResult, MintInfo, MyDialogue, PayoutData, PayoutRow, Role,
+};
+use crate::requests::{get_address_book, get_mint_info, get_token_account_info};
+use crate::utils::{
+    download_text_file, find_user_keypair, format_base_amount, format_localized_amount,
+    format_token, get_associated_token_address, get_group_chat_id, get_language,
+    get_multisig_authority_pubkey, get_multisig_pubkey, get_timestamp, get_transfer_instructions,
+    has_role, parse_token_amount, split_into_batches, translate, MAX_PAYOUT_ROWS,
+    PAYOUT_CALLBACK_PREFIX, SOL_DECIMALS,
+};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::str::FromStr;
+use teloxide::{
+    prelude::*,
+    types::{InlineKeyboardButton, InlineKeyboardMarkup, ParseMode},
+    utils::html,
+};
+const MAX_FILE_SIZE: u32 = 1024 * 1024;
+
+/// Validates a payout CSV sent in private (recipient, amount, token, memo) and asks for
+/// confirmation before creating the proposals
+pub async fn handle_payout_file(bot: Bot, dialogue: MyDialogue, msg: Message) -> HandlerResult {
+    let (Some(user), Some(document)) = (&msg.from, msg.document()) else {
+        return Ok(());
+    };
+
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    if !has_role(user.id, Role::Proposer).await {
+        bot.send_message(msg.chat.id, translate(language, "no-proposer-role", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    if find_user_keypair(user.id).is_none() {
+        bot.send_message(msg.chat.id, translate(language, "no-signer-key", &[]))
+            .await?;
+        return Ok(());
+    }
+
+    if document.file.size > MAX_FILE_SIZE {
+        bot.send_message(
+            msg.chat.id,
+            translate(language, "payout-file-too-large", &[]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let csv = download_text_file(&bot, document).await?;
+    let address_book = get_address_book(get_group_chat_id()).await;
+    let mut mints: HashMap<Pubkey, Option<MintInfo>> = HashMap::new();
+    let mut rows: Vec<PayoutRow> = vec![];
+    let mut totals: BTreeMap<String, (u64, u8)> = BTreeMap::new();
+    let mut errors: Vec<String> = vec![];
+
+    for (line_number, line) in csv.lines().enumerate() {
+        let line_number = line_number + 1;
+        let Some(fields) = split_payout_line(line) else {
+            continue;
+        };
+
+        let [recipient, amount, token, memo @ ..] = fields.as_slice() else {
+            errors.push(translate(
+                language,
+                "payout-line-missing-fields",
+                &[("line", line_number.into())],
+            ));
+            continue;
+        };
+
+        let entry = address_book
+            .iter()
+            .find(|entry| entry.label.eq_ignore_ascii_case(recipient));
+        let address = entry.map_or(*recipient, |entry| entry.address.as_str());
+        let Ok(recipient_pubkey) = Pubkey::from_str(address) else {
+            errors.push(translate(
+                language,
+                "payout-line-unknown-recipient",
+                &[
+                    ("line", line_number.into()),
+                    ("recipient", recipient.to_string().into()),
+                ],
+            ));
+            continue;
+        };
+
+        let mint = if token.eq_ignore_ascii_case("SOL") {
+            None
+        } else {
+            let Ok(mint) = Pubkey::from_str(token) else {
+                errors.push(translate(
+                    language,
+                    "payout-line-unknown-token",
+                    &[
+                        ("line", line_number.into()),
+                        ("token", token.to_string().into()),
+                    ],
+                ));
+                continue;
+            };
+
+            let mint_info = match mints.get(&mint) {
+                Some(mint_info) => mint_info.clone(),
+                None => {
+                    let mint_info = get_mint_info(mint).await;
+                    mints.insert(mint, mint_info.clone());
+                    mint_info
+                }
+            };
+
+            match mint_info {
+                Some(mint_info) => Some((mint, mint_info)),
+                None => {
+                    errors.push(translate(
+                        language,
+                        "payout-line-not-a-mint",
+                        &[
+                            ("line", line_number.into()),
+                            ("token", token.to_string().into()),
+                        ],
+                    ));
+                    continue;
+                }
+            }
+        };
+
+        let decimals = mint
+            .as_ref()
+            .map_or(SOL_DECIMALS, |(_, mint_info)| mint_info.decimals);
+        let Some(base_amount) = parse_token_amount(amount, decimals) else {
+            errors.push(translate(
+                language,
+                "payout-line-invalid-amount",
+                &[
+                    ("line", line_number.into()),
+                    ("amount", amount.to_string().into()),
+                ],
+            ));
+            continue;
+        };
+
+        // Totals are u64 base units like the transfers, a file overflowing them is rejected
+        let token_label = mint.as_ref().map_or("SOL".to_string(), |(mint, _)| {
+            format_token(&mint.to_string())
+        });
+        let total = totals.entry(token_label.clone()).or_insert((0, decimals));
+        let Some(new_total) = total.0.checked_add(base_amount) else {
+            errors.push(translate(
+                language,
+                "payout-line-total-overflow",
+                &[("line", line_number.into()), ("token", token_label.into())],
+            ));
+            continue;
+        };
+        total.0 = new_total;
+
+        // Only the first transfer to a new token account needs to create it
+        let create_account = match &mint {
+            Some((mint, mint_info)) => {
+                let destination =
+                    get_associated_token_address(recipient_pubkey, *mint, mint_info.token_program);
+
+                get_token_account_info(destination).await.is_none()
+                    && !rows.iter().any(|row| {
+                        row.create_account
+                            && row.recipient == recipient_pubkey
+                            && row.mint.as_ref().map(|(row_mint, _)| row_mint) == Some(mint)
+                    })
+            }
+            None => false,
+        };
+
+        rows.push(PayoutRow {
+            recipient: recipient_pubkey,
+            label: entry.map(|entry| entry.label.clone()),
+            amount: amount.to_string(),
+            base_amount,
+            mint,
+            create_account,
+            memo: memo.first().unwrap_or(&"").to_string(),
+        });
+    }
+
+    if rows.len() > MAX_PAYOUT_ROWS {
+        errors.push(translate(
+            language,
+            "payout-too-many-rows",
+            &[
+                ("count", rows.len().into()),
+                ("limit", MAX_PAYOUT_ROWS.into()),
+            ],
+        ));
+    }
+
+    if rows.is_empty() && errors.is_empty() {
+        errors.push(translate(language, "payout-no-rows", &[]));
+    }
+
+    if !errors.is_empty() {
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "payout-file-errors",
+                &[("errors", html::escape(&errors.join("\n")).into())],
+            ),
+        )
+        .parse_mode(ParseMode::Html)
+        .await?;
+        return Ok(());
+    }
+
+    let multisig_pubkey = get_multisig_pubkey();
+    let vault_pubkey = get_multisig_authority_pubkey(multisig_pubkey, 1);
+    let groups: Vec<_> = rows
+        .iter()
+        .map(|row| {
+            get_transfer_instructions(
+                vault_pubkey,
+                row.recipient,
+                row.base_amount,
+                row.mint
+                    .as_ref()
+                    .map(|(mint, mint_info)| (*mint, mint_info)),
+                row.create_account,
+            )
+        })
+        .collect();
+    let batches = split_into_batches(multisig_pubkey, user.id, &groups).len();
+
+    let recipients: HashSet<Pubkey> = rows.iter().map(|row| row.recipient).collect();
+    let mut text = translate(
+        language,
+        "payout-summary",
+        &[
+            (
+                "file",
+                html::escape(document.file_name.as_deref().unwrap_or_default()).into(),
+            ),
+            ("transfers", rows.len().into()),
+            ("recipients", recipients.len().into()),
+            ("proposals", batches.into()),
+        ],
+    );
+
+    for (token, (amount, decimals)) in &totals {
+        text.push_str(&format!(
+            "\n• {} {}",
+            format_localized_amount(
+                &format_base_amount(u128::from(*amount), *decimals),
+                language
+            ),
+            html::escape(token)
+        ));
+    }
+
+    let new_accounts = rows.iter().filter(|row| row.create_account).count();
+    if new_accounts > 0 {
+        text.push_str(&format!(
+            "\n\n{}",
+            translate(
+                language,
+                "payout-new-accounts",
+                &[("count", new_accounts.into())],
+            )
+        ));
+    }
+
+    let buttons = [
+        InlineKeyboardButton::callback(
+            translate(
+                language,
+                "button-create-proposals",
+                &[("count", batches.into())],
+            ),
+            format!("{}confirm", PAYOUT_CALLBACK_PREFIX),
+        ),
+        InlineKeyboardButton::callback(
+            translate(language, "button-discard-proposal", &[]),
+            format!("{}cancel", PAYOUT_CALLBACK_PREFIX),
+        ),
+    ];
+
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .reply_markup(InlineKeyboardMarkup::new([buttons]))
+        .await?;
+
+    dialogue
+        .update(Handler::Payout {
+            data: PayoutData {
+                rows,
+                file_name: document.file_name.clone().unwrap_or_default(),
+                updated_at: get_timestamp(),
+            },
+        })
+        .await?;
+
+    Ok(())
+}
+
+/// Fields of a CSV line, the memo keeps its commas. None for blank lines and the header.
+fn split_payout_line(line: &str) -> Option<Vec<&str>> {
+    let fields: Vec<&str> = line
+        .splitn(4, ',')
+        .map(|field| field.trim().trim_matches('"'))
+        .collect();
+
+    if line.trim().is_empty() || fields[0].eq_ignore_ascii_case("recipient") {
+        return None;
+    }
+
+    Some(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_quoted_fields_and_keeps_commas_in_the_memo() {
+        assert_eq!(
+            split_payout_line(r#" "payroll", 1.5 ,SOL,"June, week 2" "#),
+            Some(vec!["payroll", "1.5", "SOL", "June, week 2"])
+        );
+        assert_eq!(
+            split_payout_line("payroll,1.5,SOL"),
+            Some(vec!["payroll", "1.5", "SOL"])
+        );
+    }
+
+    #[test]
+    fn skips_blank_lines_and_the_header() {
+        assert_eq!(split_payout_line("   "), None);
+        assert_eq!(split_payout_line("Recipient,Amount,Token,Memo"), None);
+    }
+
+    #[test]
+    fn keeps_short_lines_for_the_caller_to_report() {
+        assert_eq!(
+            split_payout_line("payroll,1.5"),
+            Some(vec!["payroll", "1.5"])
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::actions::perform_transaction_action;
+use crate::collections::{HandlerResult, InFlight};
+use crate::requests::get_transaction_by_index;
+use crate::utils::{
+    check_transaction_action, get_disabled_link_preview, get_explorer_url, get_language,
+    get_transaction_message_url, parse_transaction_index, translate,
+};
+use teloxide::{
+    prelude::*,
+    types::{ParseMode, ReplyParameters},
+    utils::html,
+};
+
+/// Runs /approve, /reject and /execute with the same checks as the proposal buttons
+pub async fn handle_transaction_command(
+    bot: Bot,
+    in_flight: InFlight,
+    msg: Message,
+    args: String,
+    action: &str,
+) -> HandlerResult {
+    let Some(user) = &msg.from else {
+        return Ok(());
+    };
+    let language = get_language(msg.chat.id, Some(user)).await;
+
+    let Some(transaction_index) = parse_transaction_index(&args) else {
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "transaction-command-usage",
+                &[("command", action.to_lowercase().into())],
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let Some(transaction_entry) = get_transaction_by_index(transaction_index).await else {
+        bot.send_message(
+            msg.chat.id,
+            translate(
+                language,
+                "proposal-not-found",
+                &[("index", transaction_index.into())],
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    if let Err(reason) =
+        check_transaction_action(&bot, user, &transaction_entry, action, language).await?
+    {
+        bot.send_message(msg.chat.id, reason).await?;
+        return Ok(());
+    }
+
+    let Some(_guard) = in_flight.try_lock(transaction_entry.id).await else {
+        bot.send_message(msg.chat.id, translate(language, "action-in-progress", &[]))
+            .await?;
+        return Ok(());
+    };
+
+    let link = get_transaction_message_url(&transaction_entry)
+        .map(|url| {
+            format!(
+                " · <a href=\"{}\">{}</a>",
+                url,
+                translate(language, "view-proposal", &[])
+            )
+        })
+        .unwrap_or_default();
+
+    let text = match perform_transaction_action(&bot, user, &transaction_entry, action).await? {
+        Ok(signature) => format!(
+            "{}{}",
+            translate(
+                language,
+                "action-done",
+                &[
+                    ("action", action.into()),
+                    ("index", transaction_index.into()),
+                    ("url", get_explorer_url("tx", &signature.to_string()).into()),
+                ],
+            ),
+            link
+        ),
+        Err(e) => format!(
+            "{}{}",
+            translate(
+                language,
+                "action-command-failed",
+                &[
+                    ("action", action.into()),
+                    ("index", transaction_index.into()),
+                    ("error", html::escape(&e).into()),
+                ],
+            ),
+            link
+        ),
+    };
+
+    bot.send_message(msg.chat.id, text)
+        .parse_mode(ParseMode::Html)
+        .link_preview_options(get_disabled_link_preview())
+        .reply_parameters(ReplyParameters::new(msg.id))
+        .await?;
+
+    Ok(())
+}
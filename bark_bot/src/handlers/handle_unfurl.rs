@@ -0,0 +1,93 @@
+use crate::collections::{HandlerResult, JoinStorage, MyDialogue, Role};
+use crate::handlers::start_blink_flow;
+use crate::requests::upsert_user;
+use crate::utils::{find_action_url, get_language, has_role, translate, UNFURL_CALLBACK_PREFIX};
+use teloxide::prelude::*;
+
+/// Starts the proposal flow of a previewed Blink, in the group under the preview or
+/// in the member's private chat
+pub async fn handle_unfurl(bot: Bot, storage: JoinStorage, q: CallbackQuery) -> HandlerResult {
+    let language = match q.regular_message() {
+        Some(preview) => get_language(preview.chat.id, Some(&q.from)).await,
+        None => get_language(ChatId::from(q.from.id), Some(&q.from)).await,
+    };
+
+    if !has_role(q.from.id, Role::Proposer).await {
+        bot.answer_callback_query(&q.id)
+            .text(translate(language, "no-proposer-role", &[]))
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    }
+
+    // The preview replies to the message with the link
+    let Some((preview, url)) = q.regular_message().and_then(|preview| {
+        let url = find_action_url(preview.reply_to_message()?)?;
+        Some((preview, url))
+    }) else {
+        bot.answer_callback_query(&q.id)
+            .text(translate(language, "unfurl-link-gone", &[]))
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    };
+
+    let in_group = q.data.as_deref() == Some(&format!("{}here", UNFURL_CALLBACK_PREFIX));
+    let chat_id = if in_group {
+        preview.chat.id
+    } else {
+        ChatId::from(q.from.id)
+    };
+    let dialogue = MyDialogue::new(storage, chat_id);
+
+    if in_group
+        && dialogue
+            .get()
+            .await?
+            .is_some_and(|handler| handler.updated_at().is_some())
+    {
+        bot.answer_callback_query(&q.id)
+            .text(translate(language, "unfurl-group-busy", &[]))
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    }
+
+    upsert_user(q.from.id, q.from.full_name(), None).await;
+
+    let reply_to = in_group.then_some(preview.id);
+    if let Err(e) =
+        start_blink_flow(&bot, &dialogue, &q.from, url.as_str(), None, None, reply_to).await
+    {
+        log::warn!("Failed to start the flow of {}: {}", url, e);
+
+        // Bots can only write to users that opened a chat with them
+        bot.answer_callback_query(&q.id)
+            .text(translate(
+                language,
+                if in_group {
+                    "unfurl-load-failed"
+                } else {
+                    "unfurl-open-private-chat"
+                },
+                &[],
+            ))
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    }
+
+    bot.answer_callback_query(&q.id)
+        .text(translate(
+            language,
+            if in_group {
+                "unfurl-pick-action"
+            } else {
+                "unfurl-continue-in-dm"
+            },
+            &[],
+        ))
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use crate::collections::{HandlerResult, MyDialogue, WalletLinkData};
+use crate::requests::{link_wallet, upsert_user};
+use crate::utils::{get_language, get_timestamp, translate};
+use base64::prelude::*;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use std::str::FromStr;
+use teloxide::{prelude::*, types::ParseMode};
+
+pub async fn handle_wallet_link(
+    bot: Bot,
+    dialogue: MyDialogue,
+    data: WalletLinkData,
+    msg: Message,
+) -> HandlerResult {
+    let language = get_language(msg.chat.id, msg.from.as_ref()).await;
+
+    let (Some(user), Some(text)) = (&msg.from, msg.text()) else {
+        bot.send_message(msg.chat.id, translate(language, "link-send-signature", &[]))
+            .await?;
+        return Ok(());
+    };
+
+    if get_timestamp() > data.expires_at {
+        bot.send_message(msg.chat.id, translate(language, "link-expired", &[]))
+            .await?;
+        dialogue.exit().await?;
+        return Ok(());
+    }
+
+    // Wallets hand signatures out either as base58 or as base64
+    let signature = Signature::from_str(text.trim()).ok().or_else(|| {
+        BASE64_STANDARD
+            .decode(text.trim())
+            .ok()
+            .filter(|bytes| bytes.len() == 64)
+            .map(|bytes| Signature::new(&bytes))
+    });
+
+    let pubkey = Pubkey::from_str(&data.pubkey).unwrap();
+
+    let Some(signature) =
+        signature.filter(|signature| signature.verify(&pubkey.to_bytes(), data.message.as_bytes()))
+    else {
+        log::warn!(
+            "Invalid wallet link signature for {} from {} ({})",
+            pubkey,
+            user.full_name(),
+            user.id
+        );
+
+        bot.send_message(
+            msg.chat.id,
+            translate(language, "link-invalid-signature", &[]),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    link_wallet(user.id, pubkey.to_string(), signature.to_string()).await;
+    // The users row keeps the bot's signer key, the wallet is read from the link
+    upsert_user(user.id, user.full_name(), None).await;
+
+    bot.send_message(
+        msg.chat.id,
+        translate(
+            language,
+            "link-done",
+            &[("address", pubkey.to_string().into())],
+        ),
+    )
+    .parse_mode(ParseMode::Html)
+    .await?;
+
+    dialogue.exit().await?;
+
+    Ok(())
+}
@@ -1,9 +1,37 @@
+pub mod confirm_blink_parameters;
+pub mod expire_idle_dialogue;
+pub mod handle_blink_confirmation;
 pub mod handle_blink_url;
 pub mod handle_external_action;
+pub mod handle_group_message;
+pub mod handle_inline_query;
 pub mod handle_internal_action;
 pub mod handle_parameters;
+pub mod handle_payout;
+pub mod handle_payout_file;
+pub mod handle_transaction_command;
+pub mod handle_unfurl;
+pub mod handle_wallet_link;
+pub mod reset_outdated_dialogue;
+pub mod start_blink_action;
+pub mod start_blink_flow;
+pub mod submit_blink_parameters;
 
+pub use confirm_blink_parameters::*;
+pub use expire_idle_dialogue::*;
+pub use handle_blink_confirmation::*;
 pub use handle_blink_url::*;
 pub use handle_external_action::*;
+pub use handle_group_message::*;
+pub use handle_inline_query::*;
 pub use handle_internal_action::*;
 pub use handle_parameters::*;
+pub use handle_payout::*;
+pub use handle_payout_file::*;
+pub use handle_transaction_command::*;
+pub use handle_unfurl::*;
+pub use handle_wallet_link::*;
+pub use reset_outdated_dialogue::*;
+pub use start_blink_action::*;
+pub use start_blink_flow::*;
+pub use submit_blink_parameters::*;
@@ -0,0 +1,34 @@
+use crate::collections::{Handler, MyDialogue};
+use crate::utils::{get_language, translate};
+use teloxide::prelude::*;
+
+/// Replaces a dialogue that couldn't be read after an update, so the update is
+/// handled as the start of a new one
+pub async fn reset_outdated_dialogue(
+    bot: Bot,
+    dialogue: MyDialogue,
+    handler: Handler,
+    upd: Update,
+) -> Handler {
+    if !matches!(handler, Handler::Outdated) {
+        return handler;
+    }
+
+    if let Err(e) = dialogue.exit().await {
+        log::warn!("Failed to reset an outdated dialogue: {}", e);
+    }
+
+    let language = get_language(dialogue.chat_id(), upd.from()).await;
+
+    if let Err(e) = bot
+        .send_message(
+            dialogue.chat_id(),
+            translate(language, "dialogue-outdated", &[]),
+        )
+        .await
+    {
+        log::warn!("Failed to send the dialogue reset notice: {}", e);
+    }
+
+    Handler::default()
+}
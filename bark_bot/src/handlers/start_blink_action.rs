@@ -0,0 +1,248 @@
+use crate::collections::{
+    Action, Handler, HandlerResult, InternalActionData, MyDialogue, ParametersData,
+};
+use crate::handlers::confirm_blink_parameters;
+use crate::messages::{post_transaction_request, send_proposal_error, send_proposal_receipt};
+use crate::utils::{get_multisig_pubkey, get_timestamp, get_url_root, translate};
+use teloxide::{prelude::*, types::ParseMode, utils::html};
+
+/// Proposes the chosen action of a Blink, or starts asking for the parameters that
+/// `presets` doesn't fill in. Presets come from a shared link, so those flows always
+/// end with a summary the proposer has to confirm
+pub async fn start_blink_action(
+    bot: &Bot,
+    dialogue: &MyDialogue,
+    data: InternalActionData,
+    action: &Action,
+    presets: Option<&[(String, String)]>,
+    language: &str,
+) -> HandlerResult {
+    let parameters = action.parameters.clone();
+
+    // Nothing to ask, only the confirmation is left
+    if presets.is_some() && parameters.as_ref().is_none_or(|p| p.is_empty()) {
+        let url = match parameters {
+            Some(_) => format!("{}{}", data.base_url, action.href),
+            None => data.url,
+        };
+        let parameters_data = ParametersData {
+            parameter_names: vec![],
+            parameter_labels: vec![],
+            url,
+            parameters_number: 0,
+            parameters_values: vec![],
+            action_title: data.action_title,
+            action_description: data.action_description,
+            action_icon: data.action_icon,
+            user_id: data.user_id,
+            confirm: true,
+            updated_at: get_timestamp(),
+        };
+
+        return confirm_blink_parameters(bot, dialogue, parameters_data, language).await;
+    }
+
+    match parameters {
+        Some(parameters_res) => {
+            if parameters_res.is_empty() {
+                bot.send_message(
+                    dialogue.chat_id(),
+                    translate(language, "processing-blink", &[]),
+                )
+                .await?;
+
+                let action_url = format!("{}{}", data.base_url, action.href);
+                let multisig_pubkey = get_multisig_pubkey();
+                let transaction_entry = match crate::actions::create_transaction(
+                    &action_url,
+                    multisig_pubkey,
+                    data.user_id,
+                    &data.action_title,
+                    &data.action_description,
+                    &[],
+                    Some(&data.action_icon),
+                )
+                .await
+                {
+                    Ok(transaction_entry) => transaction_entry,
+                    Err(error) => {
+                        send_proposal_error(
+                            bot,
+                            dialogue.chat_id(),
+                            language,
+                            &data.action_title,
+                            &error,
+                        )
+                        .await?;
+                        dialogue.exit().await?;
+                        return Ok(());
+                    }
+                };
+                let group_message = post_transaction_request(bot, &transaction_entry).await?;
+
+                send_proposal_receipt(
+                    bot,
+                    dialogue.chat_id(),
+                    language,
+                    &transaction_entry,
+                    &group_message,
+                )
+                .await?;
+
+                dialogue.exit().await?;
+            } else {
+                // Preset values go first so only the remaining parameters are asked
+                let preset_value = |name: &str| {
+                    presets
+                        .unwrap_or_default()
+                        .iter()
+                        .find(|(preset_name, _)| preset_name == name)
+                        .map(|(_, value)| value.clone())
+                };
+                let mut parameters_res = parameters_res;
+                parameters_res.sort_by_key(|p| preset_value(&p.name).is_none());
+
+                let parameter_names: Vec<String> =
+                    parameters_res.iter().map(|p| p.name.clone()).collect();
+                let parameter_labels: Vec<String> =
+                    parameters_res.iter().map(|p| p.label.clone()).collect();
+                let parameters_values: Vec<String> = parameters_res
+                    .iter()
+                    .map_while(|p| preset_value(&p.name))
+                    .collect();
+                let preset_count = parameters_values.len();
+
+                let parameters_data = ParametersData {
+                    parameter_names,
+                    parameter_labels,
+                    url: format!("{}{}", get_url_root(&data.url).unwrap(), action.href),
+                    parameters_number: (preset_count + 1) as u8,
+                    parameters_values: parameters_values.clone(),
+                    action_title: data.action_title,
+                    action_description: data.action_description,
+                    action_icon: data.action_icon,
+                    user_id: data.user_id,
+                    confirm: presets.is_some(),
+                    updated_at: get_timestamp(),
+                };
+
+                if preset_count == parameters_res.len() {
+                    return confirm_blink_parameters(bot, dialogue, parameters_data, language)
+                        .await;
+                }
+
+                let mut result_parameters = String::new();
+
+                for (index, parameter) in parameters_res.iter().enumerate() {
+                    if index < preset_count {
+                        result_parameters.push_str(&format!(
+                            "{} - {} = {}\n\n",
+                            index + 1,
+                            html::escape(&parameter.label),
+                            html::escape(&parameters_values[index])
+                        ));
+                        continue;
+                    }
+
+                    match parameter.required {
+                        Some(is_required) => {
+                            result_parameters.push_str(&format!(
+                                "{} - {} ({})\n\n",
+                                index + 1,
+                                html::escape(&parameter.label),
+                                translate(
+                                    language,
+                                    if is_required {
+                                        "parameter-required"
+                                    } else {
+                                        "parameter-optional"
+                                    },
+                                    &[]
+                                )
+                            ));
+                        }
+                        None => {
+                            result_parameters.push_str(&format!(
+                                "{} - {} ({})\n\n",
+                                index + 1,
+                                html::escape(&parameter.label),
+                                translate(language, "parameter-optional", &[])
+                            ));
+                        }
+                    }
+                }
+
+                bot.send_message(
+                    dialogue.chat_id(),
+                    translate(
+                        language,
+                        "action-parameters",
+                        &[
+                            ("action", html::escape(&action.label).into()),
+                            ("parameters", result_parameters.into()),
+                        ],
+                    ),
+                )
+                .parse_mode(ParseMode::Html)
+                .await?;
+
+                bot.send_message(
+                    dialogue.chat_id(),
+                    html::escape(&parameters_data.parameter_labels[preset_count]),
+                )
+                .parse_mode(ParseMode::Html)
+                .await?;
+
+                dialogue
+                    .update(Handler::Parameters {
+                        data: parameters_data,
+                    })
+                    .await?;
+            }
+        }
+        None => {
+            // TODO: Does it ever gets here?
+
+            let multisig_pubkey = get_multisig_pubkey();
+            let transaction_entry = match crate::actions::create_transaction(
+                &data.url,
+                multisig_pubkey,
+                data.user_id,
+                &data.action_title,
+                &data.action_description,
+                &[],
+                Some(&data.action_icon),
+            )
+            .await
+            {
+                Ok(transaction_entry) => transaction_entry,
+                Err(error) => {
+                    send_proposal_error(
+                        bot,
+                        dialogue.chat_id(),
+                        language,
+                        &data.action_title,
+                        &error,
+                    )
+                    .await?;
+                    dialogue.exit().await?;
+                    return Ok(());
+                }
+            };
+            let group_message = post_transaction_request(bot, &transaction_entry).await?;
+
+            send_proposal_receipt(
+                bot,
+                dialogue.chat_id(),
+                language,
+                &transaction_entry,
+                &group_message,
+            )
+            .await?;
+
+            dialogue.exit().await?;
+        }
+    }
+
+    Ok(())
+}
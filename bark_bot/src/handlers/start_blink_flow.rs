@@ -0,0 +1,143 @@
+use crate::{
+    collections::{Handler, HandlerResult, InternalActionData, MyDialogue, Template},
+    handlers::start_blink_action,
+    messages::send_blink_preview,
+    requests::{get_blink_metadata, get_blink_transaction},
+    utils::{
+        get_language, get_multisig_pubkey, get_timestamp, render_template, translate,
+        BLINK_CALLBACK_PREFIX,
+    },
+};
+use serde_json::json;
+use teloxide::{
+    prelude::*,
+    types::{InlineKeyboardButton, InlineKeyboardMarkup, MessageId, ParseMode, User},
+    utils::html,
+};
+use url::Url;
+
+/// Shows the actions of a Blink in the dialogue's chat and waits for the proposer to
+/// pick one, or goes on with `action_label` and `presets` when a shared link chose them
+pub async fn start_blink_flow(
+    bot: &Bot,
+    dialogue: &MyDialogue,
+    user: &User,
+    url: &str,
+    action_label: Option<&str>,
+    presets: Option<&[(String, String)]>,
+    reply_to: Option<MessageId>,
+) -> HandlerResult {
+    let language = get_language(dialogue.chat_id(), Some(user)).await;
+    let response = get_blink_metadata(&url.to_string()).await;
+    let parsed_url = Url::parse(url)?;
+    let base_url = format!("{}://{}", parsed_url.scheme(), parsed_url.host().unwrap());
+
+    match response {
+        Ok(res) => {
+            let links = res.links;
+
+            match links {
+                Some(links_res) => {
+                    let actions = links_res.actions;
+                    let chosen_action = actions
+                        .iter()
+                        .find(|action| Some(action.label.as_str()) == action_label)
+                        .cloned();
+
+                    let actions_data = InternalActionData {
+                        actions: actions.clone(),
+                        url: url.to_string(),
+                        action_title: res.title.clone(),
+                        action_description: res.description.clone(),
+                        action_icon: res.icon.clone(),
+                        user_id: user.id,
+                        base_url,
+                        updated_at: get_timestamp(),
+                    };
+
+                    if let Some(action) = chosen_action {
+                        return start_blink_action(
+                            bot,
+                            dialogue,
+                            actions_data,
+                            &action,
+                            presets,
+                            language,
+                        )
+                        .await;
+                    }
+
+                    let action_buttons = actions.iter().map(|action| {
+                        InlineKeyboardButton::callback(
+                            &action.label,
+                            format!("{}{}", BLINK_CALLBACK_PREFIX, action.label),
+                        )
+                    });
+
+                    let text = render_template(
+                        Template::Preview,
+                        dialogue.chat_id(),
+                        language,
+                        &json!({
+                            "title": res.title,
+                            "description": res.description,
+                            "choose_action": true,
+                        }),
+                    );
+
+                    send_blink_preview(
+                        bot,
+                        dialogue.chat_id(),
+                        &res.icon,
+                        text,
+                        Some(InlineKeyboardMarkup::new([action_buttons])),
+                        reply_to,
+                    )
+                    .await?;
+
+                    dialogue
+                        .update(Handler::InternalAction { data: actions_data })
+                        .await?;
+                }
+                None => {
+                    let multisig_pubkey = get_multisig_pubkey();
+                    let transaction_response =
+                        get_blink_transaction(multisig_pubkey, &url.to_string()).await?;
+
+                    let text = render_template(
+                        Template::Preview,
+                        dialogue.chat_id(),
+                        language,
+                        &json!({
+                            "title": res.title,
+                            "description": res.description,
+                            "transaction": transaction_response.transaction,
+                        }),
+                    );
+
+                    send_blink_preview(bot, dialogue.chat_id(), &res.icon, text, None, None)
+                        .await?;
+
+                    dialogue.exit().await?;
+                }
+            }
+        }
+        Err(e) => {
+            let text = render_template(
+                Template::Error,
+                dialogue.chat_id(),
+                language,
+                &json!({
+                    "message": translate(language, "blink-error", &[]),
+                    "details": html::escape(&e.to_string()),
+                }),
+            );
+
+            bot.send_message(dialogue.chat_id(), text)
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::{
+    collections::{HandlerResult, MyDialogue, ParametersData},
+    messages::{post_transaction_request, send_proposal_error, send_proposal_receipt},
+    utils::{fill_action_url, get_multisig_pubkey, translate},
+};
+use teloxide::prelude::*;
+
+/// Fills the action URL with the collected values and proposes the transaction
+pub async fn submit_blink_parameters(
+    bot: &Bot,
+    dialogue: &MyDialogue,
+    data: ParametersData,
+    parameters_values: Vec<String>,
+    language: &str,
+) -> HandlerResult {
+    let request_url = fill_action_url(&data.url, &data.parameter_names, &parameters_values);
+
+    let parameters: Vec<(String, String)> = data
+        .parameter_labels
+        .iter()
+        .cloned()
+        .zip(parameters_values)
+        .collect();
+
+    bot.send_message(
+        dialogue.chat_id(),
+        translate(language, "processing-blink", &[]),
+    )
+    .await?;
+
+    let multsig_pubkey = get_multisig_pubkey();
+    let transaction_entry = match crate::actions::create_transaction(
+        &request_url,
+        multsig_pubkey,
+        data.user_id,
+        &data.action_title,
+        &data.action_description,
+        &parameters,
+        Some(&data.action_icon),
+    )
+    .await
+    {
+        Ok(transaction_entry) => transaction_entry,
+        Err(error) => {
+            send_proposal_error(
+                bot,
+                dialogue.chat_id(),
+                language,
+                &data.action_title,
+                &error,
+            )
+            .await?;
+            dialogue.exit().await?;
+            return Ok(());
+        }
+    };
+
+    let group_message = post_transaction_request(bot, &transaction_entry).await?;
+
+    send_proposal_receipt(
+        bot,
+        dialogue.chat_id(),
+        language,
+        &transaction_entry,
+        &group_message,
+    )
+    .await?;
+
+    dialogue.exit().await?;
+
+    Ok(())
+}
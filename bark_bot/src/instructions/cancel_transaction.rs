@@ -0,0 +1,29 @@
+use crate::utils::{get_program, get_transaction_pubkey, get_user_keypair, SQUADS_PROGRAM_ID};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signer::Signer, system_program};
+use teloxide::types::UserId;
+
+pub fn cancel_transaction(
+    multisig_pubkey: Pubkey,
+    transaction_index: u32,
+    user_id: UserId,
+) -> Instruction {
+    let member_keypair = get_user_keypair(user_id);
+    let member_pubkey = member_keypair.pubkey();
+    let program = get_program(member_keypair, SQUADS_PROGRAM_ID);
+    let transaction_pubkey = get_transaction_pubkey(multisig_pubkey, transaction_index);
+
+    return program
+        .request()
+        .accounts(squads_mpl::accounts::CancelTransaction {
+            multisig: multisig_pubkey,
+            transaction: transaction_pubkey,
+            member: member_pubkey,
+            system_program: system_program::ID,
+        })
+        .args(squads_mpl::instruction::CancelTransaction)
+        .instructions()
+        .unwrap()
+        .first()
+        .unwrap()
+        .clone();
+}
@@ -1,6 +1,7 @@
 pub mod activate_transaction;
 pub mod add_instruction;
 pub mod approve_transaction;
+pub mod cancel_transaction;
 pub mod create_transaction;
 pub mod execute_transaction;
 pub mod reject_transaction;
@@ -8,6 +9,7 @@ pub mod reject_transaction;
 pub use activate_transaction::*;
 pub use add_instruction::*;
 pub use approve_transaction::*;
+pub use cancel_transaction::*;
 pub use create_transaction::*;
 pub use execute_transaction::*;
 pub use reject_transaction::*;
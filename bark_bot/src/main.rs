@@ -1,11 +1,13 @@
-use collections::{Command, Config, Handler, JoinStorage};
+use collections::{
+    ActiveDialogues, Command, Config, Handler, HistoryFilter, InFlight, JoinStorage,
+    ParametersData, StorageBackend, TrackedStorage,
+};
 use dptree::{case, deps};
-use std::path::PathBuf;
 use std::sync::Arc;
-use teloxide::dispatching::dialogue::serializer::Json;
 use teloxide::{
-    dispatching::dialogue::{self, ErasedStorage, InMemStorage, SqliteStorage, Storage},
+    dispatching::dialogue::{self, ErasedStorage},
     prelude::*,
+    update_listeners::webhooks,
 };
 use dotenv::dotenv;  // Add this to load environment variables
 
@@ -14,7 +16,10 @@ mod collections;
 mod commands;
 mod handlers;
 mod instructions;
+mod messages;
+mod policies;
 mod requests;
+mod schedulers;
 mod utils;
 
 #[tokio::main]
@@ -27,51 +32,186 @@ async fn main() {
     // Initialize the bot from the environment variables (TELOXIDE_TOKEN must be set)
     let bot = Bot::from_env();
 
-    // Load configuration
+    // Load configuration, an invalid treasury policy stops the bot before it takes updates
+    if utils::get_policy().is_some() {
+        log::info!("Treasury policy loaded");
+    }
+    let storage_settings = utils::get_storage_settings();
     let config = Config {
         channel_id: Some(-4594739971),
-        storage_path: Some(PathBuf::from("db.sqlite")),
+        storage_path: match &storage_settings.backend {
+            StorageBackend::Sqlite(path) => Some(path.clone()),
+            _ => None,
+        },
     };
 
-    // Initialize storage based on configuration
-    let storage: JoinStorage = if let Some(_storage_path) = config.storage_path.clone() {
-        // Use SQLite storage if a path is specified
-        SqliteStorage::open("db.sqlite", Json)
-            .await
-            .unwrap()
-            .erase()
-    } else {
-        // Use in-memory storage if no path is specified
-        InMemStorage::new().erase()
-    };
+    // Initialize the dialogue storage selected by STORAGE (SQLite by default)
+    log::info!("Using {:?} dialogue storage", storage_settings.backend);
+    let active_dialogues = ActiveDialogues::default();
+    let storage: JoinStorage = Arc::new(TrackedStorage {
+        inner: utils::open_storage(&storage_settings).await,
+        active: active_dialogues.clone(),
+    });
+
+    // Start the background jobs that share the bot with the dispatcher
+    tokio::spawn(schedulers::remind_pending_transactions(bot.clone()));
+    tokio::spawn(schedulers::run_schedules(bot.clone()));
+    if utils::get_dialogue_timeout().is_some() {
+        tokio::spawn(schedulers::sweep_idle_dialogues(
+            bot.clone(),
+            storage.clone(),
+            storage_settings.clone(),
+            active_dialogues,
+        ));
+    }
 
     // Define the handler for the bot's updates
-    let handler = dialogue::enter::<Update, ErasedStorage<Handler>, Handler, _>()
-        .branch(Update::filter_callback_query().branch(
-            case![Handler::InternalAction { data }].endpoint(handlers::handle_internal_action),
-        ))
-        .branch(Update::filter_callback_query().endpoint(handlers::handle_external_action))
+    let dialogue_handler = dialogue::enter::<Update, ErasedStorage<Handler>, Handler, _>()
+        .map_async(handlers::reset_outdated_dialogue)
+        .map_async(handlers::expire_idle_dialogue)
         .branch(
-            Update::filter_message()
-                .enter_dialogue::<Message, ErasedStorage<Handler>, Handler>()
-                .branch(case![Handler::BlinkUrl].endpoint(handlers::handle_blink_url))
-                .branch(case![Handler::Parameters { data }].endpoint(handlers::handle_parameters)),
+            Update::filter_callback_query()
+                .filter_map(|q: CallbackQuery| HistoryFilter::try_from(q.data?).ok())
+                .endpoint(commands::history_page),
         )
+        .branch(
+            Update::filter_callback_query()
+                .filter(|q: CallbackQuery| {
+                    q.data
+                        .is_some_and(|data| data.starts_with(utils::UNFURL_CALLBACK_PREFIX))
+                })
+                .endpoint(handlers::handle_unfurl),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .filter(|q: CallbackQuery| {
+                    q.data
+                        .is_some_and(|data| data.starts_with(utils::BLINK_CALLBACK_PREFIX))
+                })
+                .branch(
+                    case![Handler::InternalAction { data }]
+                        .endpoint(handlers::handle_internal_action),
+                ),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .filter(|q: CallbackQuery| {
+                    q.data
+                        .is_some_and(|data| data.starts_with(utils::CONFIRM_CALLBACK_PREFIX))
+                })
+                .branch(
+                    case![Handler::Parameters { data }]
+                        .endpoint(handlers::handle_blink_confirmation),
+                ),
+        )
+        .branch(
+            Update::filter_callback_query()
+                .filter(|q: CallbackQuery| {
+                    q.data
+                        .is_some_and(|data| data.starts_with(utils::PAYOUT_CALLBACK_PREFIX))
+                })
+                .branch(case![Handler::Payout { data }].endpoint(handlers::handle_payout)),
+        )
+        .branch(Update::filter_callback_query().endpoint(handlers::handle_external_action))
+        // Commands go first so they aren't taken as input by the dialogue
         .branch(
             Update::filter_message()
                 .filter_command::<Command>()
                 .branch(case![Command::Help].endpoint(commands::help))
-                .branch(case![Command::Cancel].endpoint(commands::cancel)),
+                .branch(case![Command::Start(payload)].endpoint(commands::start))
+                .branch(case![Command::Cancel].endpoint(commands::cancel))
+                .branch(case![Command::Pending].endpoint(commands::pending))
+                .branch(case![Command::History(args)].endpoint(commands::history))
+                .branch(case![Command::Treasury].endpoint(commands::treasury))
+                .branch(case![Command::Grant(args)].endpoint(commands::grant))
+                .branch(case![Command::Revoke(args)].endpoint(commands::revoke))
+                .branch(case![Command::Roles].endpoint(commands::roles))
+                .branch(case![Command::Link(args)].endpoint(commands::link))
+                .branch(case![Command::AddAddress(args)].endpoint(commands::add_address))
+                .branch(case![Command::RemoveAddress(args)].endpoint(commands::remove_address))
+                .branch(case![Command::Addresses].endpoint(commands::addresses))
+                .branch(
+                    case![Command::ImportAddresses(args)].endpoint(commands::import_addresses),
+                )
+                .branch(case![Command::Transfer(args)].endpoint(commands::transfer))
+                .branch(case![Command::Schedule(args)].endpoint(commands::schedule))
+                .branch(case![Command::Schedules].endpoint(commands::schedules))
+                .branch(case![Command::PauseSchedule(args)].endpoint(commands::pause_schedule))
+                .branch(case![Command::ResumeSchedule(args)].endpoint(commands::resume_schedule))
+                .branch(case![Command::DeleteSchedule(args)].endpoint(commands::delete_schedule))
+                .branch(case![Command::Approve(args)].endpoint(commands::approve))
+                .branch(case![Command::Reject(args)].endpoint(commands::reject))
+                .branch(case![Command::Execute(args)].endpoint(commands::execute))
+                .branch(case![Command::Status(args)].endpoint(commands::status))
+                .branch(case![Command::Deeplink(args)].endpoint(commands::deeplink))
+                .branch(case![Command::Language(args)].endpoint(commands::language)),
+        )
+        .branch(
+            Update::filter_message()
+                .enter_dialogue::<Message, ErasedStorage<Handler>, Handler>()
+                .branch(
+                    dptree::filter(|msg: Message| msg.chat.is_private() && msg.document().is_some())
+                        .endpoint(handlers::handle_payout_file),
+                )
+                // In groups only the proposer's answers and Blink links are picked up
+                .branch(
+                    dptree::filter(|msg: Message| !msg.chat.is_private())
+                        .branch(
+                            case![Handler::Parameters { data }]
+                                .filter(|msg: Message, data: ParametersData| {
+                                    msg.from.is_some_and(|user| user.id == data.user_id)
+                                })
+                                .endpoint(handlers::handle_parameters),
+                        )
+                        .endpoint(handlers::handle_group_message),
+                )
+                .branch(case![Handler::BlinkUrl].endpoint(handlers::handle_blink_url))
+                .branch(case![Handler::Parameters { data }].endpoint(handlers::handle_parameters))
+                .branch(case![Handler::WalletLink { data }].endpoint(handlers::handle_wallet_link)),
         );
 
+    // Inline queries have no chat, so they are handled outside of the dialogue
+    let handler = dptree::entry()
+        .branch(Update::filter_inline_query().endpoint(handlers::handle_inline_query))
+        .branch(dialogue_handler);
+
     // Initialize the dispatcher
-    Dispatcher::builder(bot, handler)
-        .dependencies(deps![storage, Arc::new(config)]) // Pass dependencies (storage and config)
+    let me = bot.get_me().await.unwrap();
+
+    let mut dispatcher = Dispatcher::builder(bot.clone(), handler)
+        .dependencies(deps![storage, Arc::new(config), InFlight::default(), me]) // Pass dependencies (storage, config, in-flight lock and the bot's own user)
         .default_handler(|_| async move {
             // Handle unknown updates here (ignoring them for now)
         })
         .enable_ctrlc_handler() // Enable the ctrl+c handler to stop the bot gracefully
-        .build()
-        .dispatch()
-        .await; // Start dispatching the bot's updates
+        .build();
+
+    // Both modes feed the same handler tree, only the source of the updates changes
+    let Some(webhook_settings) = utils::get_webhook_settings() else {
+        log::info!("No WEBHOOK_URL set, using long polling");
+        dispatcher.dispatch().await; // Start dispatching the bot's updates
+        return;
+    };
+
+    let mut options = webhooks::Options::new(webhook_settings.address, webhook_settings.url);
+    if let Some(secret_token) = webhook_settings.secret_token {
+        options = options.secret_token(secret_token);
+    }
+
+    match webhooks::axum(bot, options).await {
+        Ok(listener) => {
+            log::info!("Receiving updates by webhook on {}", webhook_settings.address);
+            dispatcher
+                .dispatch_with_listener(
+                    listener,
+                    LoggingErrorHandler::with_custom_text("An error from the webhook listener"),
+                )
+                .await;
+        }
+        Err(e) => {
+            // setWebhook failed, so Telegram still holds the updates for getUpdates
+            log::error!("Failed to set up the webhook, falling back to long polling: {}", e);
+            dispatcher.dispatch().await;
+        }
+    }
 }
@@ -0,0 +1,38 @@
+use crate::collections::{ProposalDetails, Transaction};
+use crate::policies::{check_recipients, check_transaction_policy};
+use crate::requests::{get_transactions, GetTransactionsQuery};
+use crate::utils::{get_group_chat_id, get_language};
+use solana_sdk::pubkey::Pubkey;
+use squads_mpl::state::MsTransaction;
+
+pub async fn get_proposal_details(
+    multisig_pubkey: Pubkey,
+    transaction_entry: &Transaction,
+    transaction_account: &MsTransaction,
+) -> ProposalDetails {
+    let batch = match &transaction_entry.batch_id {
+        Some(batch_id) => {
+            let mut batch = get_transactions(&GetTransactionsQuery {
+                batch_id: Some(batch_id.clone()),
+                ..Default::default()
+            })
+            .await
+            .transactions;
+            batch.sort_by_key(|transaction| transaction.id);
+            batch
+        }
+        None => vec![],
+    };
+
+    ProposalDetails {
+        policy: check_transaction_policy(
+            multisig_pubkey,
+            transaction_entry.id,
+            transaction_account,
+        )
+        .await,
+        recipients: check_recipients(transaction_entry.id).await,
+        batch,
+        language: get_language(get_group_chat_id(), None).await,
+    }
+}
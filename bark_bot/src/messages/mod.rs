@@ -0,0 +1,15 @@
+pub mod get_proposal_details;
+pub mod post_transaction_request;
+pub mod refresh_transaction_request;
+pub mod send_blink_icon;
+pub mod send_blink_preview;
+pub mod send_proposal_error;
+pub mod send_proposal_receipt;
+
+pub use get_proposal_details::*;
+pub use post_transaction_request::*;
+pub use refresh_transaction_request::*;
+pub use send_blink_icon::*;
+pub use send_blink_preview::*;
+pub use send_proposal_error::*;
+pub use send_proposal_receipt::*;
@@ -0,0 +1,76 @@
+use crate::collections::{RequestProgress, Transaction};
+use crate::messages::{get_proposal_details, send_blink_icon};
+use crate::requests::{
+    get_multisig_account, get_transaction_account, get_transaction_events, get_users,
+    update_transaction,
+};
+use crate::utils::{
+    get_disabled_link_preview, get_group_chat_id, get_multisig_pubkey,
+    get_transaction_request_buttons, render_transaction_request,
+};
+use teloxide::{
+    prelude::*,
+    types::{InlineKeyboardMarkup, ParseMode, ReplyParameters},
+};
+
+/// Posts a freshly created proposal in the group and links the message to its entry
+pub async fn post_transaction_request(
+    bot: &Bot,
+    transaction_entry: &Transaction,
+) -> ResponseResult<Message> {
+    let multisig_pubkey = get_multisig_pubkey();
+    let multisig_account = get_multisig_account(multisig_pubkey).await;
+    let transaction_account =
+        get_transaction_account(multisig_pubkey, transaction_entry.transaction_index).await;
+
+    let details =
+        get_proposal_details(multisig_pubkey, transaction_entry, &transaction_account).await;
+
+    let text = render_transaction_request(
+        transaction_entry,
+        multisig_account.threshold,
+        &transaction_account,
+        &get_users().await,
+        &get_transaction_events(transaction_entry.id).await,
+        &details,
+        &RequestProgress::Idle,
+    );
+
+    let buttons = get_transaction_request_buttons(
+        transaction_entry.id,
+        multisig_account.threshold,
+        &transaction_account,
+        None,
+        !details.policy.violations.is_empty(),
+        details.language,
+    );
+
+    // The proposal stays a text message to be edited, it answers the icon instead
+    let icon_message = match &transaction_entry.icon_url {
+        Some(icon_url) => send_blink_icon(bot, get_group_chat_id(), icon_url, None, None, None)
+            .await
+            .unwrap_or_else(|e| {
+                log::warn!(
+                    "Failed to send the icon of proposal {}: {}",
+                    transaction_entry.id,
+                    e
+                );
+                None
+            }),
+        None => None,
+    };
+
+    let mut request = bot
+        .send_message(get_group_chat_id(), text)
+        .parse_mode(ParseMode::Html)
+        .link_preview_options(get_disabled_link_preview())
+        .reply_markup(InlineKeyboardMarkup::new([buttons]));
+    if let Some(icon_message) = icon_message {
+        request = request.reply_parameters(ReplyParameters::new(icon_message.id));
+    }
+    let group_message = request.await?;
+
+    update_transaction(transaction_entry.id, group_message.id).await;
+
+    Ok(group_message)
+}
@@ -0,0 +1,81 @@
+use crate::collections::{ButtonMetadata, RequestProgress};
+use crate::messages::get_proposal_details;
+use crate::requests::{
+    get_multisig_account, get_transaction, get_transaction_account, get_transaction_events,
+    get_users,
+};
+use crate::utils::{
+    get_disabled_link_preview, get_group_chat_id, get_multisig_pubkey, get_reminder_settings,
+    get_transaction_message_id, get_transaction_request_buttons, render_transaction_request,
+};
+use teloxide::{
+    prelude::*,
+    types::{InlineKeyboardButton, InlineKeyboardMarkup, ParseMode},
+    ApiError, RequestError,
+};
+
+/// Renders the group message of a proposal again with its current on-chain state
+pub async fn refresh_transaction_request(
+    bot: &Bot,
+    transaction_id: i64,
+    progress: &RequestProgress,
+) -> ResponseResult<()> {
+    let transaction_entry = get_transaction(transaction_id).await;
+
+    let Some(message_id) = get_transaction_message_id(&transaction_entry) else {
+        return Ok(());
+    };
+
+    let multisig_pubkey = get_multisig_pubkey();
+    let multisig_account = get_multisig_account(multisig_pubkey).await;
+    let transaction_account =
+        get_transaction_account(multisig_pubkey, transaction_entry.transaction_index).await;
+
+    let details =
+        get_proposal_details(multisig_pubkey, &transaction_entry, &transaction_account).await;
+
+    let text = render_transaction_request(
+        &transaction_entry,
+        multisig_account.threshold,
+        &transaction_account,
+        &get_users().await,
+        &get_transaction_events(transaction_entry.id).await,
+        &details,
+        progress,
+    );
+
+    let expiry_action = transaction_entry
+        .expired_at
+        .map(|_| get_reminder_settings().expiry_action);
+
+    let buttons = match progress {
+        // Telegram has no disabled buttons, this one only tells the members to wait
+        RequestProgress::InFlight(label) => vec![InlineKeyboardButton::callback(
+            format!("⏳ {}", label),
+            &ButtonMetadata {
+                transaction_id,
+                value: "Busy".to_string(),
+            },
+        )],
+        _ => get_transaction_request_buttons(
+            transaction_entry.id,
+            multisig_account.threshold,
+            &transaction_account,
+            expiry_action.as_ref(),
+            !details.policy.violations.is_empty(),
+            details.language,
+        ),
+    };
+
+    let result = bot
+        .edit_message_text(get_group_chat_id(), message_id, text)
+        .parse_mode(ParseMode::Html)
+        .link_preview_options(get_disabled_link_preview())
+        .reply_markup(InlineKeyboardMarkup::new([buttons]))
+        .await;
+
+    match result {
+        Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
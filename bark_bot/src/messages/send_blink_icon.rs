@@ -0,0 +1,93 @@
+use crate::requests::get_blink_icon;
+use crate::utils::get_icon_cache_path;
+use teloxide::{
+    prelude::*,
+    types::{InlineKeyboardMarkup, InputFile, MessageId, ParseMode, ReplyParameters},
+};
+use tokio::fs;
+
+/// Sends the icon of a Blink as a photo from the cache, None when it can't be used or
+/// Telegram refuses it.
+/// Telegram keeps the first upload, so later ones reuse its file id.
+pub async fn send_blink_icon(
+    bot: &Bot,
+    chat_id: ChatId,
+    icon_url: &str,
+    caption: Option<String>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    reply_to: Option<MessageId>,
+) -> ResponseResult<Option<Message>> {
+    let file_id_path = get_icon_cache_path(icon_url).with_extension("file_id");
+
+    if let Ok(file_id) = fs::read_to_string(&file_id_path).await {
+        let request = send_photo(
+            bot,
+            chat_id,
+            InputFile::file_id(file_id),
+            caption.clone(),
+            reply_markup.clone(),
+            reply_to,
+        );
+
+        match request.await {
+            Ok(message) => return Ok(Some(message)),
+            // Uploaded again below
+            Err(e) => {
+                log::warn!("Failed to reuse the icon {}: {}", icon_url, e);
+                let _ = fs::remove_file(&file_id_path).await;
+            }
+        }
+    }
+
+    let Some(icon) = get_blink_icon(icon_url).await else {
+        return Ok(None);
+    };
+
+    let request = send_photo(
+        bot,
+        chat_id,
+        InputFile::memory(icon),
+        caption,
+        reply_markup,
+        reply_to,
+    );
+
+    // Telegram refuses some images, like extreme aspect ratios, the caller sends text instead
+    let message = match request.await {
+        Ok(message) => message,
+        Err(e) => {
+            log::warn!("Failed to upload the icon {}: {}", icon_url, e);
+            return Ok(None);
+        }
+    };
+
+    if let Some(photo) = message.photo().and_then(|sizes| sizes.last()) {
+        if let Err(e) = fs::write(&file_id_path, &photo.file.id).await {
+            log::warn!("Failed to keep the file id of the icon {}: {}", icon_url, e);
+        }
+    }
+
+    Ok(Some(message))
+}
+
+async fn send_photo(
+    bot: &Bot,
+    chat_id: ChatId,
+    photo: InputFile,
+    caption: Option<String>,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    reply_to: Option<MessageId>,
+) -> ResponseResult<Message> {
+    let mut request = bot.send_photo(chat_id, photo);
+    if let Some(caption) = caption {
+        request = request.caption(caption).parse_mode(ParseMode::Html);
+    }
+    if let Some(reply_markup) = reply_markup {
+        request = request.reply_markup(reply_markup);
+    }
+    if let Some(message_id) = reply_to {
+        request = request.reply_parameters(ReplyParameters::new(message_id));
+    }
+
+    request.await
+}
@@ -0,0 +1,51 @@
+use crate::messages::send_blink_icon;
+use crate::utils::{get_disabled_link_preview, MAX_CAPTION_LENGTH};
+use teloxide::{
+    prelude::*,
+    types::{InlineKeyboardMarkup, MessageId, ParseMode, ReplyParameters},
+};
+
+/// Sends the preview of a Blink as its icon captioned with `text`. When the caption is
+/// too long for a photo the text follows the icon, or goes alone without one.
+pub async fn send_blink_preview(
+    bot: &Bot,
+    chat_id: ChatId,
+    icon_url: &str,
+    text: String,
+    reply_markup: Option<InlineKeyboardMarkup>,
+    reply_to: Option<MessageId>,
+) -> ResponseResult<Message> {
+    if text.chars().count() <= MAX_CAPTION_LENGTH {
+        if let Some(message) = send_blink_icon(
+            bot,
+            chat_id,
+            icon_url,
+            Some(text.clone()),
+            reply_markup.clone(),
+            reply_to,
+        )
+        .await?
+        {
+            return Ok(message);
+        }
+    }
+
+    let icon_message = if text.chars().count() > MAX_CAPTION_LENGTH {
+        send_blink_icon(bot, chat_id, icon_url, None, None, reply_to).await?
+    } else {
+        None
+    };
+
+    let mut request = bot
+        .send_message(chat_id, text)
+        .parse_mode(ParseMode::Html)
+        .link_preview_options(get_disabled_link_preview());
+    if let Some(reply_markup) = reply_markup {
+        request = request.reply_markup(reply_markup);
+    }
+    if let Some(message_id) = icon_message.map(|message| message.id).or(reply_to) {
+        request = request.reply_parameters(ReplyParameters::new(message_id));
+    }
+
+    request.await
+}
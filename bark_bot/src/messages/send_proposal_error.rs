@@ -0,0 +1,38 @@
+use crate::collections::{ProposalError, Template};
+use crate::utils::{format_policy_violations, render_template, translate};
+use serde_json::json;
+use teloxide::{prelude::*, types::ParseMode, utils::html};
+
+/// Tells why the proposal `title` wasn't created, refused by the treasury policy or
+/// failed on chain
+pub async fn send_proposal_error(
+    bot: &Bot,
+    chat_id: ChatId,
+    language: &str,
+    title: &str,
+    error: &ProposalError,
+) -> ResponseResult<Message> {
+    let (message, details) = match error {
+        ProposalError::Refused(violations) => (
+            translate(language, "policy-refused", &[("title", title.into())]),
+            format_policy_violations(language, violations),
+        ),
+        ProposalError::Failed(e) => (
+            translate(language, "proposal-not-sent", &[("title", title.into())]),
+            html::escape(&e.to_string().chars().take(200).collect::<String>()),
+        ),
+    };
+    let text = render_template(
+        Template::Error,
+        chat_id,
+        language,
+        &json!({
+            "message": message,
+            "details": details,
+        }),
+    );
+
+    bot.send_message(chat_id, text)
+        .parse_mode(ParseMode::Html)
+        .await
+}
@@ -0,0 +1,28 @@
+use crate::collections::{Template, Transaction};
+use crate::utils::render_template;
+use serde_json::json;
+use teloxide::{prelude::*, types::ParseMode};
+
+/// Tells the proposer their proposal is in the group, with a link to its message
+pub async fn send_proposal_receipt(
+    bot: &Bot,
+    chat_id: ChatId,
+    language: &str,
+    transaction_entry: &Transaction,
+    group_message: &Message,
+) -> ResponseResult<Message> {
+    let text = render_template(
+        Template::Receipt,
+        chat_id,
+        language,
+        &json!({
+            "index": transaction_entry.transaction_index,
+            "title": transaction_entry.title,
+            "url": group_message.url().map(|url| url.to_string()),
+        }),
+    );
+
+    bot.send_message(chat_id, text)
+        .parse_mode(ParseMode::Html)
+        .await
+}
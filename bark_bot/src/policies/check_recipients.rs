@@ -0,0 +1,44 @@
+use crate::collections::RecipientCheck;
+use crate::requests::{get_address_book, get_transfers, GetTransfersQuery};
+use crate::utils::{find_lookalike, get_group_chat_id};
+
+/// Labels the recipients of a proposal and flags the new and lookalike ones
+pub async fn check_recipients(transaction_id: i64) -> Vec<RecipientCheck> {
+    let transfers = get_transfers(&GetTransfersQuery {
+        transaction_id: Some(transaction_id),
+        ..Default::default()
+    })
+    .await;
+
+    if transfers.is_empty() {
+        return vec![];
+    }
+
+    let address_book = get_address_book(get_group_chat_id()).await;
+    let mut recipients: Vec<RecipientCheck> = vec![];
+
+    for transfer in transfers {
+        let label = address_book
+            .iter()
+            .find(|entry| entry.address == transfer.recipient)
+            .map(|entry| entry.label.clone());
+        let first_time = get_transfers(&GetTransfersQuery {
+            recipient: Some(transfer.recipient.clone()),
+            executed: Some(true),
+            ..Default::default()
+        })
+        .await
+        .iter()
+        .all(|record| record.transaction_id == transaction_id);
+        let lookalike_of = find_lookalike(&transfer.recipient, &address_book).cloned();
+
+        recipients.push(RecipientCheck {
+            transfer,
+            label,
+            first_time,
+            lookalike_of,
+        });
+    }
+
+    recipients
+}
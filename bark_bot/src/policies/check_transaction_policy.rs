@@ -0,0 +1,31 @@
+use crate::collections::PolicyCheck;
+use crate::policies::{evaluate_policy, find_unchecked_programs};
+use crate::requests::get_transaction_instructions;
+use solana_sdk::pubkey::Pubkey;
+use squads_mpl::state::{MsTransaction, MsTransactionStatus};
+
+/// Policy check of a proposal that can still be executed, with the votes it has so far
+pub async fn check_transaction_policy(
+    multisig_pubkey: Pubkey,
+    transaction_id: i64,
+    transaction_account: &MsTransaction,
+) -> PolicyCheck {
+    if transaction_account.status != MsTransactionStatus::Active
+        && transaction_account.status != MsTransactionStatus::ExecuteReady
+    {
+        return PolicyCheck::default();
+    }
+
+    let instructions =
+        get_transaction_instructions(multisig_pubkey, transaction_account.transaction_index).await;
+
+    PolicyCheck {
+        violations: evaluate_policy(
+            &instructions,
+            &transaction_account.approved,
+            Some(transaction_id),
+        )
+        .await,
+        unchecked_programs: find_unchecked_programs(&instructions),
+    }
+}
@@ -0,0 +1,99 @@
+use crate::collections::Transfer;
+use crate::requests::get_token_account_info;
+use crate::utils::{
+    ASSOCIATED_TOKEN_PROGRAM_ID, SOL_DECIMALS, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
+};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, system_program};
+
+const SYSTEM_TRANSFER: u32 = 2;
+const TOKEN_TRANSFER: u8 = 3;
+const TOKEN_TRANSFER_CHECKED: u8 = 12;
+
+/// SOL and SPL transfers made by a list of instructions. Recipients are wallets,
+/// token accounts are resolved to their owner when possible. Only direct calls to the
+/// System and Token programs are seen, see `find_unchecked_programs`.
+pub async fn decode_transfers(instructions: &[Instruction]) -> Vec<Transfer> {
+    let mut transfers: Vec<Transfer> = vec![];
+
+    for instruction in instructions {
+        let data = &instruction.data;
+
+        if instruction.program_id == system_program::ID {
+            // Instructions come from the Blink server, malformed ones are skipped
+            if let Some(recipient) = instruction.accounts.get(1).filter(|_| {
+                data.len() >= 12
+                    && u32::from_le_bytes(data[0..4].try_into().unwrap()) == SYSTEM_TRANSFER
+            }) {
+                let lamports = u64::from_le_bytes(data[4..12].try_into().unwrap());
+
+                transfers.push(Transfer {
+                    token: "SOL".to_string(),
+                    recipient: recipient.pubkey.to_string(),
+                    base_amount: lamports,
+                    decimals: SOL_DECIMALS,
+                });
+            }
+            continue;
+        }
+
+        if instruction.program_id != TOKEN_PROGRAM_ID
+            && instruction.program_id != TOKEN_2022_PROGRAM_ID
+        {
+            continue;
+        }
+
+        let account = |index: usize| instruction.accounts.get(index).map(|meta| meta.pubkey);
+        let (source, mint, destination, decimals) = match (data.first(), account(0)) {
+            (Some(&TOKEN_TRANSFER), Some(source)) if data.len() >= 9 => {
+                let Some(destination) = account(1) else {
+                    continue;
+                };
+
+                (source, None, destination, None)
+            }
+            (Some(&TOKEN_TRANSFER_CHECKED), Some(source)) if data.len() >= 10 => {
+                let (Some(mint), Some(destination)) = (account(1), account(2)) else {
+                    continue;
+                };
+
+                (source, Some(mint), destination, Some(data[9]))
+            }
+            _ => continue,
+        };
+        let amount = u64::from_le_bytes(data[1..9].try_into().unwrap());
+
+        let source_info = get_token_account_info(source).await;
+        let destination_info = get_token_account_info(destination).await;
+
+        let Some(mint) = mint.or(source_info.as_ref().map(|info| info.mint)) else {
+            continue;
+        };
+        let decimals = decimals
+            .or(source_info.map(|info| info.decimals))
+            .unwrap_or_default();
+
+        // The destination may be an associated account created earlier in the same proposal
+        let recipient = destination_info
+            .map(|info| info.owner)
+            .or_else(|| find_associated_account_owner(instructions, &destination))
+            .unwrap_or(destination);
+
+        transfers.push(Transfer {
+            token: mint.to_string(),
+            recipient: recipient.to_string(),
+            base_amount: amount,
+            decimals,
+        });
+    }
+
+    transfers
+}
+
+fn find_associated_account_owner(instructions: &[Instruction], account: &Pubkey) -> Option<Pubkey> {
+    instructions
+        .iter()
+        .filter(|instruction| instruction.program_id == ASSOCIATED_TOKEN_PROGRAM_ID)
+        .find(|instruction| instruction.accounts.get(1).map(|meta| &meta.pubkey) == Some(account))
+        .and_then(|instruction| instruction.accounts.get(2))
+        .map(|meta| meta.pubkey)
+}
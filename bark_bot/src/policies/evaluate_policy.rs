@@ -0,0 +1,180 @@
+use crate::collections::{PolicyViolation, Transfer};
+use crate::policies::decode_transfers;
+use crate::requests::{get_transfers, GetTransfersQuery};
+use crate::utils::{format_pubkey, get_policy, get_timestamp, parse_token_amount};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Checks the instructions of a proposal against the treasury policy. `transaction_id`
+/// is left out of the history so a proposal doesn't count against itself.
+pub async fn evaluate_policy(
+    instructions: &[Instruction],
+    approved: &[Pubkey],
+    transaction_id: Option<i64>,
+) -> Vec<PolicyViolation> {
+    let Some(policy) = get_policy() else {
+        return vec![];
+    };
+
+    let mut violations: Vec<PolicyViolation> = vec![];
+
+    for instruction in instructions {
+        if policy
+            .blocked_programs
+            .contains(&instruction.program_id.to_string())
+        {
+            violations.push(PolicyViolation::BlockedProgram(instruction.program_id));
+        }
+    }
+
+    let transfers = decode_transfers(instructions).await;
+    // Summed in base units, as u128 so that no number of u64 amounts can overflow
+    let total_of = |token: &str, transfers: &[&Transfer]| -> u128 {
+        transfers
+            .iter()
+            .filter(|transfer| transfer.token == token)
+            .map(|transfer| u128::from(transfer.base_amount))
+            .sum()
+    };
+    // Only asked for tokens the proposal moves, so one of its transfers has them
+    let decimals_of = |token: &str| -> u8 {
+        transfers
+            .iter()
+            .find(|transfer| transfer.token == token)
+            .map_or(0, |transfer| transfer.decimals)
+    };
+    let all_transfers: Vec<&Transfer> = transfers.iter().collect();
+
+    for rule in &policy.approvals {
+        let total = total_of(&rule.token, &all_transfers);
+
+        if total == 0 {
+            continue;
+        }
+
+        let decimals = decimals_of(&rule.token);
+        let above = to_base_units(rule.above, decimals);
+
+        if total <= u128::from(above) {
+            continue;
+        }
+
+        let missing: Vec<String> = rule
+            .approvers
+            .iter()
+            .filter(|approver| {
+                Pubkey::from_str(approver).map_or(true, |pubkey| !approved.contains(&pubkey))
+            })
+            .map(|approver| {
+                Pubkey::from_str(approver).map_or(approver.clone(), |pubkey| format_pubkey(&pubkey))
+            })
+            .collect();
+
+        if !missing.is_empty() {
+            violations.push(PolicyViolation::MissingApprovals {
+                token: rule.token.clone(),
+                above,
+                decimals,
+                approvers: rule.label.clone().unwrap_or(missing.join(", ")),
+            });
+        }
+    }
+
+    for limit in &policy.limits {
+        let history = get_transfers(&GetTransfersQuery {
+            token: Some(limit.token.clone()),
+            executed: Some(true),
+            ..Default::default()
+        })
+        .await;
+        let history: Vec<_> = history
+            .iter()
+            .filter(|record| Some(record.transaction_id) != transaction_id)
+            .collect();
+
+        // First time each recipient was paid, to tell which ones are new
+        let mut first_paid_at: HashMap<&str, i64> = HashMap::new();
+        for record in &history {
+            let executed_at = record.executed_at.unwrap_or(record.created_at);
+            let entry = first_paid_at
+                .entry(record.recipient.as_str())
+                .or_insert(executed_at);
+            *entry = (*entry).min(executed_at);
+        }
+
+        let proposed: Vec<&Transfer> = transfers
+            .iter()
+            .filter(|transfer| {
+                !limit.new_recipients_only
+                    || !first_paid_at.contains_key(transfer.recipient.as_str())
+            })
+            .collect();
+        let mut total = total_of(&limit.token, &proposed);
+
+        if total == 0 {
+            continue;
+        }
+
+        let decimals = decimals_of(&limit.token);
+        let max_amount = to_base_units(limit.max_amount, decimals);
+
+        if let Some(window) = limit.window {
+            let window_start = get_timestamp() - window as i64;
+
+            total += history
+                .iter()
+                .filter(|record| record.executed_at.unwrap_or_default() >= window_start)
+                .filter(|record| {
+                    !limit.new_recipients_only
+                        || first_paid_at[record.recipient.as_str()] >= window_start
+                })
+                .map(|record| u128::from(record.base_units(decimals)))
+                .sum::<u128>();
+        }
+
+        if total > u128::from(max_amount) {
+            violations.push(PolicyViolation::LimitExceeded {
+                token: limit.token.clone(),
+                total,
+                max_amount,
+                decimals,
+                window: limit.window,
+                new_recipients_only: limit.new_recipients_only,
+            });
+        }
+    }
+
+    violations
+}
+
+/// Policy amounts are in token units. Digits past the decimals of the token can't be
+/// sent and are dropped, amounts that don't fit count as 0 so that the rule applies.
+fn to_base_units(amount: f64, decimals: u8) -> u64 {
+    let amount = amount.to_string();
+    let (integer, fraction) = amount.split_once('.').unwrap_or((&amount, ""));
+    let fraction: String = fraction.chars().take(usize::from(decimals)).collect();
+
+    parse_token_amount(&format!("{}.{}", integer, fraction), decimals).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_policy_amounts_without_rounding_errors() {
+        assert_eq!(to_base_units(1000.0, 6), 1_000_000_000);
+        assert_eq!(to_base_units(0.1, 9), 100_000_000);
+        assert_eq!(to_base_units(0.3, 9), 300_000_000);
+        assert_eq!(to_base_units(1.239, 2), 123);
+        assert_eq!(to_base_units(5.0, 0), 5);
+    }
+
+    #[test]
+    fn counts_invalid_amounts_as_zero() {
+        assert_eq!(to_base_units(0.0, 6), 0);
+        assert_eq!(to_base_units(-1.0, 6), 0);
+        assert_eq!(to_base_units(1e30, 9), 0);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::utils::{
+    get_policy, ASSOCIATED_TOKEN_PROGRAM_ID, MEMO_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
+    TOKEN_PROGRAM_ID,
+};
+use solana_sdk::{compute_budget, instruction::Instruction, pubkey::Pubkey, system_program};
+
+/// Programs called by a proposal besides the ones `decode_transfers` understands. The
+/// funds they move, themselves or through other programs, escape the limits and approval
+/// rules, so proposals show them instead.
+pub fn find_unchecked_programs(instructions: &[Instruction]) -> Vec<Pubkey> {
+    let Some(policy) = get_policy() else {
+        return vec![];
+    };
+
+    if policy.limits.is_empty() && policy.approvals.is_empty() {
+        return vec![];
+    }
+
+    let mut programs: Vec<Pubkey> = vec![];
+
+    for instruction in instructions {
+        let program_id = instruction.program_id;
+        let understood = [
+            system_program::ID,
+            TOKEN_PROGRAM_ID,
+            TOKEN_2022_PROGRAM_ID,
+            ASSOCIATED_TOKEN_PROGRAM_ID,
+            MEMO_PROGRAM_ID,
+            compute_budget::ID,
+        ]
+        .contains(&program_id);
+
+        if !understood && !programs.contains(&program_id) {
+            programs.push(program_id);
+        }
+    }
+
+    programs
+}
@@ -0,0 +1,50 @@
+use crate::requests::{claim_lease, release_lease};
+use crate::utils::{IN_FLIGHT_LEASE_TTL, POLICY_LEASE, POLICY_LOCK_TIMEOUT};
+use std::time::Duration;
+use tokio::sync::{Mutex, MutexGuard};
+
+static POLICY_LOCK: Mutex<()> = Mutex::const_new(());
+
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Held while an execution is checked and recorded, in this process and through a lease
+/// for the other replicas
+pub struct PolicyGuard {
+    local: Option<MutexGuard<'static, ()>>,
+}
+
+/// Runs executions one at a time, the rolling window limits only hold if each one is
+/// checked after the previous one is recorded as executed. None when the lock couldn't
+/// be taken in time, because the API is unreachable or another replica keeps the lease.
+pub async fn lock_policy() -> Option<PolicyGuard> {
+    let lock = async {
+        let local = POLICY_LOCK.lock().await;
+
+        while !claim_lease(POLICY_LEASE, IN_FLIGHT_LEASE_TTL).await {
+            tokio::time::sleep(RETRY_DELAY).await;
+        }
+
+        PolicyGuard { local: Some(local) }
+    };
+
+    match tokio::time::timeout(POLICY_LOCK_TIMEOUT, lock).await {
+        Ok(guard) => Some(guard),
+        Err(_) => {
+            log::warn!("Timed out waiting for the policy lock");
+            None
+        }
+    }
+}
+
+impl Drop for PolicyGuard {
+    fn drop(&mut self) {
+        // The next execution of this process would renew the lease, only to lose it to
+        // the release, so it waits for the release to be done
+        let local = self.local.take();
+
+        tokio::spawn(async move {
+            release_lease(POLICY_LEASE).await;
+            drop(local);
+        });
+    }
+}
@@ -0,0 +1,13 @@
+pub mod check_recipients;
+pub mod check_transaction_policy;
+pub mod decode_transfers;
+pub mod evaluate_policy;
+pub mod find_unchecked_programs;
+pub mod lock_policy;
+
+pub use check_recipients::*;
+pub use check_transaction_policy::*;
+pub use decode_transfers::*;
+pub use evaluate_policy::*;
+pub use find_unchecked_programs::*;
+pub use lock_policy::*;
@@ -0,0 +1,28 @@
+use crate::utils::get_replica_id;
+use serde::{Deserialize, Serialize};
+use std::{env, time::Duration};
+
+#[derive(Serialize, Deserialize)]
+pub struct ClaimLeaseBody {
+    pub holder: String,
+    pub ttl: u64,
+}
+
+/// Takes or renews a lease shared by the replicas of the bot, false while another one
+/// holds it or when the API can't be reached
+pub async fn claim_lease(name: &str, ttl: Duration) -> bool {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let body = ClaimLeaseBody {
+        holder: get_replica_id().to_string(),
+        ttl: ttl.as_secs(),
+    };
+    let client = reqwest::Client::new();
+    let path = format!("{}/leases/{}", base_url, name);
+
+    client
+        .put(path)
+        .json(&body)
+        .send()
+        .await
+        .is_ok_and(|response| response.status().is_success())
+}
@@ -0,0 +1,48 @@
+use crate::collections::{Schedule, ScheduledAction};
+use serde::{Deserialize, Serialize};
+use std::env;
+use teloxide::types::{ChatId, UserId};
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateScheduleBody {
+    pub chat_id: String,
+    pub user_id: String,
+    pub cadence: String,
+    pub action: String,
+    pub title: String,
+    pub next_run_at: i64,
+    pub ends_at: Option<i64>,
+}
+
+pub async fn create_schedule(
+    chat_id: ChatId,
+    user_id: UserId,
+    cadence: &str,
+    action: &ScheduledAction,
+    title: &str,
+    next_run_at: i64,
+    ends_at: Option<i64>,
+) -> Schedule {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let body = CreateScheduleBody {
+        chat_id: chat_id.to_string(),
+        user_id: user_id.to_string(),
+        cadence: cadence.to_string(),
+        action: serde_json::to_string(action).unwrap(),
+        title: title.to_string(),
+        next_run_at,
+        ends_at,
+    };
+    let client = reqwest::Client::new();
+    let path = format!("{}/schedules", base_url);
+
+    client
+        .post(path)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json::<Schedule>()
+        .await
+        .unwrap()
+}
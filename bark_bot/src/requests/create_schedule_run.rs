@@ -0,0 +1,34 @@
+use crate::collections::Schedule;
+use serde::{Deserialize, Serialize};
+use std::env;
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateScheduleRunBody {
+    pub transaction_id: Option<i64>,
+    pub next_run_at: Option<i64>,
+}
+
+/// Records a run of the schedule, `next_run_at` None ends it
+pub async fn create_schedule_run(
+    id: i64,
+    transaction_id: Option<i64>,
+    next_run_at: Option<i64>,
+) -> Schedule {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let body = CreateScheduleRunBody {
+        transaction_id,
+        next_run_at,
+    };
+    let client = reqwest::Client::new();
+    let path = format!("{}/schedules/{}/runs", base_url, id);
+
+    client
+        .post(path)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json::<Schedule>()
+        .await
+        .unwrap()
+}
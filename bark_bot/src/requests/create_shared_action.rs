@@ -0,0 +1,40 @@
+use crate::collections::SharedAction;
+use serde::{Deserialize, Serialize};
+use std::env;
+use teloxide::types::UserId;
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateSharedActionBody {
+    pub url: String,
+    pub action: String,
+    pub parameters: String,
+    pub user_id: String,
+}
+
+/// Returns the existing row when the same action and presets were shared before
+pub async fn create_shared_action(
+    url: &str,
+    action: &str,
+    parameters: &[(String, String)],
+    user_id: UserId,
+) -> SharedAction {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let body = CreateSharedActionBody {
+        url: url.to_string(),
+        action: action.to_string(),
+        parameters: serde_json::to_string(parameters).unwrap(),
+        user_id: user_id.to_string(),
+    };
+    let client = reqwest::Client::new();
+    let path = format!("{}/shared_actions", base_url);
+
+    client
+        .post(path)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json::<SharedAction>()
+        .await
+        .unwrap()
+}
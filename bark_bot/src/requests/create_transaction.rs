@@ -8,18 +8,34 @@ pub struct CreateTransactionBody {
     pub transaction_index: i32,
     pub user_id: String,
     pub signature: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub parameters: Option<String>,
+    pub batch_id: Option<String>,
 }
 
 pub async fn create_transaction(
     transaction_index: i32,
     user_id: UserId,
     signature: String,
+    title: String,
+    description: String,
+    parameters: &[(String, String)],
+    batch_id: Option<&str>,
 ) -> Transaction {
     let base_url = env::var("API_BASE_URL").unwrap();
     let body = CreateTransactionBody {
         transaction_index,
         user_id: user_id.to_string(),
         signature,
+        title: Some(title),
+        description: Some(description),
+        parameters: if parameters.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(parameters).unwrap())
+        },
+        batch_id: batch_id.map(str::to_string),
     };
     let client = reqwest::Client::new();
     let path = format!("{}/transactions", base_url);
@@ -0,0 +1,37 @@
+use crate::collections::TransactionEvent;
+use serde::{Deserialize, Serialize};
+use std::env;
+use teloxide::types::UserId;
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateTransactionEventBody {
+    pub kind: String,
+    pub user_id: Option<String>,
+    pub signature: Option<String>,
+}
+
+pub async fn create_transaction_event(
+    id: i64,
+    kind: &str,
+    user_id: UserId,
+    signature: String,
+) -> TransactionEvent {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let body = CreateTransactionEventBody {
+        kind: kind.to_string(),
+        user_id: Some(user_id.to_string()),
+        signature: Some(signature),
+    };
+    let client = reqwest::Client::new();
+    let path = format!("{}/transactions/{}/events", base_url, id);
+
+    client
+        .post(path)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json::<TransactionEvent>()
+        .await
+        .unwrap()
+}
@@ -0,0 +1,17 @@
+use crate::collections::Transaction;
+use std::env;
+
+pub async fn create_transaction_reminder(id: i64) -> Transaction {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/transactions/{}/reminders", base_url, id);
+
+    client
+        .post(path)
+        .send()
+        .await
+        .unwrap()
+        .json::<Transaction>()
+        .await
+        .unwrap()
+}
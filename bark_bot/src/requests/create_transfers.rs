@@ -0,0 +1,18 @@
+use crate::collections::{Transfer, TransferRecord};
+use std::env;
+
+pub async fn create_transfers(id: i64, transfers: &[Transfer]) -> Vec<TransferRecord> {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/transactions/{}/transfers", base_url, id);
+
+    client
+        .post(path)
+        .json(transfers)
+        .send()
+        .await
+        .unwrap()
+        .json::<Vec<TransferRecord>>()
+        .await
+        .unwrap()
+}
@@ -0,0 +1,16 @@
+use std::env;
+
+/// Returns false if the schedule didn't exist
+pub async fn delete_schedule(id: i64) -> bool {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/schedules/{}", base_url, id);
+
+    client
+        .delete(path)
+        .send()
+        .await
+        .unwrap()
+        .status()
+        .is_success()
+}
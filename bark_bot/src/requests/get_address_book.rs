@@ -0,0 +1,18 @@
+use crate::collections::AddressBookEntry;
+use std::env;
+use teloxide::types::ChatId;
+
+pub async fn get_address_book(chat_id: ChatId) -> Vec<AddressBookEntry> {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/groups/{}/addresses", base_url, chat_id);
+
+    client
+        .get(path)
+        .send()
+        .await
+        .unwrap()
+        .json::<Vec<AddressBookEntry>>()
+        .await
+        .unwrap()
+}
@@ -0,0 +1,47 @@
+use crate::requests::get_public_url;
+use crate::utils::{
+    convert_icon, get_icon_cache_dir, get_icon_cache_path, prune_icon_cache, MAX_ICON_SIZE,
+};
+use std::path::Path;
+use tokio::fs;
+
+/// Icon of a Blink ready to be sent as a photo, None when it can't be fetched safely or
+/// isn't a PNG, JPEG, WebP or SVG image
+pub async fn get_blink_icon(url: &str) -> Option<Vec<u8>> {
+    let cache_dir = get_icon_cache_dir();
+    let path = get_icon_cache_path(url);
+
+    if let Ok(icon) = fs::read(&path).await {
+        return Some(icon);
+    }
+
+    match fetch_icon(url).await {
+        Ok(icon) => {
+            if let Err(e) = cache_icon(&cache_dir, &path, &icon).await {
+                log::warn!("Failed to cache the icon {}: {}", url, e);
+            }
+
+            Some(icon)
+        }
+        Err(e) => {
+            log::debug!("Icon {} not sent as a photo: {}", url, e);
+            None
+        }
+    }
+}
+
+async fn cache_icon(cache_dir: &Path, path: &Path, icon: &[u8]) -> std::io::Result<()> {
+    fs::create_dir_all(cache_dir).await?;
+    fs::write(path, icon).await?;
+
+    prune_icon_cache(cache_dir).await
+}
+
+async fn fetch_icon(url: &str) -> Result<Vec<u8>, String> {
+    let icon = get_public_url(url, MAX_ICON_SIZE).await?;
+
+    // Telegram only reliably takes PNG and JPEG as photos, decoding is kept off the runtime
+    tokio::task::spawn_blocking(move || convert_icon(icon))
+        .await
+        .map_err(|e| e.to_string())?
+}
@@ -0,0 +1,18 @@
+use crate::collections::ChatLanguage;
+use std::env;
+use teloxide::types::ChatId;
+
+pub async fn get_chat_language(chat_id: ChatId) -> Option<ChatLanguage> {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/groups/{}/language", base_url, chat_id);
+
+    client
+        .get(path)
+        .send()
+        .await
+        .unwrap()
+        .json::<Option<ChatLanguage>>()
+        .await
+        .unwrap()
+}
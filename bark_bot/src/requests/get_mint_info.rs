@@ -0,0 +1,92 @@
+use crate::collections::MintInfo;
+use crate::utils::{RPC, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+
+const MINT_LEN: usize = 82;
+const MINT_DECIMALS_OFFSET: usize = 44;
+const MINT_INITIALIZED_OFFSET: usize = 45;
+// Token-2022 accounts with extensions are padded to the size of a token account and
+// followed by a byte telling mints and token accounts apart
+const ACCOUNT_LEN: usize = 165;
+const ACCOUNT_TYPE_MINT: u8 = 1;
+
+/// Token program and decimals of a mint, or None if the account is not a mint
+pub async fn get_mint_info(mint: Pubkey) -> Option<MintInfo> {
+    let solana_client = RpcClient::new(RPC.to_string());
+    let account = solana_client.get_account(&mint).await.ok()?;
+
+    parse_mint(account.owner, &account.data)
+}
+
+fn parse_mint(owner: Pubkey, data: &[u8]) -> Option<MintInfo> {
+    let is_mint = match data.len() {
+        MINT_LEN => owner == TOKEN_PROGRAM_ID || owner == TOKEN_2022_PROGRAM_ID,
+        len if len > ACCOUNT_LEN => {
+            owner == TOKEN_2022_PROGRAM_ID && data[ACCOUNT_LEN] == ACCOUNT_TYPE_MINT
+        }
+        _ => false,
+    };
+
+    if !is_mint || data[MINT_INITIALIZED_OFFSET] != 1 {
+        return None;
+    }
+
+    Some(MintInfo {
+        token_program: owner,
+        decimals: data[MINT_DECIMALS_OFFSET],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mint_data(len: usize, decimals: u8) -> Vec<u8> {
+        let mut data = vec![0; len];
+        data[MINT_DECIMALS_OFFSET] = decimals;
+        data[MINT_INITIALIZED_OFFSET] = 1;
+        data
+    }
+
+    #[test]
+    fn reads_the_decimals_of_a_mint() {
+        let mint = parse_mint(TOKEN_PROGRAM_ID, &mint_data(MINT_LEN, 6)).unwrap();
+
+        assert_eq!(mint.decimals, 6);
+        assert_eq!(mint.token_program, TOKEN_PROGRAM_ID);
+    }
+
+    #[test]
+    fn reads_token_2022_mints_with_extensions() {
+        let mut data = mint_data(ACCOUNT_LEN + 20, 9);
+        data[ACCOUNT_LEN] = ACCOUNT_TYPE_MINT;
+
+        assert_eq!(
+            parse_mint(TOKEN_2022_PROGRAM_ID, &data).unwrap().decimals,
+            9
+        );
+        assert!(parse_mint(TOKEN_PROGRAM_ID, &data).is_none());
+    }
+
+    #[test]
+    fn refuses_token_accounts() {
+        // Byte 44 of a token account is part of its owner
+        let mut data = vec![7; ACCOUNT_LEN];
+        data[MINT_INITIALIZED_OFFSET] = 1;
+        assert!(parse_mint(TOKEN_PROGRAM_ID, &data).is_none());
+
+        let mut data = vec![7; ACCOUNT_LEN + 20];
+        data[ACCOUNT_LEN] = 2;
+        assert!(parse_mint(TOKEN_2022_PROGRAM_ID, &data).is_none());
+    }
+
+    #[test]
+    fn refuses_uninitialized_mints_and_other_programs() {
+        let mut data = mint_data(MINT_LEN, 6);
+        assert!(parse_mint(Pubkey::new_unique(), &data).is_none());
+
+        data[MINT_INITIALIZED_OFFSET] = 0;
+        assert!(parse_mint(TOKEN_PROGRAM_ID, &data).is_none());
+    }
+}
@@ -0,0 +1,17 @@
+use crate::collections::Transaction;
+use std::env;
+
+pub async fn get_open_transactions() -> Vec<Transaction> {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/transactions/open", base_url);
+
+    client
+        .get(path)
+        .send()
+        .await
+        .unwrap()
+        .json::<Vec<Transaction>>()
+        .await
+        .unwrap()
+}
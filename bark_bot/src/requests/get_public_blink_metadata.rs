@@ -0,0 +1,11 @@
+use crate::collections::BlinkMetadata;
+use crate::requests::get_public_url;
+use crate::utils::MAX_METADATA_SIZE;
+
+/// Metadata of a Blink shared by anyone in the group, fetched through `get_public_url`
+/// so links can't make the bot reach internal hosts
+pub async fn get_public_blink_metadata(url: &str) -> Result<BlinkMetadata, String> {
+    let body = get_public_url(url, MAX_METADATA_SIZE).await?;
+
+    serde_json::from_slice(&body).map_err(|e| e.to_string())
+}
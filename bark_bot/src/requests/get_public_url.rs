@@ -0,0 +1,88 @@
+use crate::utils::is_public_address;
+use reqwest::{header::LOCATION, redirect::Policy, Client, Response};
+use std::{net::SocketAddr, time::Duration};
+use tokio::net::lookup_host;
+use url::Url;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_REDIRECTS: usize = 3;
+
+/// Body of an HTTPS URL fetched defensively, for URLs anyone can make the bot load: only
+/// public addresses, every redirect checked, a timeout and at most `max_size` bytes
+pub async fn get_public_url(url: &str, max_size: usize) -> Result<Vec<u8>, String> {
+    let mut url = Url::parse(url).map_err(|e| e.to_string())?;
+
+    // Redirects are followed by hand so each hop is checked like the first one
+    for _ in 0..=MAX_REDIRECTS {
+        let mut response = send_request(&url).await?;
+
+        if response.status().is_redirection() {
+            let location = response
+                .headers()
+                .get(LOCATION)
+                .and_then(|location| location.to_str().ok())
+                .ok_or("Redirect without a location")?;
+            url = url.join(location).map_err(|e| e.to_string())?;
+            continue;
+        }
+
+        if !response.status().is_success() {
+            return Err(format!("Responded with {}", response.status()));
+        }
+
+        if response
+            .content_length()
+            .is_some_and(|length| length > max_size as u64)
+        {
+            return Err("Too large".to_string());
+        }
+
+        let mut body = vec![];
+        while let Some(chunk) = response.chunk().await.map_err(|e| e.to_string())? {
+            body.extend_from_slice(&chunk);
+
+            if body.len() > max_size {
+                return Err("Too large".to_string());
+            }
+        }
+
+        return Ok(body);
+    }
+
+    Err("Too many redirects".to_string())
+}
+
+async fn send_request(url: &Url) -> Result<Response, String> {
+    if url.scheme() != "https" {
+        return Err("Not an HTTPS URL".to_string());
+    }
+
+    let host = url.host_str().ok_or("No host")?;
+    let port = url.port_or_known_default().unwrap_or(443);
+    let addresses: Vec<SocketAddr> = lookup_host((host, port))
+        .await
+        .map_err(|e| e.to_string())?
+        .collect();
+
+    if addresses.is_empty()
+        || !addresses
+            .iter()
+            .all(|address| is_public_address(address.ip()))
+    {
+        return Err(format!("{} doesn't resolve to a public address", host));
+    }
+
+    // Pinned to the checked address so a second lookup can't point somewhere else
+    let client = Client::builder()
+        .redirect(Policy::none())
+        .timeout(TIMEOUT)
+        .resolve(host, addresses[0])
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    client
+        .get(url.clone())
+        .send()
+        .await
+        .map_err(|e| e.to_string())
+}
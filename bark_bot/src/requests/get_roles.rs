@@ -0,0 +1,22 @@
+use crate::collections::RoleGrant;
+use std::env;
+use teloxide::types::{ChatId, UserId};
+
+pub async fn get_roles(chat_id: ChatId, user_id: Option<UserId>) -> Vec<RoleGrant> {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/groups/{}/roles", base_url, chat_id);
+    let mut request = client.get(path);
+
+    if let Some(user_id) = user_id {
+        request = request.query(&[("user_id", user_id.to_string())]);
+    }
+
+    request
+        .send()
+        .await
+        .unwrap()
+        .json::<Vec<RoleGrant>>()
+        .await
+        .unwrap()
+}
@@ -0,0 +1,18 @@
+use crate::collections::Schedule;
+use reqwest::StatusCode;
+use std::env;
+
+/// Returns None if there is no schedule with this id
+pub async fn get_schedule(id: i64) -> Option<Schedule> {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/schedules/{}", base_url, id);
+
+    let response = client.get(path).send().await.unwrap();
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return None;
+    }
+
+    Some(response.json::<Schedule>().await.unwrap())
+}
@@ -0,0 +1,25 @@
+use crate::collections::Schedule;
+use serde::{Deserialize, Serialize};
+use std::env;
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct GetSchedulesQuery {
+    pub chat_id: Option<String>,
+    pub due_before: Option<i64>,
+}
+
+pub async fn get_schedules(query: &GetSchedulesQuery) -> Vec<Schedule> {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/schedules", base_url);
+
+    client
+        .get(path)
+        .query(query)
+        .send()
+        .await
+        .unwrap()
+        .json::<Vec<Schedule>>()
+        .await
+        .unwrap()
+}
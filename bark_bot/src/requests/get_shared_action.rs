@@ -0,0 +1,18 @@
+use crate::collections::SharedAction;
+use reqwest::StatusCode;
+use std::env;
+
+/// Returns None if the deep link points to an unknown action
+pub async fn get_shared_action(id: i64) -> Option<SharedAction> {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/shared_actions/{}", base_url, id);
+
+    let response = client.get(path).send().await.unwrap();
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return None;
+    }
+
+    Some(response.json::<SharedAction>().await.unwrap())
+}
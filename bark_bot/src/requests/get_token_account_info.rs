@@ -0,0 +1,27 @@
+use crate::collections::TokenAccountInfo;
+use crate::utils::RPC;
+use serde_json::{json, Value};
+use solana_client::{nonblocking::rpc_client::RpcClient, rpc_request::RpcRequest};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+/// Mint, owner and decimals of a token account, or None if it doesn't exist yet
+pub async fn get_token_account_info(pubkey: Pubkey) -> Option<TokenAccountInfo> {
+    let solana_client = RpcClient::new(RPC.to_string());
+
+    let token_account: Value = solana_client
+        .send(
+            RpcRequest::GetAccountInfo,
+            json!([pubkey.to_string(), { "encoding": "jsonParsed" }]),
+        )
+        .await
+        .ok()?;
+
+    let info = &token_account["value"]["data"]["parsed"]["info"];
+
+    Some(TokenAccountInfo {
+        mint: Pubkey::from_str(info["mint"].as_str()?).ok()?,
+        owner: Pubkey::from_str(info["owner"].as_str()?).ok()?,
+        decimals: info["tokenAmount"]["decimals"].as_u64()? as u8,
+    })
+}
@@ -0,0 +1,42 @@
+use crate::collections::TokenHolding;
+use crate::utils::{RPC, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID};
+use solana_client::{nonblocking::rpc_client::RpcClient, rpc_request::TokenAccountsFilter};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+/// Non-empty SPL and Token-2022 balances of the given owner
+pub async fn get_token_holdings(owner: Pubkey) -> Vec<TokenHolding> {
+    let solana_client = RpcClient::new(RPC.to_string());
+    let mut holdings: Vec<TokenHolding> = vec![];
+
+    for program_id in [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID] {
+        let token_accounts = solana_client
+            .get_token_accounts_by_owner(&owner, TokenAccountsFilter::ProgramId(program_id))
+            .await
+            .unwrap();
+
+        for token_account in token_accounts {
+            // The accounts come back json parsed, the decoder types aren't needed to read them
+            let data = serde_json::to_value(&token_account.account.data).unwrap();
+            let info = &data["parsed"]["info"];
+            let token_amount = &info["tokenAmount"];
+
+            let ui_amount = token_amount["uiAmount"].as_f64().unwrap_or_default();
+
+            if ui_amount == 0.0 {
+                continue;
+            }
+
+            holdings.push(TokenHolding {
+                mint: Pubkey::from_str(info["mint"].as_str().unwrap()).unwrap(),
+                amount: token_amount["uiAmountString"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+                ui_amount,
+            });
+        }
+    }
+
+    holdings
+}
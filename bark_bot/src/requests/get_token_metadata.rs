@@ -0,0 +1,58 @@
+use crate::collections::TokenMetadata;
+use crate::utils::{RPC, TOKEN_METADATA_PROGRAM_ID};
+use borsh::BorshDeserialize;
+use serde_json::{json, Value};
+use solana_client::{nonblocking::rpc_client::RpcClient, rpc_request::RpcRequest};
+use solana_sdk::pubkey::Pubkey;
+
+/// Name and symbol of a mint, read from its Token-2022 metadata extension or
+/// from the Metaplex metadata account, whichever exists
+pub async fn get_token_metadata(mint: Pubkey) -> Option<TokenMetadata> {
+    let solana_client = RpcClient::new(RPC.to_string());
+
+    let mint_account: Value = solana_client
+        .send(
+            RpcRequest::GetAccountInfo,
+            json!([mint.to_string(), { "encoding": "jsonParsed" }]),
+        )
+        .await
+        .ok()?;
+
+    let extensions = mint_account["value"]["data"]["parsed"]["info"]["extensions"].as_array();
+
+    if let Some(state) = extensions
+        .into_iter()
+        .flatten()
+        .find(|extension| extension["extension"] == "tokenMetadata")
+        .map(|extension| &extension["state"])
+    {
+        return Some(TokenMetadata {
+            name: state["name"].as_str().unwrap_or_default().to_string(),
+            symbol: state["symbol"].as_str().unwrap_or_default().to_string(),
+        });
+    }
+
+    let (metadata_pubkey, _) = Pubkey::find_program_address(
+        &[
+            b"metadata",
+            &TOKEN_METADATA_PROGRAM_ID.to_bytes(),
+            &mint.to_bytes(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    );
+
+    let metadata_data = solana_client
+        .get_account_data(&metadata_pubkey)
+        .await
+        .ok()?;
+
+    // Only the leading fields are needed: key, update authority, mint, name and symbol
+    let (_, _, _, name, symbol) =
+        <(u8, [u8; 32], [u8; 32], String, String)>::deserialize(&mut &metadata_data[..]).ok()?;
+
+    // Metaplex pads the strings with null bytes up to their maximum length
+    Some(TokenMetadata {
+        name: name.trim_end_matches('\0').to_string(),
+        symbol: symbol.trim_end_matches('\0').to_string(),
+    })
+}
@@ -0,0 +1,25 @@
+use crate::collections::PriceSource;
+use std::collections::HashMap;
+
+/// USD prices keyed by mint address, SOL is priced under the wrapped SOL mint
+pub async fn get_token_prices(price_source: &PriceSource) -> HashMap<String, f64> {
+    match price_source {
+        PriceSource::File(path) => {
+            let contents = std::fs::read_to_string(path).unwrap();
+
+            serde_json::from_str(&contents).unwrap()
+        }
+        PriceSource::Http(url) => {
+            let client = reqwest::Client::new();
+
+            client
+                .get(url)
+                .send()
+                .await
+                .unwrap()
+                .json::<HashMap<String, f64>>()
+                .await
+                .unwrap()
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::collections::Transaction;
+use crate::requests::{get_transactions, GetTransactionsQuery};
+
+/// Returns None if the bot never proposed a transaction with this index
+pub async fn get_transaction_by_index(transaction_index: u32) -> Option<Transaction> {
+    get_transactions(&GetTransactionsQuery {
+        transaction_index: Some(transaction_index),
+        limit: Some(1),
+        ..Default::default()
+    })
+    .await
+    .transactions
+    .into_iter()
+    .next()
+}
@@ -0,0 +1,17 @@
+use crate::collections::TransactionEvent;
+use std::env;
+
+pub async fn get_transaction_events(id: i64) -> Vec<TransactionEvent> {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/transactions/{}/events", base_url, id);
+
+    client
+        .get(path)
+        .send()
+        .await
+        .unwrap()
+        .json::<Vec<TransactionEvent>>()
+        .await
+        .unwrap()
+}
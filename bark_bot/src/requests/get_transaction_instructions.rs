@@ -0,0 +1,35 @@
+use crate::utils::{get_instruction_pubkey, get_transaction_pubkey, RPC};
+use anchor_lang::AccountDeserialize;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+use squads_mpl::state::{MsInstruction, MsTransaction};
+
+/// Instructions stored on-chain for a proposal, in the order they will be executed
+pub async fn get_transaction_instructions(
+    multisig_pubkey: Pubkey,
+    transaction_index: u32,
+) -> Vec<Instruction> {
+    let solana_client = RpcClient::new(RPC.to_string());
+    let transaction_pubkey = get_transaction_pubkey(multisig_pubkey, transaction_index);
+
+    let mut transaction_data = &solana_client
+        .get_account_data(&transaction_pubkey)
+        .await
+        .unwrap()[..];
+    let transaction = MsTransaction::try_deserialize(&mut transaction_data).unwrap();
+
+    let mut instructions: Vec<Instruction> = vec![];
+
+    for i in 0..transaction.instruction_index {
+        let instruction_pubkey = get_instruction_pubkey(transaction_pubkey, i + 1);
+        let mut instruction_data = &solana_client
+            .get_account_data(&instruction_pubkey)
+            .await
+            .unwrap()[..];
+        let instruction = MsInstruction::try_deserialize(&mut instruction_data).unwrap();
+
+        instructions.push(instruction.into());
+    }
+
+    instructions
+}
@@ -0,0 +1,31 @@
+use crate::collections::TransactionPage;
+use serde::{Deserialize, Serialize};
+use std::env;
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct GetTransactionsQuery {
+    pub state: Option<String>,
+    pub user_id: Option<String>,
+    pub batch_id: Option<String>,
+    pub transaction_index: Option<u32>,
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+pub async fn get_transactions(query: &GetTransactionsQuery) -> TransactionPage {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/transactions", base_url);
+
+    client
+        .get(path)
+        .query(query)
+        .send()
+        .await
+        .unwrap()
+        .json::<TransactionPage>()
+        .await
+        .unwrap()
+}
@@ -0,0 +1,28 @@
+use crate::collections::TransferRecord;
+use serde::{Deserialize, Serialize};
+use std::env;
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct GetTransfersQuery {
+    pub transaction_id: Option<i64>,
+    pub token: Option<String>,
+    pub recipient: Option<String>,
+    pub since: Option<i64>,
+    pub executed: Option<bool>,
+}
+
+pub async fn get_transfers(query: &GetTransfersQuery) -> Vec<TransferRecord> {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/transfers", base_url);
+
+    client
+        .get(path)
+        .query(query)
+        .send()
+        .await
+        .unwrap()
+        .json::<Vec<TransferRecord>>()
+        .await
+        .unwrap()
+}
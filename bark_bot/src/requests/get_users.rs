@@ -0,0 +1,17 @@
+use crate::collections::UserProfile;
+use std::env;
+
+pub async fn get_users() -> Vec<UserProfile> {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/users", base_url);
+
+    client
+        .get(path)
+        .send()
+        .await
+        .unwrap()
+        .json::<Vec<UserProfile>>()
+        .await
+        .unwrap()
+}
@@ -0,0 +1,18 @@
+use crate::collections::WalletLink;
+use std::env;
+use teloxide::types::UserId;
+
+pub async fn get_wallet_link(user_id: UserId) -> Option<WalletLink> {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/users/{}/wallet", base_url, user_id);
+
+    client
+        .get(path)
+        .send()
+        .await
+        .unwrap()
+        .json::<Option<WalletLink>>()
+        .await
+        .unwrap()
+}
@@ -0,0 +1,39 @@
+use crate::collections::{Role, RoleGrant};
+use serde::{Deserialize, Serialize};
+use std::env;
+use teloxide::types::{ChatId, UserId};
+
+#[derive(Serialize, Deserialize)]
+pub struct GrantRoleBody {
+    pub granted_by: Option<String>,
+}
+
+pub async fn grant_role(
+    chat_id: ChatId,
+    user_id: UserId,
+    role: Role,
+    granted_by: UserId,
+) -> RoleGrant {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let body = GrantRoleBody {
+        granted_by: Some(granted_by.to_string()),
+    };
+    let client = reqwest::Client::new();
+    let path = format!(
+        "{}/groups/{}/roles/{}/{}",
+        base_url,
+        chat_id,
+        user_id,
+        role.as_str()
+    );
+
+    client
+        .put(path)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json::<RoleGrant>()
+        .await
+        .unwrap()
+}
@@ -0,0 +1,27 @@
+use crate::collections::WalletLink;
+use serde::{Deserialize, Serialize};
+use std::env;
+use teloxide::types::UserId;
+
+#[derive(Serialize, Deserialize)]
+pub struct LinkWalletBody {
+    pub pubkey: String,
+    pub signature: String,
+}
+
+pub async fn link_wallet(user_id: UserId, pubkey: String, signature: String) -> WalletLink {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let body = LinkWalletBody { pubkey, signature };
+    let client = reqwest::Client::new();
+    let path = format!("{}/users/{}/wallet", base_url, user_id);
+
+    client
+        .put(path)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json::<WalletLink>()
+        .await
+        .unwrap()
+}
@@ -1,19 +1,105 @@
+pub mod claim_lease;
+pub mod create_schedule;
+pub mod create_schedule_run;
+pub mod create_shared_action;
 pub mod create_transaction;
+pub mod create_transaction_event;
+pub mod create_transaction_reminder;
+pub mod create_transfers;
+pub mod delete_schedule;
+pub mod expire_transaction;
+pub mod get_address_book;
+pub mod get_blink_icon;
 pub mod get_blink_metadata;
 pub mod get_blink_transaction;
+pub mod get_chat_language;
+pub mod get_mint_info;
 pub mod get_multisig_account;
+pub mod get_open_transactions;
+pub mod get_public_blink_metadata;
+pub mod get_public_url;
+pub mod get_roles;
+pub mod get_schedule;
+pub mod get_schedules;
+pub mod get_shared_action;
+pub mod get_token_account_info;
+pub mod get_token_holdings;
+pub mod get_token_metadata;
+pub mod get_token_prices;
 pub mod get_transaction;
 pub mod get_transaction_account;
 pub mod get_transaction_accounts;
+pub mod get_transaction_by_index;
+pub mod get_transaction_events;
+pub mod get_transaction_instructions;
+pub mod get_transactions;
+pub mod get_transfers;
+pub mod get_users;
+pub mod get_wallet_link;
+pub mod grant_role;
+pub mod link_wallet;
+pub mod release_lease;
+pub mod remove_address;
+pub mod reset_chat_language;
+pub mod revoke_role;
+pub mod save_address;
 pub mod send_and_confirm_transaction;
+pub mod set_chat_language;
+pub mod update_schedule;
 pub mod update_transaction;
+pub mod update_transaction_icon;
+pub mod update_transaction_state;
+pub mod upsert_user;
 
+pub use claim_lease::*;
+pub use create_schedule::*;
+pub use create_schedule_run::*;
+pub use create_shared_action::*;
 pub use create_transaction::*;
+pub use create_transaction_event::*;
+pub use create_transaction_reminder::*;
+pub use create_transfers::*;
+pub use delete_schedule::*;
+pub use expire_transaction::*;
+pub use get_address_book::*;
+pub use get_blink_icon::*;
 pub use get_blink_metadata::*;
 pub use get_blink_transaction::*;
+pub use get_chat_language::*;
+pub use get_mint_info::*;
 pub use get_multisig_account::*;
+pub use get_open_transactions::*;
+pub use get_public_blink_metadata::*;
+pub use get_public_url::*;
+pub use get_roles::*;
+pub use get_schedule::*;
+pub use get_schedules::*;
+pub use get_shared_action::*;
+pub use get_token_account_info::*;
+pub use get_token_holdings::*;
+pub use get_token_metadata::*;
+pub use get_token_prices::*;
 pub use get_transaction::*;
 pub use get_transaction_account::*;
 pub use get_transaction_accounts::*;
+pub use get_transaction_by_index::*;
+pub use get_transaction_events::*;
+pub use get_transaction_instructions::*;
+pub use get_transactions::*;
+pub use get_transfers::*;
+pub use get_users::*;
+pub use get_wallet_link::*;
+pub use grant_role::*;
+pub use link_wallet::*;
+pub use release_lease::*;
+pub use remove_address::*;
+pub use reset_chat_language::*;
+pub use revoke_role::*;
+pub use save_address::*;
 pub use send_and_confirm_transaction::*;
+pub use set_chat_language::*;
+pub use update_schedule::*;
 pub use update_transaction::*;
+pub use update_transaction_icon::*;
+pub use update_transaction_state::*;
+pub use upsert_user::*;
@@ -0,0 +1,18 @@
+use crate::utils::get_replica_id;
+use std::env;
+
+/// Gives up a lease before it runs out, other replicas may claim it right away
+pub async fn release_lease(name: &str) {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/leases/{}", base_url, name);
+
+    if let Err(e) = client
+        .delete(path)
+        .query(&[("holder", get_replica_id())])
+        .send()
+        .await
+    {
+        log::warn!("Failed to release the lease {}: {}", name, e);
+    }
+}
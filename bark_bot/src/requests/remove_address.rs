@@ -0,0 +1,17 @@
+use std::env;
+use teloxide::types::ChatId;
+
+/// Returns false if the address wasn't saved
+pub async fn remove_address(chat_id: ChatId, address: &str) -> bool {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/groups/{}/addresses/{}", base_url, chat_id, address);
+
+    client
+        .delete(path)
+        .send()
+        .await
+        .unwrap()
+        .status()
+        .is_success()
+}
@@ -0,0 +1,17 @@
+use std::env;
+use teloxide::types::ChatId;
+
+/// Returns false if the chat had no language set
+pub async fn reset_chat_language(chat_id: ChatId) -> bool {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!("{}/groups/{}/language", base_url, chat_id);
+
+    client
+        .delete(path)
+        .send()
+        .await
+        .unwrap()
+        .status()
+        .is_success()
+}
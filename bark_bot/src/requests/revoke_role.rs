@@ -0,0 +1,24 @@
+use crate::collections::Role;
+use std::env;
+use teloxide::types::{ChatId, UserId};
+
+/// Returns false if the user didn't have the role
+pub async fn revoke_role(chat_id: ChatId, user_id: UserId, role: Role) -> bool {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let client = reqwest::Client::new();
+    let path = format!(
+        "{}/groups/{}/roles/{}/{}",
+        base_url,
+        chat_id,
+        user_id,
+        role.as_str()
+    );
+
+    client
+        .delete(path)
+        .send()
+        .await
+        .unwrap()
+        .status()
+        .is_success()
+}
@@ -0,0 +1,36 @@
+use crate::collections::AddressBookEntry;
+use serde::{Deserialize, Serialize};
+use std::env;
+use teloxide::types::{ChatId, UserId};
+
+#[derive(Serialize, Deserialize)]
+pub struct SaveAddressBody {
+    pub label: String,
+    pub added_by: Option<String>,
+}
+
+/// Saves the address, or renames it if it is already in the address book
+pub async fn save_address(
+    chat_id: ChatId,
+    address: &str,
+    label: &str,
+    added_by: UserId,
+) -> AddressBookEntry {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let body = SaveAddressBody {
+        label: label.to_string(),
+        added_by: Some(added_by.to_string()),
+    };
+    let client = reqwest::Client::new();
+    let path = format!("{}/groups/{}/addresses/{}", base_url, chat_id, address);
+
+    client
+        .put(path)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json::<AddressBookEntry>()
+        .await
+        .unwrap()
+}
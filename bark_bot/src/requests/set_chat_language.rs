@@ -0,0 +1,34 @@
+use crate::collections::ChatLanguage;
+use serde::{Deserialize, Serialize};
+use std::env;
+use teloxide::types::{ChatId, UserId};
+
+#[derive(Serialize, Deserialize)]
+pub struct SetChatLanguageBody {
+    pub language: String,
+    pub updated_by: Option<String>,
+}
+
+pub async fn set_chat_language(
+    chat_id: ChatId,
+    language: &str,
+    updated_by: UserId,
+) -> ChatLanguage {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let body = SetChatLanguageBody {
+        language: language.to_string(),
+        updated_by: Some(updated_by.to_string()),
+    };
+    let client = reqwest::Client::new();
+    let path = format!("{}/groups/{}/language", base_url, chat_id);
+
+    client
+        .put(path)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json::<ChatLanguage>()
+        .await
+        .unwrap()
+}
@@ -0,0 +1,29 @@
+use crate::collections::Schedule;
+use serde::{Deserialize, Serialize};
+use std::env;
+
+#[derive(Serialize, Deserialize)]
+pub struct UpdateScheduleBody {
+    pub paused: bool,
+    pub next_run_at: Option<i64>,
+}
+
+pub async fn update_schedule(id: i64, paused: bool, next_run_at: Option<i64>) -> Schedule {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let body = UpdateScheduleBody {
+        paused,
+        next_run_at,
+    };
+    let client = reqwest::Client::new();
+    let path = format!("{}/schedules/{}", base_url, id);
+
+    client
+        .patch(path)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json::<Schedule>()
+        .await
+        .unwrap()
+}
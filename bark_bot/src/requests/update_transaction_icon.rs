@@ -0,0 +1,27 @@
+use crate::collections::Transaction;
+use serde::{Deserialize, Serialize};
+use std::env;
+
+#[derive(Serialize, Deserialize)]
+pub struct UpdateTransactionIconBody {
+    pub icon_url: Option<String>,
+}
+
+pub async fn update_transaction_icon(id: i64, icon_url: &str) -> Transaction {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let body = UpdateTransactionIconBody {
+        icon_url: Some(icon_url.to_string()),
+    };
+    let client = reqwest::Client::new();
+    let path = format!("{}/transactions/{}/icon", base_url, id);
+
+    client
+        .patch(path)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json::<Transaction>()
+        .await
+        .unwrap()
+}
@@ -0,0 +1,27 @@
+use crate::collections::Transaction;
+use serde::{Deserialize, Serialize};
+use std::env;
+
+#[derive(Serialize, Deserialize)]
+pub struct UpdateTransactionStateBody {
+    pub state: String,
+}
+
+pub async fn update_transaction_state(id: i64, state: &str) -> Transaction {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let body = UpdateTransactionStateBody {
+        state: state.to_string(),
+    };
+    let client = reqwest::Client::new();
+    let path = format!("{}/transactions/{}/state", base_url, id);
+
+    client
+        .patch(path)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json::<Transaction>()
+        .await
+        .unwrap()
+}
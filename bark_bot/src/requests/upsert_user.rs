@@ -0,0 +1,27 @@
+use crate::collections::UserProfile;
+use serde::{Deserialize, Serialize};
+use std::env;
+use teloxide::types::UserId;
+
+#[derive(Serialize, Deserialize)]
+pub struct UpsertUserBody {
+    pub name: String,
+    pub pubkey: Option<String>,
+}
+
+pub async fn upsert_user(user_id: UserId, name: String, pubkey: Option<String>) -> UserProfile {
+    let base_url = env::var("API_BASE_URL").unwrap();
+    let body = UpsertUserBody { name, pubkey };
+    let client = reqwest::Client::new();
+    let path = format!("{}/users/{}", base_url, user_id);
+
+    client
+        .put(path)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json::<UserProfile>()
+        .await
+        .unwrap()
+}
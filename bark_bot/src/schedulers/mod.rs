@@ -0,0 +1,7 @@
+pub mod remind_pending_transactions;
+pub mod run_schedules;
+pub mod sweep_idle_dialogues;
+
+pub use remind_pending_transactions::*;
+pub use run_schedules::*;
+pub use sweep_idle_dialogues::*;
@@ -0,0 +1,322 @@
+use crate::collections::{
+    ExpiryAction, ReminderSettings, ReminderTarget, RequestProgress, Transaction,
+};
+use crate::messages::refresh_transaction_request;
+use crate::requests::{
+    claim_lease, create_transaction_event, create_transaction_reminder, expire_transaction,
+    get_multisig_account, get_open_transactions, get_transaction_account, update_transaction_state,
+};
+use crate::utils::{
+    find_whitelist_user_id, format_duration, format_pubkey, get_group_chat_id, get_language,
+    get_multisig_pubkey, get_reminder_settings, get_timestamp, get_transaction_message_id,
+    get_transaction_message_url, get_transaction_state, translate, JOBS_LEASE, JOBS_LEASE_TTL,
+};
+use squads_mpl::state::{Ms, MsTransaction, MsTransactionStatus};
+use std::time::Duration;
+use teloxide::{
+    prelude::*,
+    types::{ParseMode, ReplyParameters},
+};
+
+const REMINDER_TICK: Duration = Duration::from_secs(60);
+
+/// Periodically nags the members that haven't voted on open proposals and
+/// expires the ones that outlived the configured deadline.
+pub async fn remind_pending_transactions(bot: Bot) {
+    let settings = get_reminder_settings();
+
+    if settings.interval.is_none() && settings.deadline.is_none() {
+        log::info!("Proposal reminders and expiry are disabled");
+        return;
+    }
+
+    let mut interval = tokio::time::interval(REMINDER_TICK);
+
+    loop {
+        interval.tick().await;
+
+        // Only one replica runs the jobs, the others stand by in case it goes away
+        if !claim_lease(JOBS_LEASE, JOBS_LEASE_TTL).await {
+            continue;
+        }
+
+        // Run every check in its own task so a failing request doesn't stop the scheduler
+        let check = tokio::spawn(check_pending_transactions(bot.clone(), settings.clone()));
+
+        if let Err(e) = check.await {
+            log::error!("Failed to check pending transactions: {}", e);
+        }
+    }
+}
+
+async fn check_pending_transactions(bot: Bot, settings: ReminderSettings) {
+    let multisig_pubkey = get_multisig_pubkey();
+    let multisig_account = get_multisig_account(multisig_pubkey).await;
+
+    for transaction_entry in get_open_transactions().await {
+        let transaction_account =
+            get_transaction_account(multisig_pubkey, transaction_entry.transaction_index).await;
+        let state = get_transaction_state(&transaction_account.status);
+
+        // Votes cast outside of the bot only show up here, so the message has to follow
+        if state != transaction_entry.state {
+            update_transaction_state(transaction_entry.id, state).await;
+
+            if let Err(e) =
+                refresh_transaction_request(&bot, transaction_entry.id, &RequestProgress::Idle)
+                    .await
+            {
+                log::warn!("Failed to refresh transaction request: {}", e);
+            }
+        }
+
+        if transaction_account.status != MsTransactionStatus::Active
+            && transaction_account.status != MsTransactionStatus::ExecuteReady
+        {
+            continue;
+        }
+
+        // Expired proposals stay open on chain but were already announced
+        if transaction_entry.expired_at.is_some() {
+            continue;
+        }
+
+        let now = get_timestamp();
+
+        if let Some(deadline) = settings.deadline {
+            if now - transaction_entry.created_at >= deadline.as_secs() as i64 {
+                expire(&bot, &settings, &transaction_entry, &transaction_account).await;
+                continue;
+            }
+        }
+
+        if let Some(interval) = settings.interval {
+            let last_reminder_at = transaction_entry
+                .last_reminder_at
+                .unwrap_or(transaction_entry.created_at);
+
+            if transaction_account.status == MsTransactionStatus::Active
+                && now - last_reminder_at >= interval.as_secs() as i64
+            {
+                remind(
+                    &bot,
+                    &settings,
+                    &transaction_entry,
+                    &multisig_account,
+                    &transaction_account,
+                )
+                .await;
+            }
+        }
+    }
+}
+
+async fn remind(
+    bot: &Bot,
+    settings: &ReminderSettings,
+    transaction_entry: &Transaction,
+    multisig_account: &Ms,
+    transaction_account: &MsTransaction,
+) {
+    let pending_members: Vec<_> = multisig_account
+        .keys
+        .iter()
+        .filter(|key| {
+            !transaction_account.approved.contains(key)
+                && !transaction_account.rejected.contains(key)
+        })
+        .collect();
+
+    if pending_members.is_empty() {
+        return;
+    }
+
+    let group_chat_id = get_group_chat_id();
+    let message_id = get_transaction_message_id(transaction_entry);
+    let age = get_timestamp() - transaction_entry.created_at;
+
+    if settings.target != ReminderTarget::Group {
+        let url = get_transaction_message_url(transaction_entry);
+
+        for member in &pending_members {
+            let Some(user_id) = find_whitelist_user_id(member) else {
+                continue;
+            };
+
+            // Each member is reminded in their own language
+            let language = get_language(user_id.into(), None).await;
+            let link = url
+                .as_ref()
+                .map(|url| {
+                    format!(
+                        "\n\n<a href=\"{}\">{}</a>",
+                        url,
+                        translate(language, "reminder-open-request", &[])
+                    )
+                })
+                .unwrap_or_default();
+            let text = translate(
+                language,
+                "reminder-direct",
+                &[
+                    ("index", transaction_entry.transaction_index.into()),
+                    ("age", format_duration(language, age).into()),
+                    ("link", link.into()),
+                ],
+            );
+
+            if let Err(e) = bot
+                .send_message(user_id, text)
+                .parse_mode(ParseMode::Html)
+                .await
+            {
+                log::warn!("Failed to remind user {}: {}", user_id, e);
+            }
+        }
+    }
+
+    if settings.target != ReminderTarget::Direct {
+        let mentions: Vec<String> = pending_members
+            .iter()
+            .map(|member| match find_whitelist_user_id(member) {
+                Some(user_id) => format!(
+                    "<a href=\"tg://user?id={}\">{}</a>",
+                    user_id,
+                    format_pubkey(member)
+                ),
+                None => format!("<code>{}</code>", format_pubkey(member)),
+            })
+            .collect();
+
+        let language = get_language(group_chat_id, None).await;
+        let text = translate(
+            language,
+            "reminder-group",
+            &[
+                ("index", transaction_entry.transaction_index.into()),
+                ("age", format_duration(language, age).into()),
+                ("members", mentions.join(", ").into()),
+            ],
+        );
+
+        let mut request = bot
+            .send_message(group_chat_id, text)
+            .parse_mode(ParseMode::Html);
+
+        if let Some(message_id) = message_id {
+            request = request.reply_parameters(ReplyParameters::new(message_id));
+        }
+
+        if let Err(e) = request.await {
+            log::warn!("Failed to post reminder in the group: {}", e);
+        }
+    }
+
+    create_transaction_reminder(transaction_entry.id).await;
+}
+
+async fn expire(
+    bot: &Bot,
+    settings: &ReminderSettings,
+    transaction_entry: &Transaction,
+    transaction_account: &MsTransaction,
+) {
+    expire_transaction(transaction_entry.id).await;
+
+    let voted = match (&settings.expiry_action, settings.expiry_executor) {
+        (ExpiryAction::Cancel, Some(executor)) => {
+            vote_against_expired(transaction_entry, transaction_account, executor).await
+        }
+        _ => false,
+    };
+
+    if let Err(e) =
+        refresh_transaction_request(bot, transaction_entry.id, &RequestProgress::Idle).await
+    {
+        log::warn!("Failed to refresh transaction request: {}", e);
+    }
+
+    let group_chat_id = get_group_chat_id();
+    let language = get_language(group_chat_id, None).await;
+    let ready = transaction_account.status == MsTransactionStatus::ExecuteReady;
+    let key = match (&settings.expiry_action, ready, voted) {
+        (ExpiryAction::None, _, _) => return,
+        (ExpiryAction::Status, _, _) => "expiry-status",
+        (ExpiryAction::Cancel, true, true) => "expiry-cancel-voted",
+        (ExpiryAction::Cancel, false, true) => "expiry-reject-voted",
+        // The members are asked instead when the vote couldn't be sent
+        (ExpiryAction::Cancel, true, false) => "expiry-vote-cancel",
+        (ExpiryAction::Cancel, false, false) => "expiry-vote-reject",
+    };
+    let text = translate(
+        language,
+        key,
+        &[
+            ("index", transaction_entry.transaction_index.into()),
+            (
+                "age",
+                format_duration(language, get_timestamp() - transaction_entry.created_at).into(),
+            ),
+        ],
+    );
+
+    let Some(message_id) = get_transaction_message_id(transaction_entry) else {
+        return;
+    };
+
+    if let Err(e) = bot
+        .send_message(group_chat_id, text)
+        .reply_parameters(ReplyParameters::new(message_id))
+        .await
+    {
+        log::warn!("Failed to post expiry status in the group: {}", e);
+    }
+}
+
+/// Votes with the executor's key to cancel a proposal that is ready to execute, or to
+/// reject one that is still collecting votes since Squads only cancels approved ones.
+/// The other members can add their votes to reach the threshold.
+async fn vote_against_expired(
+    transaction_entry: &Transaction,
+    transaction_account: &MsTransaction,
+    executor: UserId,
+) -> bool {
+    let multisig_pubkey = get_multisig_pubkey();
+    let transaction_index = transaction_entry.transaction_index;
+    let (kind, result) = match transaction_account.status {
+        MsTransactionStatus::ExecuteReady => (
+            "cancel",
+            crate::actions::cancel_transaction(multisig_pubkey, transaction_index, executor).await,
+        ),
+        _ => (
+            "reject",
+            crate::actions::reject_transaction(multisig_pubkey, transaction_index, executor).await,
+        ),
+    };
+
+    match result {
+        Ok(signature) => {
+            create_transaction_event(transaction_entry.id, kind, executor, signature.to_string())
+                .await;
+
+            let transaction_account =
+                get_transaction_account(multisig_pubkey, transaction_index).await;
+            update_transaction_state(
+                transaction_entry.id,
+                get_transaction_state(&transaction_account.status),
+            )
+            .await;
+
+            true
+        }
+        Err(e) => {
+            log::warn!(
+                "Failed to {} expired transaction Nº{}: {}",
+                kind,
+                transaction_index,
+                e
+            );
+            false
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::collections::{Role, Schedule, ScheduledAction, Transaction};
+use crate::messages::{post_transaction_request, send_proposal_error};
+use crate::requests::{
+    claim_lease, create_schedule_run, get_schedules, update_schedule, GetSchedulesQuery,
+};
+use crate::utils::{
+    find_user_keypair, get_group_chat_id, get_language, get_multisig_pubkey, get_next_run,
+    get_timestamp, has_role, parse_transfer_arguments, translate, JOBS_LEASE, JOBS_LEASE_TTL,
+};
+use std::time::Duration;
+use teloxide::{prelude::*, types::ParseMode, utils::html};
+
+const SCHEDULE_TICK: Duration = Duration::from_secs(60);
+
+/// Creates the proposals of the schedules that are due and posts them to the group
+pub async fn run_schedules(bot: Bot) {
+    let mut interval = tokio::time::interval(SCHEDULE_TICK);
+
+    loop {
+        interval.tick().await;
+
+        // Only one replica runs the jobs, the others stand by in case it goes away
+        if !claim_lease(JOBS_LEASE, JOBS_LEASE_TTL).await {
+            continue;
+        }
+
+        let schedules = get_schedules(&GetSchedulesQuery {
+            due_before: Some(get_timestamp()),
+            ..Default::default()
+        })
+        .await;
+
+        for schedule in schedules {
+            let schedule_id = schedule.id;
+
+            // Each run gets its own task so a failing Blink doesn't stop the scheduler
+            if let Err(e) = tokio::spawn(run_schedule(bot.clone(), schedule)).await {
+                log::error!("Failed to run schedule {}: {}", schedule_id, e);
+            }
+        }
+    }
+}
+
+async fn run_schedule(bot: Bot, schedule: Schedule) {
+    let user_id = UserId(schedule.user_id.parse().unwrap());
+    let language = get_language(get_group_chat_id(), None).await;
+
+    // The creator may have lost the permission to propose since the schedule was made
+    if !has_role(user_id, Role::Proposer).await || find_user_keypair(user_id).is_none() {
+        update_schedule(schedule.id, true, schedule.next_run_at).await;
+        notify(
+            &bot,
+            translate(
+                language,
+                "schedule-paused-creator",
+                &[
+                    ("id", schedule.id.into()),
+                    ("title", html::escape(&schedule.title).into()),
+                ],
+            ),
+        )
+        .await;
+        return;
+    }
+
+    // Moving on before proposing means a failed run is skipped instead of retried every tick
+    let next_run_at = get_next_run(&schedule.cadence, get_timestamp(), schedule.ends_at);
+    create_schedule_run(schedule.id, None, next_run_at).await;
+
+    let result = match serde_json::from_str::<ScheduledAction>(&schedule.action) {
+        Ok(ScheduledAction::Transfer { arguments }) => {
+            match parse_transfer_arguments(&arguments, language).await {
+                Ok(arguments) => {
+                    Ok(
+                        crate::actions::create_transfer(get_multisig_pubkey(), user_id, &arguments)
+                            .await,
+                    )
+                }
+                Err(e) => Err(e),
+            }
+        }
+        Ok(ScheduledAction::Blink { url }) => Ok(crate::actions::create_transaction(
+            &url,
+            get_multisig_pubkey(),
+            user_id,
+            &schedule.title,
+            &translate(
+                language,
+                "schedule-proposal-description",
+                &[("id", schedule.id.into())],
+            ),
+            &[],
+            None,
+        )
+        .await),
+        Err(e) => Err(translate(
+            language,
+            "schedule-invalid-stored-action",
+            &[("error", e.to_string().into())],
+        )),
+    };
+
+    match result {
+        Ok(Ok(transaction_entry)) => {
+            on_proposed(&bot, &schedule, &transaction_entry, next_run_at).await;
+        }
+        Ok(Err(error)) => {
+            let title = format!("#{} {}", schedule.id, schedule.title);
+
+            if let Err(e) =
+                send_proposal_error(&bot, get_group_chat_id(), language, &title, &error).await
+            {
+                log::warn!("Failed to send schedule notification: {}", e);
+            }
+        }
+        Err(e) => {
+            notify(
+                &bot,
+                translate(
+                    language,
+                    "schedule-run-failed",
+                    &[
+                        ("id", schedule.id.into()),
+                        ("title", html::escape(&schedule.title).into()),
+                        ("error", html::escape(&e).into()),
+                    ],
+                ),
+            )
+            .await;
+        }
+    }
+}
+
+async fn on_proposed(
+    bot: &Bot,
+    schedule: &Schedule,
+    transaction_entry: &Transaction,
+    next_run_at: Option<i64>,
+) {
+    create_schedule_run(schedule.id, Some(transaction_entry.id), next_run_at).await;
+
+    if let Err(e) = post_transaction_request(bot, transaction_entry).await {
+        log::warn!("Failed to post scheduled transaction request: {}", e);
+    }
+
+    log::info!(
+        "Schedule {} proposed transaction Nº{}",
+        schedule.id,
+        transaction_entry.transaction_index
+    );
+}
+
+async fn notify(bot: &Bot, text: String) {
+    if let Err(e) = bot
+        .send_message(get_group_chat_id(), text)
+        .parse_mode(ParseMode::Html)
+        .await
+    {
+        log::warn!("Failed to send schedule notification: {}", e);
+    }
+}
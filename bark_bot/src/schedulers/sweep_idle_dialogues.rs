@@ -0,0 +1,52 @@
+use crate::collections::{ActiveDialogues, JoinStorage, MyDialogue, StorageSettings};
+use crate::handlers::expire_idle_dialogue;
+use crate::requests::claim_lease;
+use crate::utils::{list_dialogues, JOBS_LEASE, JOBS_LEASE_TTL};
+use std::time::Duration;
+use teloxide::prelude::*;
+
+const SWEEP_TICK: Duration = Duration::from_secs(60);
+
+/// Removes the flows that went idle. SQLite and Redis are listed directly, so dialogues
+/// from before a restart are swept too. The in-memory storage can't be listed and falls
+/// back to the chats tracked since the bot started.
+pub async fn sweep_idle_dialogues(
+    bot: Bot,
+    storage: JoinStorage,
+    settings: StorageSettings,
+    active: ActiveDialogues,
+) {
+    let mut interval = tokio::time::interval(SWEEP_TICK);
+
+    loop {
+        interval.tick().await;
+
+        let chat_ids = match list_dialogues(&settings).await {
+            Ok(Some(chat_ids)) => {
+                // Shared storage is swept by the replica running the jobs only
+                if !claim_lease(JOBS_LEASE, JOBS_LEASE_TTL).await {
+                    continue;
+                }
+
+                chat_ids
+            }
+            Ok(None) => active.chat_ids(),
+            Err(e) => {
+                log::warn!("Failed to list the stored dialogues: {}", e);
+                continue;
+            }
+        };
+
+        for chat_id in chat_ids {
+            let dialogue = MyDialogue::new(storage.clone(), chat_id);
+
+            match dialogue.get().await {
+                Ok(Some(handler)) => {
+                    expire_idle_dialogue(bot.clone(), dialogue, handler).await;
+                }
+                Ok(None) => {}
+                Err(e) => log::warn!("Failed to read the dialogue of {}: {}", chat_id, e),
+            }
+        }
+    }
+}
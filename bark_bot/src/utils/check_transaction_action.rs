@@ -0,0 +1,108 @@
+use crate::collections::{RequestProgress, Role, Transaction};
+use crate::messages::refresh_transaction_request;
+use crate::policies::check_transaction_policy;
+use crate::requests::{get_multisig_account, get_transaction_account};
+use crate::utils::{find_user_keypair, get_multisig_pubkey, has_role, translate};
+use solana_sdk::signer::Signer;
+use teloxide::{prelude::*, types::User};
+
+/// Checks that the user may approve, reject, execute or cancel the proposal,
+/// the error is the reason to show them in their language
+pub async fn check_transaction_action(
+    bot: &Bot,
+    user: &User,
+    transaction_entry: &Transaction,
+    action: &str,
+    language: &str,
+) -> ResponseResult<Result<(), String>> {
+    let role = match action {
+        "Execute" => Role::Executor,
+        "Approve" | "Reject" | "Cancel" => Role::Voter,
+        _ => {
+            return Ok(Err(translate(
+                language,
+                "unknown-action",
+                &[("action", action.into())],
+            )))
+        }
+    };
+
+    if !has_role(user.id, role).await {
+        log::warn!(
+            "{} ({}) tried to {} transaction {} without the {} role",
+            user.full_name(),
+            user.id,
+            action.to_lowercase(),
+            transaction_entry.id,
+            role.as_str()
+        );
+
+        return Ok(Err(translate(
+            language,
+            "role-required",
+            &[("role", role.as_str().into())],
+        )));
+    }
+
+    let multisig_pubkey = get_multisig_pubkey();
+    let multisig_account = get_multisig_account(multisig_pubkey).await;
+
+    let Some(member_pubkey) = find_user_keypair(user.id)
+        .map(|keypair| keypair.pubkey())
+        .filter(|pubkey| multisig_account.keys.contains(pubkey))
+    else {
+        log::warn!(
+            "Unauthorized {} attempt on transaction Nº{} by {} ({})",
+            action,
+            transaction_entry.transaction_index,
+            user.full_name(),
+            user.id
+        );
+
+        return Ok(Err(translate(language, "not-a-signer", &[])));
+    };
+
+    let transaction_account =
+        get_transaction_account(multisig_pubkey, transaction_entry.transaction_index).await;
+
+    // Squads lets members switch votes, repeating the same one would only waste a transaction
+    let already_voted = match action {
+        "Approve" => transaction_account.approved.contains(&member_pubkey),
+        "Reject" => transaction_account.rejected.contains(&member_pubkey),
+        "Cancel" => transaction_account.cancelled.contains(&member_pubkey),
+        _ => false,
+    };
+
+    if already_voted {
+        return Ok(Err(translate(
+            language,
+            "already-voted",
+            &[("action", action.into())],
+        )));
+    }
+
+    if action == "Execute" {
+        let violations =
+            check_transaction_policy(multisig_pubkey, transaction_entry.id, &transaction_account)
+                .await
+                .violations;
+
+        if !violations.is_empty() {
+            let reasons = violations
+                .iter()
+                .map(|violation| violation.message(language))
+                .collect::<Vec<String>>()
+                .join("; ");
+
+            refresh_transaction_request(bot, transaction_entry.id, &RequestProgress::Idle).await?;
+
+            return Ok(Err(translate(
+                language,
+                "policy-blocked",
+                &[("reasons", reasons.into())],
+            )));
+        }
+    }
+
+    Ok(Ok(()))
+}
@@ -1,4 +1,31 @@
 use solana_sdk::{pubkey, pubkey::Pubkey};
+use std::time::Duration;
 
 pub const SQUADS_PROGRAM_ID: Pubkey = pubkey!("SMPLecH534NA9acpos4G6x7uf3LWbCAwZQE9e8ZekMu");
 pub const RPC: &str = "https://devnet.helius-rpc.com/?api-key=c6fd3da7-1cd1-460d-bc14-f9a9b3a26cd9";
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+pub const SOL_DECIMALS: u8 = 9;
+pub const NATIVE_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+pub const LOOKALIKE_CHARS: usize = 4; // Matching prefix or suffix length that flags a lookalike address
+pub const PAYOUT_CALLBACK_PREFIX: &str = "payout:"; // Buttons of the payout summary, handled by the dialogue
+pub const MAX_PAYOUT_ROWS: usize = 250;
+pub const BLINK_CALLBACK_PREFIX: &str = "blink:"; // Action buttons of a Blink being proposed
+pub const UNFURL_CALLBACK_PREFIX: &str = "unfurl:"; // Buttons under a Blink preview in the group
+pub const SHARED_ACTION_START_PREFIX: &str = "blink_"; // `/start` payload of the inline mode deep links
+pub const MAX_ICON_SIZE: usize = 5 * 1024 * 1024; // Bytes downloaded at most for a Blink icon
+pub const MAX_CAPTION_LENGTH: usize = 1024; // Longer photo captions are refused by Telegram
+pub const CONFIRM_CALLBACK_PREFIX: &str = "confirm:"; // Confirm and cancel buttons of a Blink opened from a shared link
+pub const MAX_ICON_CACHE_SIZE: u64 = 100 * 1024 * 1024; // Bytes of cached icons, the oldest are removed past it
+pub const JOBS_LEASE: &str = "jobs"; // Held by the one replica running the background jobs
+pub const JOBS_LEASE_TTL: Duration = Duration::from_secs(3 * 60); // Renewed on every tick, another replica takes over once it runs out
+pub const IN_FLIGHT_LEASE_TTL: Duration = Duration::from_secs(5 * 60); // Longest a vote or execution may keep a proposal locked
+pub const POLICY_LEASE: &str = "policy"; // Taken around executions, see `lock_policy`
+pub const POLICY_LOCK_TIMEOUT: Duration = Duration::from_secs(30); // Longest an execution waits for the policy lock before failing
+pub const MAX_METADATA_SIZE: usize = 1024 * 1024; // Bytes downloaded at most for the metadata of a Blink shared in the group
+pub const MAX_MESSAGE_LENGTH: usize = 4096; // Longer messages are refused by Telegram
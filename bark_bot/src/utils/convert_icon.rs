@@ -0,0 +1,111 @@
+use image::{ImageFormat, ImageReader, Limits};
+use resvg::{
+    tiny_skia::{Pixmap, Transform},
+    usvg::{ImageHrefResolver, Options, Tree},
+};
+use std::io::Cursor;
+
+const MAX_ICON_DIMENSION: u32 = 1024; // Pixels, larger WebP icons are refused and SVG ones drawn at this size
+
+/// Brings a downloaded icon to a format Telegram takes as a photo: PNG and JPEG are kept,
+/// WebP and SVG are converted to PNG
+pub fn convert_icon(icon: Vec<u8>) -> Result<Vec<u8>, String> {
+    if icon.starts_with(b"\x89PNG\r\n\x1a\n") || icon.starts_with(&[0xff, 0xd8, 0xff]) {
+        return Ok(icon);
+    }
+
+    if icon.starts_with(b"RIFF") && icon.get(8..12) == Some(b"WEBP") {
+        return convert_webp(icon);
+    }
+
+    convert_svg(&icon)
+}
+
+fn convert_webp(icon: Vec<u8>) -> Result<Vec<u8>, String> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_ICON_DIMENSION);
+    limits.max_image_height = Some(MAX_ICON_DIMENSION);
+
+    let mut reader = ImageReader::with_format(Cursor::new(icon), ImageFormat::WebP);
+    reader.limits(limits);
+
+    let mut png = Cursor::new(vec![]);
+    reader
+        .decode()
+        .map_err(|e| e.to_string())?
+        .write_to(&mut png, ImageFormat::Png)
+        .map_err(|e| e.to_string())?;
+
+    Ok(png.into_inner())
+}
+
+fn convert_svg(icon: &[u8]) -> Result<Vec<u8>, String> {
+    // Images embedded as data URLs are drawn, references to local files aren't followed
+    let options = Options {
+        image_href_resolver: ImageHrefResolver {
+            resolve_data: ImageHrefResolver::default_data_resolver(),
+            resolve_string: Box::new(|_, _| None),
+        },
+        ..Options::default()
+    };
+    let tree = Tree::from_data(icon, &options).map_err(|_| "Not a PNG, JPEG, WebP or SVG image")?;
+
+    let size = tree.size();
+    let scale = MAX_ICON_DIMENSION as f32 / size.width().max(size.height());
+    let mut pixmap = Pixmap::new(
+        (size.width() * scale).ceil() as u32,
+        (size.height() * scale).ceil() as u32,
+    )
+    .ok_or("Empty SVG image")?;
+
+    resvg::render(
+        &tree,
+        Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    pixmap.encode_png().map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{DynamicImage, RgbaImage};
+
+    const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+    #[test]
+    fn png_and_jpeg_are_kept() {
+        let jpeg = vec![0xff, 0xd8, 0xff, 0xe0];
+
+        assert_eq!(convert_icon(PNG_SIGNATURE.to_vec()).unwrap(), PNG_SIGNATURE);
+        assert_eq!(convert_icon(jpeg.clone()).unwrap(), jpeg);
+    }
+
+    #[test]
+    fn webp_is_converted() {
+        let mut webp = Cursor::new(vec![]);
+        DynamicImage::ImageRgba8(RgbaImage::new(4, 4))
+            .write_to(&mut webp, ImageFormat::WebP)
+            .unwrap();
+
+        assert!(convert_icon(webp.into_inner())
+            .unwrap()
+            .starts_with(PNG_SIGNATURE));
+    }
+
+    #[test]
+    fn svg_is_drawn_at_the_icon_size() {
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="8"><rect width="16" height="8" fill="red"/></svg>"#;
+        let png = convert_icon(svg.to_vec()).unwrap();
+        let image = image::load_from_memory(&png).unwrap();
+
+        assert_eq!((image.width(), image.height()), (1024, 512));
+    }
+
+    #[test]
+    fn other_content_is_refused() {
+        assert!(convert_icon(b"<html></html>".to_vec()).is_err());
+        assert!(convert_icon(vec![]).is_err());
+    }
+}
@@ -0,0 +1,13 @@
+use teloxide::{net::Download, prelude::*, types::Document};
+
+/// Contents of a small text document sent to the bot, invalid UTF-8 replaced
+pub async fn download_text_file(
+    bot: &Bot,
+    document: &Document,
+) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+    let file = bot.get_file(document.file.id.clone()).await?;
+    let mut contents: Vec<u8> = vec![];
+    bot.download_file(&file.path, &mut contents).await?;
+
+    Ok(String::from_utf8_lossy(&contents).to_string())
+}
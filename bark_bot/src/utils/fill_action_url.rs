@@ -0,0 +1,13 @@
+use url::form_urlencoded;
+
+/// Replaces the `{name}` placeholders of an action URL with the encoded values
+pub fn fill_action_url(url: &str, names: &[String], values: &[String]) -> String {
+    names
+        .iter()
+        .zip(values)
+        .fold(url.to_string(), |request_url, (name, value)| {
+            let encoded: String = form_urlencoded::byte_serialize(value.as_bytes()).collect();
+
+            request_url.replace(&format!("{{{}}}", name), &encoded)
+        })
+}
@@ -0,0 +1,28 @@
+use crate::utils::parse_action_url;
+use teloxide::types::{Message, MessageEntityKind};
+use url::Url;
+
+/// First link of the message that may point to a Blink
+pub fn find_action_url(msg: &Message) -> Option<Url> {
+    let mut links: Vec<String> = msg
+        .parse_entities()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|entity| match entity.kind() {
+            MessageEntityKind::Url => Some(entity.text().to_string()),
+            MessageEntityKind::TextLink { url } => Some(url.to_string()),
+            _ => None,
+        })
+        .collect();
+
+    // Telegram doesn't recognize the scheme as a link
+    links.extend(
+        msg.text()
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter(|word| word.starts_with("solana-action:"))
+            .map(str::to_string),
+    );
+
+    links.iter().find_map(|link| parse_action_url(link))
+}
@@ -0,0 +1,68 @@
+use crate::collections::AddressBookEntry;
+use crate::utils::LOOKALIKE_CHARS;
+
+/// Saved entry that shares the first or last characters of a different address, the
+/// trick used by address poisoning
+pub fn find_lookalike<'a>(
+    address: &str,
+    address_book: &'a [AddressBookEntry],
+) -> Option<&'a AddressBookEntry> {
+    if address.len() <= LOOKALIKE_CHARS {
+        return None;
+    }
+
+    let prefix = &address[..LOOKALIKE_CHARS];
+    let suffix = &address[address.len() - LOOKALIKE_CHARS..];
+
+    address_book.iter().find(|entry| {
+        entry.address != address
+            && (entry.address.starts_with(prefix) || entry.address.ends_with(suffix))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAVED: &str = "7Np41oeYqPefeNQEHSv1UDhYrehxin3NStELsSKCT4K2";
+
+    fn address_book() -> Vec<AddressBookEntry> {
+        vec![AddressBookEntry {
+            chat_id: "-100".to_string(),
+            address: SAVED.to_string(),
+            label: "payroll".to_string(),
+            added_by: None,
+            created_at: 0,
+        }]
+    }
+
+    #[test]
+    fn flags_a_matching_prefix_or_suffix() {
+        let address_book = address_book();
+
+        let prefix = find_lookalike(
+            "7Np4xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx",
+            &address_book,
+        );
+        let suffix = find_lookalike(
+            "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxT4K2",
+            &address_book,
+        );
+
+        assert_eq!(prefix.unwrap().label, "payroll");
+        assert_eq!(suffix.unwrap().label, "payroll");
+    }
+
+    #[test]
+    fn ignores_the_saved_address_itself() {
+        assert!(find_lookalike(SAVED, &address_book()).is_none());
+    }
+
+    #[test]
+    fn ignores_unrelated_and_short_addresses() {
+        let address_book = address_book();
+
+        assert!(find_lookalike("11111111111111111111111111111111", &address_book).is_none());
+        assert!(find_lookalike("7Np4", &address_book).is_none());
+    }
+}
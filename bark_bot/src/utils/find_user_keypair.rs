@@ -0,0 +1,14 @@
+use solana_sdk::signature::Keypair;
+use std::env;
+use teloxide::types::UserId;
+
+pub fn find_user_keypair(user_id: UserId) -> Option<Keypair> {
+    let whitelist = env::var("WHITELIST").unwrap();
+
+    let parsed_whitelist: Vec<(String, String)> = serde_json::from_str(&whitelist).unwrap();
+
+    parsed_whitelist
+        .iter()
+        .find(|entry| entry.0 == user_id.to_string())
+        .map(|entry| Keypair::from_base58_string(&entry.1))
+}
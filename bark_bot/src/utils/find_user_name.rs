@@ -0,0 +1,21 @@
+use crate::collections::UserProfile;
+use crate::utils::{find_whitelist_user_id, format_pubkey};
+use solana_sdk::pubkey::Pubkey;
+
+/// Telegram name of the member behind a signer key or linked wallet, or the shortened
+/// key if unknown
+pub fn find_user_name(users: &[UserProfile], pubkey: &Pubkey) -> String {
+    let user_id = find_whitelist_user_id(pubkey).map(|user_id| user_id.to_string());
+
+    users
+        .iter()
+        .find(|user| {
+            let pubkey = Some(pubkey.to_string());
+
+            Some(&user.user_id) == user_id.as_ref()
+                || user.pubkey == pubkey
+                || user.wallet == pubkey
+        })
+        .map(|user| user.name.clone())
+        .unwrap_or_else(|| format_pubkey(pubkey))
+}
@@ -0,0 +1,14 @@
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use std::env;
+use teloxide::types::UserId;
+
+pub fn find_whitelist_user_id(pubkey: &Pubkey) -> Option<UserId> {
+    let whitelist = env::var("WHITELIST").unwrap();
+
+    let parsed_whitelist: Vec<(String, String)> = serde_json::from_str(&whitelist).unwrap();
+
+    parsed_whitelist
+        .iter()
+        .find(|entry| Keypair::from_base58_string(&entry.1).pubkey() == *pubkey)
+        .map(|entry| UserId(entry.0.parse::<u64>().unwrap()))
+}
@@ -0,0 +1,21 @@
+/// Adds thousands separators to the integer part of a decimal amount
+pub fn format_amount(amount: &str) -> String {
+    let (integer, fraction) = match amount.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (amount, None),
+    };
+
+    let mut grouped = String::new();
+
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    match fraction {
+        Some(fraction) => format!("{}.{}", grouped, fraction),
+        None => grouped,
+    }
+}
@@ -0,0 +1,28 @@
+/// Amount in token units of an amount in base units, without trailing zeros
+pub fn format_base_amount(amount: u128, decimals: u8) -> String {
+    // Padded as a string, mints may have more decimals than fit in an integer divisor
+    let decimals = usize::from(decimals);
+    let digits = format!("{:0>width$}", amount, width = decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{}.{}", integer, fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_base_amounts_without_trailing_zeros() {
+        assert_eq!(format_base_amount(1_500_000, 6), "1.5");
+        assert_eq!(format_base_amount(2_000_000_000, 9), "2");
+        assert_eq!(format_base_amount(7, 0), "7");
+        assert_eq!(format_base_amount(5, 3), "0.005");
+        assert_eq!(format_base_amount(1, 20), "0.00000000000000000001");
+    }
+}
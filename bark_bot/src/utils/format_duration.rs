@@ -0,0 +1,22 @@
+use crate::utils::translate;
+
+pub fn format_duration(language: &str, seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    let days = seconds / 86_400;
+    let hours = (seconds % 86_400) / 3_600;
+    let minutes = (seconds % 3_600) / 60;
+
+    match (days, hours) {
+        (0, 0) => translate(language, "duration-minutes", &[("minutes", minutes.into())]),
+        (0, _) => translate(
+            language,
+            "duration-hours",
+            &[("hours", hours.into()), ("minutes", minutes.into())],
+        ),
+        _ => translate(
+            language,
+            "duration-days",
+            &[("days", days.into()), ("hours", hours.into())],
+        ),
+    }
+}
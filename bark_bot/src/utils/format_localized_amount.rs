@@ -0,0 +1,16 @@
+use crate::utils::{format_amount, translate};
+
+/// `format_amount` with the separators of the language
+pub fn format_localized_amount(amount: &str, language: &str) -> String {
+    let group_separator = translate(language, "number-group-separator", &[]);
+    let decimal_separator = translate(language, "number-decimal-separator", &[]);
+
+    format_amount(amount)
+        .chars()
+        .map(|character| match character {
+            ',' => group_separator.clone(),
+            '.' => decimal_separator.clone(),
+            other => other.to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,10 @@
+use crate::collections::PolicyViolation;
+use teloxide::utils::html;
+
+pub fn format_policy_violations(language: &str, violations: &[PolicyViolation]) -> String {
+    violations
+        .iter()
+        .map(|violation| format!("• {}", html::escape(&violation.message(language))))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
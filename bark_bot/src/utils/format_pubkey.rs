@@ -0,0 +1,7 @@
+use solana_sdk::pubkey::Pubkey;
+
+pub fn format_pubkey(pubkey: &Pubkey) -> String {
+    let pubkey = pubkey.to_string();
+
+    format!("{}…{}", &pubkey[..4], &pubkey[pubkey.len() - 4..])
+}
@@ -0,0 +1,8 @@
+use crate::utils::format_pubkey;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+/// "SOL" as is, mints shortened like any other address
+pub fn format_token(token: &str) -> String {
+    Pubkey::from_str(token).map_or(token.to_string(), |mint| format_pubkey(&mint))
+}
@@ -0,0 +1,11 @@
+use crate::utils::translate;
+
+/// Name of a proposal state in the language, unknown states are shown as stored
+pub fn format_transaction_state(language: &str, state: &str) -> String {
+    match state {
+        "draft" | "active" | "execute_ready" | "executed" | "rejected" | "cancelled" => {
+            translate(language, &format!("state-{}", state), &[])
+        }
+        other => other.to_string(),
+    }
+}
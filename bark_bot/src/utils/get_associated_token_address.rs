@@ -0,0 +1,11 @@
+use crate::utils::ASSOCIATED_TOKEN_PROGRAM_ID;
+use solana_sdk::pubkey::Pubkey;
+
+pub fn get_associated_token_address(owner: Pubkey, mint: Pubkey, token_program: Pubkey) -> Pubkey {
+    let (associated_token_address, _) = Pubkey::find_program_address(
+        &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    );
+
+    associated_token_address
+}
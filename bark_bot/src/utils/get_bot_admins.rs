@@ -0,0 +1,12 @@
+use std::env;
+use teloxide::types::UserId;
+
+/// Users that are admins of every group regardless of the stored roles, used to bootstrap them
+pub fn get_bot_admins() -> Vec<UserId> {
+    env::var("BOT_ADMINS")
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|user_id| user_id.trim().parse::<u64>().ok())
+        .map(UserId)
+        .collect()
+}
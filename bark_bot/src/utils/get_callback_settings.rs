@@ -0,0 +1,24 @@
+use crate::collections::CallbackSettings;
+use std::{env, time::Duration};
+
+const DEFAULT_MAX_AGE: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+pub fn get_callback_settings() -> CallbackSettings {
+    // Falling back to the bot token keeps existing deployments working, it's just as secret
+    let secret = env::var("CALLBACK_SECRET")
+        .ok()
+        .filter(|value| !value.is_empty())
+        .or_else(|| env::var("TELOXIDE_TOKEN").ok())
+        .unwrap();
+
+    let max_age = match env::var("CALLBACK_MAX_AGE") {
+        Ok(value) if value == "0" => None,
+        Ok(value) => Some(Duration::from_secs(value.parse::<u64>().unwrap())),
+        Err(_) => Some(DEFAULT_MAX_AGE),
+    };
+
+    CallbackSettings {
+        secret: secret.into_bytes(),
+        max_age,
+    }
+}
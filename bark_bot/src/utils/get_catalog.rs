@@ -0,0 +1,9 @@
+use crate::collections::Catalog;
+use std::sync::OnceLock;
+
+static CATALOG: OnceLock<Catalog> = OnceLock::new();
+
+/// Translations, parsed the first time a message is translated
+pub fn get_catalog() -> &'static Catalog {
+    CATALOG.get_or_init(Catalog::load)
+}
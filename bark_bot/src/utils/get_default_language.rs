@@ -0,0 +1,11 @@
+use crate::collections::FALLBACK_LANGUAGE;
+use crate::utils::get_catalog;
+use std::env;
+
+/// Language of the group messages and of the members whose app language isn't translated
+pub fn get_default_language() -> &'static str {
+    env::var("DEFAULT_LANGUAGE")
+        .ok()
+        .and_then(|code| get_catalog().find_language(&code))
+        .unwrap_or(FALLBACK_LANGUAGE)
+}
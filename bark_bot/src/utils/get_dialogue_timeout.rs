@@ -0,0 +1,12 @@
+use std::{env, time::Duration};
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// Idle time after which a flow in progress is abandoned, None when disabled
+pub fn get_dialogue_timeout() -> Option<Duration> {
+    match env::var("DIALOGUE_TIMEOUT") {
+        Ok(value) if value == "0" => None,
+        Ok(value) => Some(Duration::from_secs(value.parse::<u64>().unwrap())),
+        Err(_) => Some(DEFAULT_TIMEOUT),
+    }
+}
@@ -0,0 +1,11 @@
+use teloxide::types::LinkPreviewOptions;
+
+pub fn get_disabled_link_preview() -> LinkPreviewOptions {
+    LinkPreviewOptions {
+        is_disabled: true,
+        url: None,
+        prefer_small_media: false,
+        prefer_large_media: false,
+        show_above_text: false,
+    }
+}
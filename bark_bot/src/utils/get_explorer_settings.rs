@@ -0,0 +1,11 @@
+use crate::collections::{Explorer, ExplorerSettings};
+use std::env;
+
+pub fn get_explorer_settings() -> ExplorerSettings {
+    ExplorerSettings {
+        explorer: env::var("EXPLORER")
+            .map(|value| value.parse::<Explorer>().unwrap())
+            .unwrap_or(Explorer::Solana),
+        cluster: env::var("CLUSTER").unwrap_or("devnet".to_string()),
+    }
+}
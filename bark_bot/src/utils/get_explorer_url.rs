@@ -0,0 +1,37 @@
+use crate::collections::Explorer;
+use crate::utils::get_explorer_settings;
+
+/// Link to a transaction (`tx`) or an account (`address`) on the configured explorer
+pub fn get_explorer_url(kind: &str, value: &str) -> String {
+    let settings = get_explorer_settings();
+    let mainnet = settings.cluster == "mainnet-beta";
+
+    match settings.explorer {
+        Explorer::Solana if mainnet => format!("https://explorer.solana.com/{}/{}", kind, value),
+        Explorer::Solana => format!(
+            "https://explorer.solana.com/{}/{}?cluster={}",
+            kind, value, settings.cluster
+        ),
+        Explorer::Solscan => {
+            let kind = if kind == "address" { "account" } else { kind };
+
+            if mainnet {
+                format!("https://solscan.io/{}/{}", kind, value)
+            } else {
+                format!(
+                    "https://solscan.io/{}/{}?cluster={}",
+                    kind, value, settings.cluster
+                )
+            }
+        }
+        Explorer::SolanaFm => {
+            let cluster = if mainnet {
+                "mainnet-alpha".to_string()
+            } else {
+                format!("{}-solana", settings.cluster)
+            };
+
+            format!("https://solana.fm/{}/{}?cluster={}", kind, value, cluster)
+        }
+    }
+}
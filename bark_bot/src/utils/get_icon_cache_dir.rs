@@ -0,0 +1,10 @@
+use std::{env, path::PathBuf};
+
+/// Directory where the downloaded Blink icons are kept, one file per icon URL
+pub fn get_icon_cache_dir() -> PathBuf {
+    env::var("ICON_CACHE_DIR")
+        .ok()
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("icon_cache"))
+}
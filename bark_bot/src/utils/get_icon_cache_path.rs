@@ -0,0 +1,13 @@
+use crate::utils::get_icon_cache_dir;
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+
+/// File of the cache holding the icon downloaded from `url`
+pub fn get_icon_cache_path(url: &str) -> PathBuf {
+    let hash = Sha256::digest(url.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+
+    get_icon_cache_dir().join(hash)
+}
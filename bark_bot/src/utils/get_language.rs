@@ -0,0 +1,20 @@
+use crate::requests::get_chat_language;
+use crate::utils::{get_catalog, get_default_language};
+use teloxide::types::{ChatId, User};
+
+/// Language to talk in: the one chosen for the chat with /language, otherwise the one
+/// of the member's Telegram app when it's translated, otherwise the default
+pub async fn get_language(chat_id: ChatId, user: Option<&User>) -> &'static str {
+    let catalog = get_catalog();
+
+    if let Some(language) = get_chat_language(chat_id)
+        .await
+        .and_then(|chat_language| catalog.find_language(&chat_language.language))
+    {
+        return language;
+    }
+
+    user.and_then(|user| user.language_code.as_deref())
+        .and_then(|code| catalog.find_language(code))
+        .unwrap_or_else(get_default_language)
+}
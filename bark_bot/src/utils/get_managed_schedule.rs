@@ -0,0 +1,29 @@
+use crate::collections::{Role, Schedule};
+use crate::requests::get_schedule;
+use crate::utils::{get_group_chat_id, has_role, translate};
+use teloxide::types::UserId;
+
+/// Schedule named by the command arguments, if the user created it or is an admin.
+/// Errors are in the language.
+pub async fn get_managed_schedule(
+    user_id: UserId,
+    args: &str,
+    language: &str,
+) -> Result<Schedule, String> {
+    let id = args
+        .trim()
+        .trim_start_matches('#')
+        .parse::<i64>()
+        .map_err(|_| translate(language, "schedule-invalid-id", &[]))?;
+
+    let schedule = get_schedule(id)
+        .await
+        .filter(|schedule| schedule.chat_id == get_group_chat_id().to_string())
+        .ok_or_else(|| translate(language, "schedule-not-found", &[("id", id.into())]))?;
+
+    if schedule.user_id != user_id.to_string() && !has_role(user_id, Role::Admin).await {
+        return Err(translate(language, "schedule-not-managed", &[]));
+    }
+
+    Ok(schedule)
+}
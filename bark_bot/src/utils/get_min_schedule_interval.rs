@@ -0,0 +1,11 @@
+use std::{env, time::Duration};
+
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Shortest time allowed between two runs of a schedule, each run is an on-chain proposal
+pub fn get_min_schedule_interval() -> Duration {
+    match env::var("MIN_SCHEDULE_INTERVAL") {
+        Ok(value) => Duration::from_secs(value.parse::<u64>().unwrap()),
+        Err(_) => DEFAULT_INTERVAL,
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::DateTime;
+use cron::Schedule;
+use std::str::FromStr;
+
+/// First run of the cadence strictly after `after`, or None past the end date
+pub fn get_next_run(cadence: &str, after: i64, ends_at: Option<i64>) -> Option<i64> {
+    let schedule = Schedule::from_str(cadence).ok()?;
+    let next_run = schedule
+        .after(&DateTime::from_timestamp(after, 0)?)
+        .next()?
+        .timestamp();
+
+    match ends_at {
+        Some(ends_at) if next_run > ends_at => None,
+        _ => Some(next_run),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Sunday 2024-06-02 00:00 UTC
+    const SUNDAY: i64 = 1717286400;
+    const DAILY: &str = "0 0 9 * * *";
+
+    #[test]
+    fn runs_strictly_after() {
+        assert_eq!(get_next_run(DAILY, SUNDAY, None), Some(SUNDAY + 9 * 3600));
+        assert_eq!(
+            get_next_run(DAILY, SUNDAY + 9 * 3600, None),
+            Some(SUNDAY + 33 * 3600)
+        );
+    }
+
+    #[test]
+    fn stops_at_the_end_date() {
+        assert_eq!(
+            get_next_run(DAILY, SUNDAY, Some(SUNDAY + 9 * 3600)),
+            Some(SUNDAY + 9 * 3600)
+        );
+        assert_eq!(get_next_run(DAILY, SUNDAY, Some(SUNDAY + 3600)), None);
+    }
+
+    #[test]
+    fn refuses_invalid_expressions() {
+        assert_eq!(get_next_run("every day", SUNDAY, None), None);
+    }
+}
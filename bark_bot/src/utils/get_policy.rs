@@ -0,0 +1,23 @@
+use crate::collections::Policy;
+use std::{env, fs, sync::OnceLock};
+
+static POLICY: OnceLock<Option<Policy>> = OnceLock::new();
+
+/// Policy from the TOML file at `POLICY_PATH`, or None when no policy is configured.
+/// Read once, main loads it at startup so an invalid file stops the bot right away.
+pub fn get_policy() -> Option<&'static Policy> {
+    POLICY
+        .get_or_init(|| {
+            let path = env::var("POLICY_PATH")
+                .ok()
+                .filter(|value| !value.is_empty())?;
+            let contents = fs::read_to_string(&path)
+                .unwrap_or_else(|e| panic!("Can't read the policy at {}: {}", path, e));
+
+            Some(
+                toml::from_str(&contents)
+                    .unwrap_or_else(|e| panic!("Invalid policy at {}: {}", path, e)),
+            )
+        })
+        .as_ref()
+}
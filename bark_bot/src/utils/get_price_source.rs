@@ -0,0 +1,9 @@
+use crate::collections::PriceSource;
+use std::env;
+
+pub fn get_price_source() -> Option<PriceSource> {
+    env::var("PRICE_SOURCE")
+        .ok()
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse::<PriceSource>().unwrap())
+}
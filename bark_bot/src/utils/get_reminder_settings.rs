@@ -0,0 +1,40 @@
+use crate::collections::{ExpiryAction, ReminderSettings, ReminderTarget};
+use crate::utils::find_user_keypair;
+use std::{env, time::Duration};
+use teloxide::types::UserId;
+
+pub fn get_reminder_settings() -> ReminderSettings {
+    let seconds = |name: &str| {
+        env::var(name)
+            .ok()
+            .map(|value| Duration::from_secs(value.parse::<u64>().unwrap()))
+    };
+
+    let settings = ReminderSettings {
+        interval: seconds("REMINDER_INTERVAL"),
+        deadline: seconds("PROPOSAL_DEADLINE"),
+        target: env::var("REMINDER_TARGET")
+            .map(|value| value.parse::<ReminderTarget>().unwrap())
+            .unwrap_or(ReminderTarget::Both),
+        expiry_action: env::var("EXPIRY_ACTION")
+            .map(|value| value.parse::<ExpiryAction>().unwrap())
+            .unwrap_or(ExpiryAction::Status),
+        expiry_executor: env::var("EXPIRY_EXECUTOR")
+            .ok()
+            .filter(|value| !value.is_empty())
+            .map(|value| UserId(value.parse::<u64>().unwrap())),
+    };
+
+    if settings.expiry_action == ExpiryAction::Cancel {
+        let executor = settings
+            .expiry_executor
+            .expect("EXPIRY_ACTION=cancel needs an EXPIRY_EXECUTOR");
+
+        assert!(
+            find_user_keypair(executor).is_some(),
+            "EXPIRY_EXECUTOR must be in the WHITELIST"
+        );
+    }
+
+    settings
+}
@@ -0,0 +1,9 @@
+use rand::Rng;
+use std::sync::OnceLock;
+
+static REPLICA_ID: OnceLock<String> = OnceLock::new();
+
+/// Random id of this bot process, names it as the holder of the leases it claims
+pub fn get_replica_id() -> &'static str {
+    REPLICA_ID.get_or_init(|| format!("{:016x}", rand::thread_rng().gen::<u64>()))
+}
@@ -0,0 +1,19 @@
+use solana_sdk::pubkey::Pubkey;
+use std::env;
+use url::{form_urlencoded, Url};
+
+/// Page that asks the wallet to sign the link message, from the optional
+/// `SIGN_MESSAGE_URL` template with `{pubkey}` and `{message}` placeholders
+pub fn get_sign_message_url(pubkey: &Pubkey, message: &str) -> Option<Url> {
+    let template = env::var("SIGN_MESSAGE_URL")
+        .ok()
+        .filter(|value| !value.is_empty())?;
+    let message: String = form_urlencoded::byte_serialize(message.as_bytes()).collect();
+
+    Url::parse(
+        &template
+            .replace("{pubkey}", &pubkey.to_string())
+            .replace("{message}", &message),
+    )
+    .ok()
+}
@@ -0,0 +1,28 @@
+use crate::collections::{StorageBackend, StorageSerializer, StorageSettings};
+use std::{env, path::PathBuf};
+
+const DEFAULT_SQLITE_PATH: &str = "db.sqlite";
+
+pub fn get_storage_settings() -> StorageSettings {
+    let value = |name: &str| env::var(name).ok().filter(|value| !value.is_empty());
+
+    let backend = match value("STORAGE")
+        .as_deref()
+        .map(str::to_lowercase)
+        .as_deref()
+    {
+        Some("memory") => StorageBackend::Memory,
+        Some("redis") => StorageBackend::Redis(value("REDIS_URL").unwrap()),
+        Some("sqlite") | None => StorageBackend::Sqlite(PathBuf::from(
+            value("STORAGE_PATH").unwrap_or(DEFAULT_SQLITE_PATH.to_string()),
+        )),
+        Some(other) => panic!("Unknown storage backend: {}", other),
+    };
+
+    StorageSettings {
+        backend,
+        serializer: value("STORAGE_SERIALIZER")
+            .map(|value| value.parse::<StorageSerializer>().unwrap())
+            .unwrap_or(StorageSerializer::Json),
+    }
+}
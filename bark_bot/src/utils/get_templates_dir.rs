@@ -0,0 +1,9 @@
+use std::{env, path::PathBuf};
+
+/// Directory with the operator's message templates, None to only use the built-in ones
+pub fn get_templates_dir() -> Option<PathBuf> {
+    env::var("TEMPLATES_DIR")
+        .ok()
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
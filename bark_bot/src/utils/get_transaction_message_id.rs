@@ -0,0 +1,10 @@
+use crate::collections::Transaction;
+use teloxide::types::MessageId;
+
+pub fn get_transaction_message_id(transaction_entry: &Transaction) -> Option<MessageId> {
+    transaction_entry
+        .message_id
+        .as_ref()
+        .and_then(|message_id| message_id.parse::<i32>().ok())
+        .map(MessageId)
+}
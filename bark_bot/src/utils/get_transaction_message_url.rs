@@ -0,0 +1,11 @@
+use crate::collections::Transaction;
+use crate::utils::{get_group_chat_id, get_transaction_message_id};
+use teloxide::types::Message;
+use url::Url;
+
+/// Link to the proposal message, only available when the group is a supergroup
+pub fn get_transaction_message_url(transaction_entry: &Transaction) -> Option<Url> {
+    let message_id = get_transaction_message_id(transaction_entry)?;
+
+    Message::url_of(get_group_chat_id(), None, message_id)
+}
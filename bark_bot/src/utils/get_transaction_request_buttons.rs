@@ -1,37 +1,73 @@
-use squads_mpl::state::MsTransactionStatus;
+use squads_mpl::state::{MsTransaction, MsTransactionStatus};
 use teloxide::types::InlineKeyboardButton;
 
-use crate::collections::ButtonMetadata;
+use crate::collections::{ButtonMetadata, ExpiryAction};
+use crate::utils::translate;
 
 pub fn get_transaction_request_buttons(
     transaction_id: i64,
     threshold: u16,
-    approved: u16,
-    rejected: u16,
-    status: &MsTransactionStatus,
+    transaction_account: &MsTransaction,
+    expiry_action: Option<&ExpiryAction>,
+    policy_blocked: bool,
+    language: &str,
 ) -> Vec<InlineKeyboardButton> {
-    match status {
-        MsTransactionStatus::Active => {
-            vec![
-                InlineKeyboardButton::callback(
-                    format!("Approve {}/{}", approved, threshold),
-                    &ButtonMetadata {
-                        transaction_id,
-                        value: "Approve".to_string(),
-                    },
-                ),
-                InlineKeyboardButton::callback(
-                    format!("Reject {}/{}", rejected, threshold),
-                    &ButtonMetadata {
-                        transaction_id,
-                        value: "Reject".to_string(),
-                    },
-                ),
-            ]
+    let vote_label = |key: &str, count: usize| {
+        translate(
+            language,
+            key,
+            &[("count", count.into()), ("threshold", threshold.into())],
+        )
+    };
+
+    let approve_button = InlineKeyboardButton::callback(
+        vote_label("button-approve", transaction_account.approved.len()),
+        &ButtonMetadata {
+            transaction_id,
+            value: "Approve".to_string(),
+        },
+    );
+    let reject_button = InlineKeyboardButton::callback(
+        vote_label("button-reject", transaction_account.rejected.len()),
+        &ButtonMetadata {
+            transaction_id,
+            value: "Reject".to_string(),
+        },
+    );
+
+    match (&transaction_account.status, expiry_action) {
+        (_, Some(ExpiryAction::Status)) => {
+            vec![]
+        }
+        // Squads can only cancel execute-ready transactions, active ones have to be rejected
+        (MsTransactionStatus::Active, Some(ExpiryAction::Cancel)) => {
+            vec![reject_button]
+        }
+        (MsTransactionStatus::Active, _) => {
+            vec![approve_button, reject_button]
+        }
+        (MsTransactionStatus::ExecuteReady, Some(ExpiryAction::Cancel)) => {
+            vec![InlineKeyboardButton::callback(
+                vote_label("button-cancel", transaction_account.cancelled.len()),
+                &ButtonMetadata {
+                    transaction_id,
+                    value: "Cancel".to_string(),
+                },
+            )]
+        }
+        // Still an execute button, so clicking it explains which rules are missing
+        (MsTransactionStatus::ExecuteReady, _) if policy_blocked => {
+            vec![InlineKeyboardButton::callback(
+                translate(language, "button-policy-blocked", &[]),
+                &ButtonMetadata {
+                    transaction_id,
+                    value: "Execute".to_string(),
+                },
+            )]
         }
-        MsTransactionStatus::ExecuteReady => {
+        (MsTransactionStatus::ExecuteReady, _) => {
             vec![InlineKeyboardButton::callback(
-                "Execute".to_string(),
+                translate(language, "button-execute", &[]),
                 &ButtonMetadata {
                     transaction_id,
                     value: "Execute".to_string(),
@@ -0,0 +1,12 @@
+use squads_mpl::state::MsTransactionStatus;
+
+pub fn get_transaction_state(status: &MsTransactionStatus) -> &'static str {
+    match status {
+        MsTransactionStatus::Draft => "draft",
+        MsTransactionStatus::Active => "active",
+        MsTransactionStatus::ExecuteReady => "execute_ready",
+        MsTransactionStatus::Executed => "executed",
+        MsTransactionStatus::Rejected => "rejected",
+        MsTransactionStatus::Cancelled => "cancelled",
+    }
+}
@@ -0,0 +1,64 @@
+use crate::collections::MintInfo;
+use crate::utils::{get_associated_token_address, ASSOCIATED_TOKEN_PROGRAM_ID};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_instruction, system_program,
+};
+
+const CREATE_IDEMPOTENT: u8 = 1;
+const TOKEN_TRANSFER_CHECKED: u8 = 12;
+
+/// Instructions moving `amount` lamports, or base units of the mint, from the vault.
+/// `create_account` adds the recipient's associated token account, paid by the vault.
+pub fn get_transfer_instructions(
+    vault_pubkey: Pubkey,
+    recipient: Pubkey,
+    amount: u64,
+    mint: Option<(Pubkey, &MintInfo)>,
+    create_account: bool,
+) -> Vec<Instruction> {
+    let Some((mint, mint_info)) = mint else {
+        return vec![system_instruction::transfer(
+            &vault_pubkey,
+            &recipient,
+            amount,
+        )];
+    };
+
+    let source = get_associated_token_address(vault_pubkey, mint, mint_info.token_program);
+    let destination = get_associated_token_address(recipient, mint, mint_info.token_program);
+    let mut instructions: Vec<Instruction> = vec![];
+
+    if create_account {
+        instructions.push(Instruction {
+            program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(vault_pubkey, true),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(recipient, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+                AccountMeta::new_readonly(mint_info.token_program, false),
+            ],
+            data: vec![CREATE_IDEMPOTENT],
+        });
+    }
+
+    let mut data = vec![TOKEN_TRANSFER_CHECKED];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(mint_info.decimals);
+
+    instructions.push(Instruction {
+        program_id: mint_info.token_program,
+        accounts: vec![
+            AccountMeta::new(source, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new_readonly(vault_pubkey, true),
+        ],
+        data,
+    });
+
+    instructions
+}
@@ -0,0 +1,25 @@
+use crate::collections::WebhookSettings;
+use std::{env, net::SocketAddr};
+use url::Url;
+
+const DEFAULT_ADDRESS: &str = "0.0.0.0:8443";
+
+/// Returns None when no public URL is configured, the bot then uses long polling
+pub fn get_webhook_settings() -> Option<WebhookSettings> {
+    let url = env::var("WEBHOOK_URL")
+        .ok()
+        .filter(|value| !value.is_empty())?;
+
+    let address = env::var("WEBHOOK_ADDRESS")
+        .ok()
+        .filter(|value| !value.is_empty())
+        .unwrap_or(DEFAULT_ADDRESS.to_string());
+
+    Some(WebhookSettings {
+        url: Url::parse(&url).unwrap(),
+        address: address.parse::<SocketAddr>().unwrap(),
+        secret_token: env::var("WEBHOOK_SECRET")
+            .ok()
+            .filter(|value| !value.is_empty()),
+    })
+}
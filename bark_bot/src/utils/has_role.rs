@@ -0,0 +1,16 @@
+use crate::collections::Role;
+use crate::requests::get_roles;
+use crate::utils::{get_bot_admins, get_group_chat_id};
+use teloxide::types::UserId;
+
+pub async fn has_role(user_id: UserId, role: Role) -> bool {
+    if get_bot_admins().contains(&user_id) {
+        return true;
+    }
+
+    let roles = get_roles(get_group_chat_id(), Some(user_id)).await;
+
+    roles.iter().any(|grant| {
+        grant.role == role.as_str() || grant.role == Role::Admin.as_str() || role == Role::Viewer
+    })
+}
@@ -0,0 +1,47 @@
+use crate::collections::{StorageBackend, StorageSettings};
+use redis::AsyncCommands;
+use sqlx::{sqlite::SqliteConnectOptions, ConnectOptions, Row};
+use teloxide::types::ChatId;
+
+type ListError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Chats with a dialogue in the persistent storage, including the ones stored before a
+/// restart or by another replica. Returns `None` for the in-memory storage, which can't
+/// be listed.
+pub async fn list_dialogues(settings: &StorageSettings) -> Result<Option<Vec<ChatId>>, ListError> {
+    match &settings.backend {
+        StorageBackend::Memory => Ok(None),
+        StorageBackend::Sqlite(path) => {
+            let mut connection = SqliteConnectOptions::new()
+                .filename(path)
+                .read_only(true)
+                .connect()
+                .await?;
+
+            let chat_ids = sqlx::query("SELECT chat_id FROM teloxide_dialogues")
+                .fetch_all(&mut connection)
+                .await?
+                .iter()
+                .map(|row| row.try_get::<i64, _>("chat_id").map(ChatId))
+                .collect::<Result<Vec<ChatId>, sqlx::Error>>()?;
+
+            Ok(Some(chat_ids))
+        }
+        StorageBackend::Redis(url) => {
+            let mut connection = redis::Client::open(url.as_str())?
+                .get_multiplexed_async_connection()
+                .await?;
+
+            // Dialogues are stored under the bare chat id, skip anything else in the database
+            let mut keys = connection.scan::<String>().await?;
+            let mut chat_ids = vec![];
+            while let Some(key) = keys.next_item().await {
+                if let Ok(chat_id) = key.parse::<i64>() {
+                    chat_ids.push(ChatId(chat_id));
+                }
+            }
+
+            Ok(Some(chat_ids))
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use std::sync::{Mutex, MutexGuard};
+
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+/// Tests run on parallel threads, the ones setting or reading environment variables
+/// hold this so they never overlap
+pub fn lock_env() -> MutexGuard<'static, ()> {
+    // A failed test poisons the lock, the others can still run
+    ENV_LOCK.lock().unwrap_or_else(|error| error.into_inner())
+}
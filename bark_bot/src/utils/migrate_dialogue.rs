@@ -0,0 +1,97 @@
+use crate::utils::get_timestamp;
+use serde_json::Value;
+
+/// Each entry upgrades a stored dialogue from the version at its index to the next
+/// one, or returns None when the state can't be carried over
+const MIGRATIONS: &[fn(Value) -> Option<Value>] = &[
+    // 0 → 1: states stored before the envelope, the shape didn't change
+    Some,
+    // 1 → 2: flows got an `updated_at` for the idle timeout, counted from the deploy
+    |mut state| {
+        if let Some(data) = state
+            .as_object_mut()
+            .and_then(|variant| variant.values_mut().next())
+            .and_then(|fields| fields.get_mut("data"))
+            .and_then(Value::as_object_mut)
+        {
+            data.insert("updated_at".to_string(), get_timestamp().into());
+        }
+
+        Some(state)
+    },
+    // 2 → 3: blink flows keep the icon for the proposal message, older ones go without
+    |mut state| {
+        if let Some(data) = state
+            .as_object_mut()
+            .and_then(|variant| {
+                variant
+                    .iter_mut()
+                    .find(|(name, _)| *name == "InternalAction" || *name == "Parameters")
+            })
+            .and_then(|(_, fields)| fields.get_mut("data"))
+            .and_then(Value::as_object_mut)
+        {
+            data.insert("action_icon".to_string(), String::new().into());
+        }
+
+        Some(state)
+    },
+    // 3 → 4: parameters from shared links wait for a confirmation, older flows didn't
+    |mut state| {
+        if let Some(data) = state
+            .as_object_mut()
+            .and_then(|variant| variant.get_mut("Parameters"))
+            .and_then(|fields| fields.get_mut("data"))
+            .and_then(Value::as_object_mut)
+        {
+            data.insert("confirm".to_string(), false.into());
+        }
+
+        Some(state)
+    },
+];
+
+pub const DIALOGUE_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// Brings a stored dialogue up to `DIALOGUE_VERSION`, states from a newer bot are dropped
+pub fn migrate_dialogue(version: u32, state: Value) -> Option<Value> {
+    MIGRATIONS
+        .get(version as usize..)?
+        .iter()
+        .try_fold(state, |state, migration| migration(state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn current_states_are_kept() {
+        let state = json!({ "Start": {} });
+
+        assert_eq!(
+            migrate_dialogue(DIALOGUE_VERSION, state.clone()),
+            Some(state)
+        );
+    }
+
+    #[test]
+    fn states_from_a_newer_bot_are_dropped() {
+        assert_eq!(migrate_dialogue(DIALOGUE_VERSION + 1, json!("Start")), None);
+        assert_eq!(migrate_dialogue(u32::MAX, json!("Start")), None);
+    }
+
+    #[test]
+    fn old_parameters_are_upgraded() {
+        let state = json!({ "Parameters": { "data": { "action_url": "https://example.com" } } });
+
+        let migrated = migrate_dialogue(1, state).unwrap();
+        let data = &migrated["Parameters"]["data"];
+
+        assert!(data["updated_at"].is_i64());
+        assert_eq!(data["action_icon"], "");
+        assert_eq!(data["confirm"], false);
+        assert_eq!(data["action_url"], "https://example.com");
+    }
+}
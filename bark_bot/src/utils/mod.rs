@@ -1,29 +1,155 @@
+pub mod check_transaction_action;
 pub mod consts;
+pub mod convert_icon;
+pub mod download_text_file;
+pub mod fill_action_url;
+pub mod find_action_url;
 pub mod find_blink_instructions;
 pub mod find_key_indexes;
+pub mod find_lookalike;
 pub mod find_unique_account_metas_map;
+pub mod find_user_keypair;
+pub mod find_user_name;
+pub mod find_whitelist_user_id;
+pub mod format_amount;
+pub mod format_base_amount;
+pub mod format_duration;
+pub mod format_localized_amount;
+pub mod format_policy_violations;
+pub mod format_pubkey;
+pub mod format_token;
+pub mod format_transaction_state;
+pub mod get_associated_token_address;
+pub mod get_bot_admins;
+pub mod get_callback_settings;
+pub mod get_catalog;
+pub mod get_default_language;
+pub mod get_dialogue_timeout;
+pub mod get_disabled_link_preview;
+pub mod get_explorer_settings;
+pub mod get_explorer_url;
 pub mod get_group_chat_id;
+pub mod get_icon_cache_dir;
+pub mod get_icon_cache_path;
 pub mod get_instruction_pubkey;
+pub mod get_language;
+pub mod get_managed_schedule;
+pub mod get_min_schedule_interval;
 pub mod get_multisig_authority_pubkey;
 pub mod get_multisig_pubkey;
+pub mod get_next_run;
+pub mod get_policy;
+pub mod get_price_source;
 pub mod get_program;
+pub mod get_reminder_settings;
+pub mod get_replica_id;
+pub mod get_sign_message_url;
+pub mod get_storage_settings;
+pub mod get_templates_dir;
+pub mod get_timestamp;
+pub mod get_transaction_message_id;
+pub mod get_transaction_message_url;
 pub mod get_transaction_pubkey;
 pub mod get_transaction_request_buttons;
-pub mod get_transaction_request_message;
+pub mod get_transaction_state;
+pub mod get_transfer_instructions;
 pub mod get_url_root;
 pub mod get_user_keypair;
+pub mod get_webhook_settings;
+pub mod has_role;
+pub mod is_public_address;
+pub mod list_dialogues;
+#[cfg(test)]
+pub mod lock_env;
+pub mod migrate_dialogue;
+pub mod open_storage;
+pub mod parse_action_url;
+pub mod parse_cadence;
+pub mod parse_role_arguments;
+pub mod parse_token_amount;
+pub mod parse_transaction_index;
+pub mod parse_transfer_arguments;
+pub mod prune_icon_cache;
+pub mod render_template;
+pub mod render_transaction_request;
+pub mod split_into_batches;
+pub mod split_message;
+pub mod translate;
 
+pub use check_transaction_action::*;
 pub use consts::*;
+pub use convert_icon::*;
+pub use download_text_file::*;
+pub use fill_action_url::*;
+pub use find_action_url::*;
 pub use find_blink_instructions::*;
 pub use find_key_indexes::*;
+pub use find_lookalike::*;
 pub use find_unique_account_metas_map::*;
+pub use find_user_keypair::*;
+pub use find_user_name::*;
+pub use find_whitelist_user_id::*;
+pub use format_amount::*;
+pub use format_base_amount::*;
+pub use format_duration::*;
+pub use format_localized_amount::*;
+pub use format_policy_violations::*;
+pub use format_pubkey::*;
+pub use format_token::*;
+pub use format_transaction_state::*;
+pub use get_associated_token_address::*;
+pub use get_bot_admins::*;
+pub use get_callback_settings::*;
+pub use get_catalog::*;
+pub use get_default_language::*;
+pub use get_dialogue_timeout::*;
+pub use get_disabled_link_preview::*;
+pub use get_explorer_settings::*;
+pub use get_explorer_url::*;
 pub use get_group_chat_id::*;
+pub use get_icon_cache_dir::*;
+pub use get_icon_cache_path::*;
 pub use get_instruction_pubkey::*;
+pub use get_language::*;
+pub use get_managed_schedule::*;
+pub use get_min_schedule_interval::*;
 pub use get_multisig_authority_pubkey::*;
 pub use get_multisig_pubkey::*;
+pub use get_next_run::*;
+pub use get_policy::*;
+pub use get_price_source::*;
 pub use get_program::*;
+pub use get_reminder_settings::*;
+pub use get_replica_id::*;
+pub use get_sign_message_url::*;
+pub use get_storage_settings::*;
+pub use get_templates_dir::*;
+pub use get_timestamp::*;
+pub use get_transaction_message_id::*;
+pub use get_transaction_message_url::*;
 pub use get_transaction_pubkey::*;
 pub use get_transaction_request_buttons::*;
-pub use get_transaction_request_message::*;
+pub use get_transaction_state::*;
+pub use get_transfer_instructions::*;
 pub use get_url_root::*;
 pub use get_user_keypair::*;
+pub use get_webhook_settings::*;
+pub use has_role::*;
+pub use is_public_address::*;
+pub use list_dialogues::*;
+#[cfg(test)]
+pub use lock_env::*;
+pub use migrate_dialogue::*;
+pub use open_storage::*;
+pub use parse_action_url::*;
+pub use parse_cadence::*;
+pub use parse_role_arguments::*;
+pub use parse_token_amount::*;
+pub use parse_transaction_index::*;
+pub use parse_transfer_arguments::*;
+pub use prune_icon_cache::*;
+pub use render_template::*;
+pub use render_transaction_request::*;
+pub use split_into_batches::*;
+pub use split_message::*;
+pub use translate::*;
@@ -0,0 +1,37 @@
+use crate::collections::{
+    JoinStorage, StorageBackend, StorageSerializer, StorageSettings, Versioned,
+};
+use teloxide::dispatching::dialogue::{
+    serializer::{Cbor, Json},
+    InMemStorage, RedisStorage, SqliteStorage, Storage,
+};
+
+pub async fn open_storage(settings: &StorageSettings) -> JoinStorage {
+    match (&settings.backend, settings.serializer) {
+        (StorageBackend::Memory, _) => InMemStorage::new().erase(),
+        (StorageBackend::Sqlite(path), StorageSerializer::Json) => {
+            SqliteStorage::open(&path.to_string_lossy(), Versioned(Json))
+                .await
+                .unwrap()
+                .erase()
+        }
+        (StorageBackend::Sqlite(path), StorageSerializer::Cbor) => {
+            SqliteStorage::open(&path.to_string_lossy(), Versioned(Cbor))
+                .await
+                .unwrap()
+                .erase()
+        }
+        (StorageBackend::Redis(url), StorageSerializer::Json) => {
+            RedisStorage::open(url.as_str(), Versioned(Json))
+                .await
+                .unwrap()
+                .erase()
+        }
+        (StorageBackend::Redis(url), StorageSerializer::Cbor) => {
+            RedisStorage::open(url.as_str(), Versioned(Cbor))
+                .await
+                .unwrap()
+                .erase()
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use url::Url;
+
+const ACTION_SCHEME: &str = "solana-action:";
+
+/// Action URL behind a link, which may use the `solana-action:` scheme or be wrapped
+/// by an interstitial page (`?action=`)
+pub fn parse_action_url(link: &str) -> Option<Url> {
+    let url = Url::parse(link.trim().trim_start_matches(ACTION_SCHEME)).ok()?;
+
+    let url = match url.query_pairs().find(|(key, _)| key == "action") {
+        Some((_, action)) => Url::parse(action.trim_start_matches(ACTION_SCHEME)).ok()?,
+        None => url,
+    };
+
+    matches!(url.scheme(), "https" | "http").then_some(url)
+}
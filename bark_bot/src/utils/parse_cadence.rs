@@ -0,0 +1,186 @@
+use crate::utils::{get_min_schedule_interval, get_timestamp, translate};
+use chrono::DateTime;
+use cron::Schedule;
+use std::str::FromStr;
+
+// Upcoming runs compared against the minimum interval
+const CHECKED_RUNS: usize = 100;
+
+/// Normalizes a cadence to the cron syntax stored with schedules. Accepts hourly, daily,
+/// weekly, monthly, the @ shorthands and five field cron expressions (UTC). Cadences that
+/// run more often than `MIN_SCHEDULE_INTERVAL` are refused, in the language.
+pub fn parse_cadence(cadence: &str, language: &str) -> Result<String, String> {
+    let cadence = cadence.trim();
+    let invalid = || {
+        translate(
+            language,
+            "cadence-invalid",
+            &[("cadence", cadence.to_string().into())],
+        )
+    };
+    let fields: Vec<&str> = cadence.split_whitespace().collect();
+    let expression = match cadence.to_lowercase().as_str() {
+        "hourly" | "daily" | "weekly" | "monthly" | "yearly" => {
+            format!("@{}", cadence.to_lowercase())
+        }
+        // The cron crate expects seconds first and counts weekdays from 1 = Sunday
+        _ if fields.len() == 5 => {
+            let days_of_week = convert_days_of_week(fields[4]).ok_or_else(invalid)?;
+
+            format!("0 {} {}", fields[..4].join(" "), days_of_week)
+        }
+        _ => cadence.to_string(),
+    };
+
+    let schedule = Schedule::from_str(&expression).map_err(|_| invalid())?;
+    let min_interval = get_min_schedule_interval().as_secs() as i64;
+    let runs: Vec<i64> = schedule
+        .after(&DateTime::from_timestamp(get_timestamp(), 0).unwrap())
+        .take(CHECKED_RUNS)
+        .map(|run| run.timestamp())
+        .collect();
+
+    if runs.windows(2).any(|pair| pair[1] - pair[0] < min_interval) {
+        return Err(translate(
+            language,
+            "cadence-too-frequent",
+            &[
+                ("cadence", cadence.to_string().into()),
+                ("minutes", (min_interval / 60).into()),
+            ],
+        ));
+    }
+
+    Ok(expression)
+}
+
+/// Turns a standard day of week field (0-7, both ends being Sunday) into the cron
+/// crate's numbering (1-7 from Sunday). Day names mean the same to both and are kept.
+fn convert_days_of_week(field: &str) -> Option<String> {
+    if field == "*" || field == "?" {
+        return Some(field.to_string());
+    }
+
+    let mut days: Vec<u32> = vec![];
+    let mut names: Vec<&str> = vec![];
+
+    for element in field.split(',') {
+        let (base, step) = match element.split_once('/') {
+            Some((base, step)) => (base, Some(step.parse::<usize>().ok().filter(|&s| s > 0)?)),
+            None => (element, None),
+        };
+        let range = match base.split_once('-') {
+            _ if base == "*" || base == "?" => Some((0, 6)),
+            Some((start, end)) => start.parse().ok().zip(end.parse().ok()),
+            // `n/step` runs from n to the end of the week
+            None => base
+                .parse()
+                .ok()
+                .map(|day| (day, if step.is_some() { 6 } else { day })),
+        };
+
+        let Some((start, end)) = range else {
+            names.push(element);
+            continue;
+        };
+
+        if start > end || end > 7 {
+            return None;
+        }
+
+        days.extend(
+            (start..=end)
+                .step_by(step.unwrap_or(1))
+                .map(|day: u32| day % 7 + 1),
+        );
+    }
+
+    days.sort();
+    days.dedup();
+
+    Some(
+        days.iter()
+            .map(|day| day.to_string())
+            .chain(names.into_iter().map(String::from))
+            .collect::<Vec<String>>()
+            .join(","),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{get_next_run, lock_env};
+    use chrono::{DateTime, Datelike, Weekday};
+
+    // Sunday 2024-06-02 00:00 UTC
+    const SUNDAY: i64 = 1717286400;
+
+    fn next_weekday(cadence: &str) -> Weekday {
+        let expression = parse_cadence(cadence, "en").unwrap();
+        let next_run = get_next_run(&expression, SUNDAY, None).unwrap();
+
+        DateTime::from_timestamp(next_run, 0).unwrap().weekday()
+    }
+
+    #[test]
+    fn monday_runs_on_monday() {
+        let _env = lock_env();
+        assert_eq!(parse_cadence("0 9 * * 1", "en").unwrap(), "0 0 9 * * 2");
+        assert_eq!(next_weekday("0 9 * * 1"), Weekday::Mon);
+    }
+
+    #[test]
+    fn sunday_is_zero_or_seven() {
+        let _env = lock_env();
+        assert_eq!(parse_cadence("0 9 * * 0", "en").unwrap(), "0 0 9 * * 1");
+        assert_eq!(parse_cadence("0 9 * * 7", "en").unwrap(), "0 0 9 * * 1");
+        assert_eq!(next_weekday("0 9 * * 7"), Weekday::Sun);
+    }
+
+    #[test]
+    fn ranges_lists_and_steps() {
+        let _env = lock_env();
+        assert_eq!(
+            parse_cadence("0 9 * * 1-5", "en").unwrap(),
+            "0 0 9 * * 2,3,4,5,6"
+        );
+        assert_eq!(
+            parse_cadence("0 9 * * 5-7", "en").unwrap(),
+            "0 0 9 * * 1,6,7"
+        );
+        assert_eq!(parse_cadence("0 9 * * 1,3", "en").unwrap(), "0 0 9 * * 2,4");
+        assert_eq!(
+            parse_cadence("0 9 * * */2", "en").unwrap(),
+            "0 0 9 * * 1,3,5,7"
+        );
+        assert_eq!(next_weekday("0 9 * * 6"), Weekday::Sat);
+    }
+
+    #[test]
+    fn names_and_wildcards_are_kept() {
+        let _env = lock_env();
+        assert_eq!(parse_cadence("0 9 * * MON", "en").unwrap(), "0 0 9 * * MON");
+        assert_eq!(next_weekday("0 9 * * MON-FRI"), Weekday::Mon);
+        assert_eq!(parse_cadence("0 9 * * *", "en").unwrap(), "0 0 9 * * *");
+    }
+
+    #[test]
+    fn cadences_under_the_minimum_interval_are_refused() {
+        let _env = lock_env();
+        assert!(parse_cadence("* * * * *", "en").is_err());
+        assert!(parse_cadence("*/30 * * * *", "en").is_err());
+        assert!(parse_cadence("* * * * * *", "en").is_err());
+        assert!(parse_cadence("0,30 9 * * *", "en").is_err());
+        assert!(parse_cadence("hourly", "en").is_ok());
+        assert!(parse_cadence("0 */2 * * *", "en").is_ok());
+    }
+
+    #[test]
+    fn invalid_days_are_refused() {
+        let _env = lock_env();
+        assert!(parse_cadence("0 9 * * 8", "en").is_err());
+        assert!(parse_cadence("0 9 * * 5-2", "en").is_err());
+        assert!(parse_cadence("0 9 * * */0", "en").is_err());
+    }
+}
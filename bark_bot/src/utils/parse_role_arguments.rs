@@ -0,0 +1,41 @@
+use crate::collections::Role;
+use crate::utils::translate;
+use teloxide::types::{Message, UserId};
+
+/// Reads "<user id> <role>", or just "<role>" when replying to the user's message. Errors
+/// are in the language.
+pub fn parse_role_arguments(
+    msg: &Message,
+    args: &str,
+    language: &str,
+) -> Result<(UserId, Role), String> {
+    let parts: Vec<&str> = args.split_whitespace().collect();
+
+    let (user_id, role) = match (parts.as_slice(), msg.reply_to_message()) {
+        ([role], Some(reply)) => match &reply.from {
+            Some(user) => (user.id, *role),
+            None => return Err(translate(language, "role-unknown-sender", &[])),
+        },
+        ([user_id, role], _) => match user_id.parse::<u64>() {
+            Ok(user_id) => (UserId(user_id), *role),
+            Err(_) => {
+                return Err(translate(
+                    language,
+                    "role-invalid-user",
+                    &[("user_id", user_id.to_string().into())],
+                ))
+            }
+        },
+        _ => return Err(translate(language, "role-missing-arguments", &[])),
+    };
+
+    let role = role.parse::<Role>().map_err(|_| {
+        translate(
+            language,
+            "role-unknown",
+            &[("role", role.trim().to_string().into())],
+        )
+    })?;
+
+    Ok((user_id, role))
+}
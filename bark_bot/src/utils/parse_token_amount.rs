@@ -0,0 +1,61 @@
+/// Converts a decimal amount like "1.5" to base units without going through floats
+pub fn parse_token_amount(amount: &str, decimals: u8) -> Option<u64> {
+    let (integer, fraction) = amount.trim().split_once('.').unwrap_or((amount.trim(), ""));
+
+    if fraction.len() > usize::from(decimals)
+        || (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let digits = format!(
+        "{}{:0<width$}",
+        integer,
+        fraction,
+        width = usize::from(decimals)
+    );
+
+    digits.parse::<u64>().ok().filter(|amount| *amount > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scales_to_base_units() {
+        assert_eq!(parse_token_amount("1.5", 6), Some(1_500_000));
+        assert_eq!(parse_token_amount(" 2 ", 9), Some(2_000_000_000));
+        assert_eq!(parse_token_amount(".25", 2), Some(25));
+        assert_eq!(parse_token_amount("7", 0), Some(7));
+    }
+
+    #[test]
+    fn refuses_more_decimals_than_the_mint() {
+        assert_eq!(parse_token_amount("1.123", 2), None);
+        assert_eq!(parse_token_amount("0.5", 0), None);
+    }
+
+    #[test]
+    fn refuses_amounts_past_u64() {
+        assert_eq!(
+            parse_token_amount("18446744073709551615", 0),
+            Some(u64::MAX)
+        );
+        assert_eq!(parse_token_amount("18446744073709551616", 0), None);
+        // Small amounts overflow once scaled by the decimals
+        assert_eq!(parse_token_amount("18446744074", 9), None);
+        assert_eq!(parse_token_amount("1", 20), None);
+    }
+
+    #[test]
+    fn refuses_malformed_and_zero_amounts() {
+        for amount in ["", ".", "-1", "1e3", "1,5", "1.2.3", "0", "0.000"] {
+            assert_eq!(parse_token_amount(amount, 6), None, "{}", amount);
+        }
+    }
+}
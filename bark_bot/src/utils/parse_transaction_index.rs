@@ -0,0 +1,8 @@
+/// Accepts the index as shown on proposals, e.g. `12`, `#12` or `Nº12`
+pub fn parse_transaction_index(args: &str) -> Option<u32> {
+    args.trim()
+        .trim_start_matches("Nº")
+        .trim_start_matches('#')
+        .parse()
+        .ok()
+}
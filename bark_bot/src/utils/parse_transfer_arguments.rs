@@ -0,0 +1,73 @@
+use crate::collections::TransferArguments;
+use crate::requests::{get_address_book, get_mint_info};
+use crate::utils::{get_group_chat_id, parse_token_amount, translate, SOL_DECIMALS};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+/// Parses `<amount> <SOL or mint> <recipient address or label>`, looking up the mint and
+/// the address book. Errors are in the language.
+pub async fn parse_transfer_arguments(
+    args: &str,
+    language: &str,
+) -> Result<TransferArguments, String> {
+    let mut parts = args.split_whitespace();
+    let (Some(amount), Some(token), recipient) = (
+        parts.next(),
+        parts.next(),
+        parts.collect::<Vec<&str>>().join(" "),
+    ) else {
+        return Err(translate(language, "transfer-missing-arguments", &[]));
+    };
+
+    let mint = if token.eq_ignore_ascii_case("SOL") {
+        None
+    } else {
+        let not_a_mint = || {
+            translate(
+                language,
+                "transfer-not-a-mint",
+                &[("token", token.to_string().into())],
+            )
+        };
+        let mint = Pubkey::from_str(token).map_err(|_| not_a_mint())?;
+        let mint_info = get_mint_info(mint).await.ok_or_else(not_a_mint)?;
+
+        Some((mint, mint_info))
+    };
+
+    let decimals = mint
+        .as_ref()
+        .map_or(SOL_DECIMALS, |(_, mint_info)| mint_info.decimals);
+    let base_amount = parse_token_amount(amount, decimals).ok_or_else(|| {
+        translate(
+            language,
+            "transfer-invalid-amount",
+            &[
+                ("amount", amount.to_string().into()),
+                ("decimals", decimals.into()),
+            ],
+        )
+    })?;
+
+    // Labels from the address book work as recipients too
+    let address_book = get_address_book(get_group_chat_id()).await;
+    let entry = address_book
+        .iter()
+        .find(|entry| entry.label.eq_ignore_ascii_case(&recipient) || entry.address == recipient);
+    let address = entry.map_or(recipient.as_str(), |entry| entry.address.as_str());
+    let recipient = Pubkey::from_str(address).map_err(|_| {
+        translate(
+            language,
+            "transfer-unknown-recipient",
+            &[("recipient", recipient.clone().into())],
+        )
+    })?;
+
+    Ok(TransferArguments {
+        recipient,
+        label: entry.map(|entry| entry.label.clone()),
+        amount: amount.to_string(),
+        base_amount,
+        mint,
+    })
+}
@@ -0,0 +1,31 @@
+use crate::utils::MAX_ICON_CACHE_SIZE;
+use std::{path::Path, time::SystemTime};
+use tokio::fs;
+
+/// Removes the oldest downloaded icons until the cache fits in `MAX_ICON_CACHE_SIZE`
+pub async fn prune_icon_cache(cache_dir: &Path) -> std::io::Result<()> {
+    let mut entries = fs::read_dir(cache_dir).await?;
+    let mut files: Vec<(SystemTime, u64, std::path::PathBuf)> = vec![];
+
+    while let Some(entry) = entries.next_entry().await? {
+        let metadata = entry.metadata().await?;
+
+        if metadata.is_file() {
+            files.push((metadata.modified()?, metadata.len(), entry.path()));
+        }
+    }
+
+    // Newest first, everything past the budget goes
+    files.sort_by_key(|(modified, _, _)| std::cmp::Reverse(*modified));
+
+    let mut size = 0;
+    for (_, length, path) in files {
+        size += length;
+
+        if size > MAX_ICON_CACHE_SIZE {
+            fs::remove_file(path).await?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use crate::collections::Template;
+use crate::utils::{get_templates_dir, translate};
+use fluent_bundle::FluentValue;
+use handlebars::{
+    Context, Handlebars, Helper, HelperResult, Output, RenderContext, RenderErrorReason,
+};
+use serde_json::Value;
+use teloxide::{types::ChatId, utils::html};
+
+/// Message text from the chat's own template, the operator's shared one or the built-in
+/// one, with every value escaped unless the template asks for raw HTML.
+/// `{{t "key" name=value}}` inserts a message of the catalog in the language.
+pub fn render_template(
+    template: Template,
+    chat_id: ChatId,
+    language: &str,
+    data: &Value,
+) -> String {
+    let file_name = format!("{}.hbs", template.as_str());
+    let overrides = get_templates_dir()
+        .map(|dir| {
+            vec![
+                dir.join(chat_id.to_string()).join(&file_name),
+                dir.join(&file_name),
+            ]
+        })
+        .unwrap_or_default();
+
+    if let Some(path) = overrides.iter().find(|path| path.is_file()) {
+        let rendered = std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|source| render(&source, language, data));
+
+        match rendered {
+            Ok(text) => return text,
+            Err(e) => log::warn!(
+                "Template {} failed, using the default: {}",
+                path.display(),
+                e
+            ),
+        }
+    }
+
+    render(template.default_source(), language, data).unwrap()
+}
+
+fn render(source: &str, language: &str, data: &Value) -> Result<String, String> {
+    let mut handlebars = Handlebars::new();
+    handlebars.register_escape_fn(escape);
+    handlebars.register_helper(
+        "t",
+        Box::new(
+            |helper: &Helper,
+             _: &Handlebars,
+             _: &Context,
+             _: &mut RenderContext,
+             out: &mut dyn Output|
+             -> HelperResult {
+                let key = helper
+                    .param(0)
+                    .and_then(|param| param.value().as_str())
+                    .ok_or(RenderErrorReason::ParamNotFoundForIndex("t", 0))?;
+                let args: Vec<(&str, FluentValue)> = helper
+                    .hash()
+                    .iter()
+                    .map(|(name, value)| {
+                        let value = match value.value() {
+                            Value::Number(number) => FluentValue::from(number.as_f64()),
+                            Value::String(text) => FluentValue::from(escape(text)),
+                            other => FluentValue::from(escape(&other.to_string())),
+                        };
+
+                        (*name, value)
+                    })
+                    .collect();
+
+                out.write(&translate(language, key, &args))?;
+                Ok(())
+            },
+        ),
+    );
+
+    handlebars
+        .render_template(source, data)
+        .map(|text| text.trim().to_string())
+        .map_err(|e| e.to_string())
+}
+
+// Telegram's HTML has no other special characters, quotes matter in attributes
+fn escape(value: &str) -> String {
+    html::escape(value).replace('"', "&quot;")
+}
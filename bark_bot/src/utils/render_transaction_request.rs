@@ -0,0 +1,403 @@
+use crate::collections::{
+    ProposalDetails, RequestProgress, Template, Transaction, TransactionEvent, UserProfile,
+};
+use crate::utils::{
+    find_user_name, format_localized_amount, format_policy_violations, format_pubkey, format_token,
+    get_explorer_url, get_group_chat_id, get_transaction_message_url, get_transaction_state,
+    render_template, translate,
+};
+use chrono::DateTime;
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+use squads_mpl::state::{MsTransaction, MsTransactionStatus};
+use std::str::FromStr;
+use teloxide::utils::html;
+
+/// Group message of a proposal, rendered again from scratch on every state change
+pub fn render_transaction_request(
+    transaction_entry: &Transaction,
+    threshold: u16,
+    transaction_account: &MsTransaction,
+    users: &[UserProfile],
+    events: &[TransactionEvent],
+    details: &ProposalDetails,
+    progress: &RequestProgress,
+) -> String {
+    let language = details.language;
+    let link = |kind: &str, value: &str, label: &str| {
+        format!(
+            "<a href=\"{}\">{}</a>",
+            get_explorer_url(kind, value),
+            html::escape(label)
+        )
+    };
+    let name_of = |user_id: &str| {
+        users
+            .iter()
+            .find(|user| user.user_id == user_id)
+            .map(|user| html::escape(&user.name))
+            .unwrap_or_else(|| translate(language, "proposal-user", &[("user_id", user_id.into())]))
+    };
+    let voters = |keys: &[Pubkey]| {
+        if keys.is_empty() {
+            return "—".to_string();
+        }
+
+        keys.iter()
+            .map(|key| {
+                format!(
+                    "• {} {}",
+                    html::escape(&find_user_name(users, key)),
+                    link("address", &key.to_string(), &format_pubkey(key))
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    };
+
+    let mut batch = String::new();
+    if let Some(position) = details
+        .batch
+        .iter()
+        .position(|transaction| transaction.id == transaction_entry.id)
+    {
+        let proposals: Vec<String> = details
+            .batch
+            .iter()
+            .map(|transaction| {
+                let label = format!("Nº{}", transaction.transaction_index);
+
+                match get_transaction_message_url(transaction) {
+                    _ if transaction.id == transaction_entry.id => format!("<b>{}</b>", label),
+                    Some(url) => format!("<a href=\"{}\">{}</a>", url, label),
+                    None => label,
+                }
+            })
+            .collect();
+
+        batch = translate(
+            language,
+            "proposal-batch",
+            &[
+                ("position", (position + 1).into()),
+                ("total", details.batch.len().into()),
+                ("proposals", proposals.join(" · ").into()),
+            ],
+        );
+    }
+
+    let parameters: Vec<(String, String)> = transaction_entry
+        .parameters
+        .as_deref()
+        .and_then(|parameters| serde_json::from_str(parameters).ok())
+        .unwrap_or_default();
+
+    let mut transfers = String::new();
+    if !details.recipients.is_empty() {
+        let lines: Vec<String> = details
+            .recipients
+            .iter()
+            .map(|recipient| {
+                let address = &recipient.transfer.recipient;
+                let mut line = format!(
+                    "• {} {} → {}",
+                    format_localized_amount(&recipient.transfer.display_amount(), language),
+                    html::escape(&format_token(&recipient.transfer.token)),
+                    match &recipient.label {
+                        Some(label) => format!(
+                            "{} ({})",
+                            html::escape(label),
+                            link("address", address, &short_address(address))
+                        ),
+                        None => link("address", address, &short_address(address)),
+                    }
+                );
+
+                if recipient.first_time {
+                    line.push_str(&format!(
+                        " · {}",
+                        translate(language, "proposal-first-payment", &[])
+                    ));
+                }
+
+                if let Some(entry) = &recipient.lookalike_of {
+                    line.push_str(&format!(
+                        "\n  {}",
+                        translate(
+                            language,
+                            "proposal-lookalike",
+                            &[
+                                ("label", html::escape(&entry.label).into()),
+                                (
+                                    "address",
+                                    link("address", &entry.address, &short_address(&entry.address))
+                                        .into()
+                                ),
+                            ],
+                        )
+                    ));
+                }
+
+                line
+            })
+            .collect();
+
+        transfers = format!(
+            "{}\n{}",
+            translate(language, "proposal-transfers", &[]),
+            lines.join("\n")
+        );
+    }
+
+    let mut status = format!(
+        "{}\n{}",
+        translate(
+            language,
+            "proposal-proposed-by",
+            &[
+                ("name", name_of(&transaction_entry.user_id).into()),
+                (
+                    "link",
+                    link(
+                        "tx",
+                        &transaction_entry.signature,
+                        &translate(language, "proposal-creation-tx", &[])
+                    )
+                    .into()
+                ),
+            ],
+        ),
+        translate(
+            language,
+            "proposal-status",
+            &[(
+                "state",
+                translate(
+                    language,
+                    &format!(
+                        "state-{}",
+                        get_transaction_state(&transaction_account.status)
+                    ),
+                    &[]
+                )
+                .into()
+            )],
+        )
+    );
+
+    if transaction_entry.expired_at.is_some()
+        && (transaction_account.status == MsTransactionStatus::Active
+            || transaction_account.status == MsTransactionStatus::ExecuteReady)
+    {
+        status.push_str(&format!(
+            " · {}",
+            translate(language, "proposal-expired", &[])
+        ));
+    }
+
+    if let Some(signature) = events
+        .iter()
+        .rev()
+        .find(|event| event.kind == "execute")
+        .and_then(|event| event.signature.as_ref())
+    {
+        status.push_str(&format!(
+            "\n{}",
+            translate(
+                language,
+                "proposal-executed-in",
+                &[(
+                    "link",
+                    link("tx", signature, &format_signature(signature)).into()
+                )],
+            )
+        ));
+    }
+
+    let votes = format!(
+        "{}\n{}\n{}\n{}",
+        translate(
+            language,
+            "proposal-approved",
+            &[
+                ("count", transaction_account.approved.len().into()),
+                ("threshold", threshold.into()),
+            ],
+        ),
+        voters(&transaction_account.approved),
+        translate(
+            language,
+            "proposal-rejected",
+            &[
+                ("count", transaction_account.rejected.len().into()),
+                ("threshold", threshold.into()),
+            ],
+        ),
+        voters(&transaction_account.rejected)
+    );
+
+    let mut policy_lines: Vec<String> = vec![];
+    if !details.policy.violations.is_empty() {
+        policy_lines.push(format_policy_violations(
+            language,
+            &details.policy.violations,
+        ));
+    }
+    if !details.policy.unchecked_programs.is_empty() {
+        let programs: Vec<String> = details
+            .policy
+            .unchecked_programs
+            .iter()
+            .map(|program| format!("<code>{}</code>", program))
+            .collect();
+
+        policy_lines.push(translate(
+            language,
+            "proposal-policy-unchecked",
+            &[("programs", programs.join(", ").into())],
+        ));
+    }
+
+    let policy = if policy_lines.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "{}\n{}",
+            translate(language, "proposal-policy", &[]),
+            policy_lines.join("\n")
+        )
+    };
+
+    let timeline: Vec<String> = events
+        .iter()
+        .map(|event| {
+            let action = match event.kind.as_str() {
+                kind @ ("create" | "approve" | "reject" | "execute" | "cancel") => {
+                    translate(language, &format!("event-{}", kind), &[])
+                }
+                other => other.to_string(),
+            };
+            let by = event
+                .user_id
+                .as_deref()
+                .map(|user_id| {
+                    format!(
+                        " {}",
+                        translate(
+                            language,
+                            "proposal-by",
+                            &[("name", name_of(user_id).into())]
+                        )
+                    )
+                })
+                .unwrap_or_default();
+            let signature = event
+                .signature
+                .as_deref()
+                .map(|signature| format!(" · {}", link("tx", signature, "tx")))
+                .unwrap_or_default();
+
+            format!(
+                "• {} {}{}{}",
+                format_time(event.created_at, language),
+                action,
+                by,
+                signature
+            )
+        })
+        .chain(transaction_entry.expired_at.map(|expired_at| {
+            format!(
+                "• {} {}",
+                format_time(expired_at, language),
+                translate(language, "event-expired", &[])
+            )
+        }))
+        .collect();
+
+    let timeline = if timeline.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "{}\n{}",
+            translate(language, "proposal-timeline", &[]),
+            timeline.join("\n")
+        )
+    };
+
+    let progress = format_progress(progress, language);
+
+    render_template(
+        Template::Proposal,
+        get_group_chat_id(),
+        language,
+        &json!({
+            "index": transaction_entry.transaction_index,
+            "title": transaction_entry
+                .title
+                .clone()
+                .unwrap_or_else(|| translate(language, "proposal-untitled", &[])),
+            "description": transaction_entry.description,
+            "parameters": parameters
+                .iter()
+                .map(|(label, value)| json!({ "label": label, "value": value }))
+                .collect::<Vec<_>>(),
+            "batch": batch,
+            "transfers": transfers,
+            "status": status,
+            "votes": votes,
+            "policy": policy,
+            "timeline": timeline,
+            "progress": progress,
+        }),
+    )
+}
+
+/// The label carries the voter's name and the error may echo back RPC output, neither is
+/// trusted HTML
+fn format_progress(progress: &RequestProgress, language: &str) -> String {
+    match progress {
+        RequestProgress::Idle => String::new(),
+        RequestProgress::InFlight(label) => format!("⏳ <i>{}</i>", html::escape(label)),
+        RequestProgress::Failed(error) => translate(
+            language,
+            "proposal-failed",
+            &[("error", html::escape(error).into())],
+        ),
+    }
+}
+
+fn format_time(timestamp: i64, language: &str) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|time| {
+            time.format(&translate(language, "date-time-format", &[]))
+                .to_string()
+        })
+        .unwrap_or_default()
+}
+
+fn short_address(address: &str) -> String {
+    Pubkey::from_str(address).map_or(address.to_string(), |pubkey| format_pubkey(&pubkey))
+}
+
+fn format_signature(signature: &str) -> String {
+    if signature.len() <= 16 {
+        return signature.to_string();
+    }
+
+    format!("{}…{}", &signature[..8], &signature[signature.len() - 8..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_the_name_in_the_in_flight_label() {
+        let progress = RequestProgress::InFlight("Approving… <b>Eve</b> & co".to_string());
+
+        assert_eq!(
+            format_progress(&progress, "en"),
+            "⏳ <i>Approving… &lt;b&gt;Eve&lt;/b&gt; &amp; co</i>"
+        );
+    }
+}
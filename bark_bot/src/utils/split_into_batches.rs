@@ -0,0 +1,183 @@
+use solana_sdk::{
+    instruction::Instruction, message::Message, packet::PACKET_DATA_SIZE, pubkey::Pubkey,
+    signer::Signer, transaction::Transaction,
+};
+use std::collections::HashSet;
+use teloxide::types::UserId;
+
+use crate::utils::get_user_keypair;
+
+/// Groups consecutive instruction groups (one per payout row) into as few proposals as
+/// possible, so that both the creation and the execution transactions fit in a packet.
+/// Returns the indexes of the groups of each proposal.
+pub fn split_into_batches(
+    multisig_pubkey: Pubkey,
+    user_id: UserId,
+    groups: &[Vec<Instruction>],
+) -> Vec<Vec<usize>> {
+    let mut batches: Vec<Vec<usize>> = vec![];
+    let mut current: Vec<usize> = vec![];
+
+    for index in 0..groups.len() {
+        let candidate: Vec<&Instruction> = current
+            .iter()
+            .chain([&index])
+            .flat_map(|index| &groups[*index])
+            .collect();
+
+        if !current.is_empty()
+            && (get_creation_size(multisig_pubkey, user_id, &candidate) > PACKET_DATA_SIZE
+                || get_execution_size(&candidate) > PACKET_DATA_SIZE)
+        {
+            batches.push(current);
+            current = vec![];
+        }
+
+        current.push(index);
+    }
+
+    if !current.is_empty() {
+        batches.push(current);
+    }
+
+    batches
+}
+
+fn get_creation_size(multisig_pubkey: Pubkey, user_id: UserId, proposed: &[&Instruction]) -> usize {
+    // The index only changes the derived addresses, not the size
+    let transaction_index = 1;
+    let mut instructions = vec![crate::instructions::create_transaction(
+        multisig_pubkey,
+        transaction_index,
+        user_id,
+    )];
+
+    for (index, instruction) in proposed.iter().enumerate() {
+        instructions.push(crate::instructions::add_instruction(
+            multisig_pubkey,
+            transaction_index,
+            (index + 1).try_into().unwrap_or(u8::MAX),
+            (*instruction).clone(),
+            user_id,
+        ));
+    }
+
+    instructions.push(crate::instructions::activate_transaction(
+        multisig_pubkey,
+        transaction_index,
+        user_id,
+    ));
+    instructions.push(crate::instructions::approve_transaction(
+        multisig_pubkey,
+        transaction_index,
+        user_id,
+    ));
+
+    let creator_pubkey = get_user_keypair(user_id).pubkey();
+    let message = Message::new(&instructions, Some(&creator_pubkey));
+
+    bincode::serialized_size(&Transaction::new_unsigned(message)).unwrap() as usize
+}
+
+/// Execution passes every account of every instruction to Squads in a single transaction,
+/// the same way `instructions::execute_transaction` builds it
+fn get_execution_size(proposed: &[&Instruction]) -> usize {
+    // Signature, header, blockhash, the member, multisig, transaction and Squads program
+    // accounts, and the fixed part of the execute instruction
+    const FIXED_SIZE: usize = 1 + 64 + 3 + 1 + 32 + 4 * 32 + 1 + 1 + 2 + 3 + 2 + 8 + 4;
+
+    let mut unique_accounts: HashSet<Pubkey> = HashSet::new();
+    let mut account_list = 0;
+
+    for instruction in proposed {
+        unique_accounts.insert(instruction.program_id);
+        unique_accounts.extend(instruction.accounts.iter().map(|meta| meta.pubkey));
+        account_list += 2 + instruction.accounts.len();
+    }
+
+    // Each instruction also passes its own Squads account. Every unique account is a key
+    // in the message and an index in the instruction.
+    let accounts = unique_accounts.len() + proposed.len();
+
+    FIXED_SIZE + accounts * 33 + account_list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::lock_env;
+    use solana_sdk::{instruction::AccountMeta, signature::Keypair, system_instruction};
+    use std::{env, sync::MutexGuard};
+
+    const USER_ID: UserId = UserId(1);
+
+    fn set_env() -> MutexGuard<'static, ()> {
+        let guard = lock_env();
+        let keypair = Keypair::new().to_base58_string();
+        env::set_var("WHITELIST", format!(r#"[["1", "{}"]]"#, keypair));
+
+        guard
+    }
+
+    fn transfer() -> Vec<Instruction> {
+        vec![system_instruction::transfer(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            1,
+        )]
+    }
+
+    fn fits(multisig_pubkey: Pubkey, groups: &[Vec<Instruction>], batch: &[usize]) -> bool {
+        let instructions: Vec<&Instruction> =
+            batch.iter().flat_map(|index| &groups[*index]).collect();
+
+        get_creation_size(multisig_pubkey, USER_ID, &instructions) <= PACKET_DATA_SIZE
+            && get_execution_size(&instructions) <= PACKET_DATA_SIZE
+    }
+
+    #[test]
+    fn keeps_small_payouts_in_one_proposal() {
+        let _env = set_env();
+        let multisig_pubkey = Pubkey::new_unique();
+        let groups = vec![transfer(), transfer()];
+
+        assert_eq!(
+            split_into_batches(multisig_pubkey, USER_ID, &groups),
+            vec![vec![0, 1]]
+        );
+    }
+
+    #[test]
+    fn splits_large_payouts_into_batches_that_fit() {
+        let _env = set_env();
+        let multisig_pubkey = Pubkey::new_unique();
+        let groups: Vec<Vec<Instruction>> = (0..40).map(|_| transfer()).collect();
+
+        let batches = split_into_batches(multisig_pubkey, USER_ID, &groups);
+
+        assert!(batches.len() > 1);
+        assert_eq!(batches.concat(), (0..groups.len()).collect::<Vec<usize>>());
+        assert!(batches
+            .iter()
+            .all(|batch| fits(multisig_pubkey, &groups, batch)));
+    }
+
+    #[test]
+    fn isolates_a_row_too_large_for_any_batch() {
+        let _env = set_env();
+        let multisig_pubkey = Pubkey::new_unique();
+        let oversized = vec![Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: (0..40)
+                .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+                .collect(),
+            data: vec![],
+        }];
+        let groups = vec![transfer(), oversized, transfer()];
+
+        let batches = split_into_batches(multisig_pubkey, USER_ID, &groups);
+
+        assert_eq!(batches, vec![vec![0], vec![1], vec![2]]);
+        assert!(!fits(multisig_pubkey, &groups, &batches[1]));
+    }
+}
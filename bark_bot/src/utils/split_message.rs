@@ -0,0 +1,24 @@
+use crate::utils::MAX_MESSAGE_LENGTH;
+
+/// Joins the blocks of a listing with blank lines, starting a new message whenever the
+/// next block would push the current one past Telegram's length limit. Every message
+/// starts with the header.
+pub fn split_message(header: &str, blocks: &[String]) -> Vec<String> {
+    let mut messages: Vec<String> = vec![];
+    let mut current = header.to_string();
+
+    for block in blocks {
+        if current.len() > header.len()
+            && current.chars().count() + 2 + block.chars().count() > MAX_MESSAGE_LENGTH
+        {
+            messages.push(current);
+            current = header.to_string();
+        }
+
+        current.push_str("\n\n");
+        current.push_str(block);
+    }
+
+    messages.push(current);
+    messages
+}
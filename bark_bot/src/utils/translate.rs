@@ -0,0 +1,7 @@
+use crate::utils::get_catalog;
+use fluent_bundle::FluentValue;
+
+/// Message `key` of the catalog in the language, HTML in the arguments isn't escaped
+pub fn translate(language: &str, key: &str, args: &[(&str, FluentValue)]) -> String {
+    get_catalog().translate(language, key, args)
+}
@@ -1,14 +1,149 @@
 use axum::{
-    extract::{Json, Path, State},
+    extract::{Json, Path, Query, State},
+    http::StatusCode,
     response::{Json as AxumJson, Result as AxumResult},
-    routing::{get, patch, post},
+    routing::{delete, get, patch, post, put},
     Router,
 };
-use rusqlite::{Connection, OptionalExtension};
+use rusqlite::{params_from_iter, types::Value, Connection, OptionalExtension, Row};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::sync::Mutex;
 
+const TRANSACTION_COLUMNS: &str = "id, transaction_index, user_id, signature, status, message_id, \
+    created_at, state, reminder_count, last_reminder_at, expired_at, title, description, parameters, \
+    batch_id, icon_url";
+
+const EVENT_COLUMNS: &str = "id, transaction_id, kind, user_id, signature, created_at";
+
+// The linked wallet comes from wallet_links, the users row keeps the bot's signer key
+const USER_COLUMNS: &str = "users.user_id, users.name, users.pubkey, users.updated_at, \
+    wallet_links.pubkey";
+
+const SCHEDULE_COLUMNS: &str = "id, chat_id, user_id, cadence, action, title, next_run_at, \
+    ends_at, paused, last_run_at, last_transaction_id, created_at";
+
+/// Schema changes applied on top of the initial `entries` table, tracked through
+/// `PRAGMA user_version`. Append new migrations, never edit existing ones.
+const MIGRATIONS: &[&str] = &[
+    "ALTER TABLE entries ADD COLUMN created_at INTEGER NOT NULL DEFAULT 0;
+     ALTER TABLE entries ADD COLUMN state TEXT NOT NULL DEFAULT 'active';
+     ALTER TABLE entries ADD COLUMN reminder_count INTEGER NOT NULL DEFAULT 0;
+     ALTER TABLE entries ADD COLUMN last_reminder_at INTEGER;
+     ALTER TABLE entries ADD COLUMN expired_at INTEGER;
+     UPDATE entries SET created_at = strftime('%s', 'now') WHERE created_at = 0;",
+    "ALTER TABLE entries ADD COLUMN title TEXT;",
+    "ALTER TABLE entries ADD COLUMN description TEXT;
+     ALTER TABLE entries ADD COLUMN parameters TEXT;
+     CREATE TABLE users (
+         user_id                 TEXT PRIMARY KEY,
+         name                    TEXT NOT NULL,
+         pubkey                  TEXT,
+         updated_at              INTEGER NOT NULL
+     );
+     CREATE TABLE events (
+         id                      INTEGER PRIMARY KEY,
+         transaction_id          INTEGER NOT NULL,
+         kind                    TEXT NOT NULL,
+         user_id                 TEXT,
+         signature               TEXT,
+         created_at              INTEGER NOT NULL
+     );
+     CREATE INDEX events_transaction_id ON events (transaction_id);
+     INSERT INTO events (transaction_id, kind, user_id, signature, created_at)
+         SELECT id, 'create', user_id, signature, created_at FROM entries;",
+    "CREATE TABLE roles (
+         chat_id                 TEXT NOT NULL,
+         user_id                 TEXT NOT NULL,
+         role                    TEXT NOT NULL,
+         granted_by              TEXT,
+         created_at              INTEGER NOT NULL,
+         PRIMARY KEY (chat_id, user_id, role)
+     );",
+    "CREATE TABLE wallet_links (
+         user_id                 TEXT PRIMARY KEY,
+         pubkey                  TEXT NOT NULL UNIQUE,
+         signature               TEXT NOT NULL,
+         linked_at               INTEGER NOT NULL
+     );",
+    "CREATE TABLE transfers (
+         id                      INTEGER PRIMARY KEY,
+         transaction_id          INTEGER NOT NULL,
+         token                   TEXT NOT NULL,
+         recipient               TEXT NOT NULL,
+         amount                  REAL NOT NULL,
+         created_at              INTEGER NOT NULL
+     );
+     CREATE INDEX transfers_token ON transfers (token);
+     CREATE INDEX transfers_recipient ON transfers (recipient);",
+    "CREATE TABLE addresses (
+         chat_id                 TEXT NOT NULL,
+         address                 TEXT NOT NULL,
+         label                   TEXT NOT NULL,
+         added_by                TEXT,
+         created_at              INTEGER NOT NULL,
+         PRIMARY KEY (chat_id, address)
+     );",
+    "ALTER TABLE entries ADD COLUMN batch_id TEXT;
+     CREATE INDEX entries_batch_id ON entries (batch_id);",
+    "CREATE TABLE schedules (
+         id                      INTEGER PRIMARY KEY,
+         chat_id                 TEXT NOT NULL,
+         user_id                 TEXT NOT NULL,
+         cadence                 TEXT NOT NULL,
+         action                  TEXT NOT NULL,
+         title                   TEXT NOT NULL,
+         next_run_at             INTEGER,
+         ends_at                 INTEGER,
+         paused                  INTEGER NOT NULL DEFAULT 0,
+         last_run_at             INTEGER,
+         last_transaction_id     INTEGER,
+         created_at              INTEGER NOT NULL
+     );
+     CREATE INDEX schedules_next_run_at ON schedules (next_run_at);",
+    "CREATE TABLE shared_actions (
+         id                      INTEGER PRIMARY KEY,
+         url                     TEXT NOT NULL,
+         action                  TEXT NOT NULL,
+         user_id                 TEXT NOT NULL,
+         created_at              INTEGER NOT NULL,
+         UNIQUE (url, action)
+     );",
+    "CREATE TABLE shared_actions_new (
+         id                      INTEGER PRIMARY KEY,
+         url                     TEXT NOT NULL,
+         action                  TEXT NOT NULL,
+         parameters              TEXT NOT NULL DEFAULT '[]',
+         user_id                 TEXT NOT NULL,
+         created_at              INTEGER NOT NULL,
+         UNIQUE (url, action, parameters)
+     );
+     INSERT INTO shared_actions_new (id, url, action, user_id, created_at)
+         SELECT id, url, action, user_id, created_at FROM shared_actions;
+     DROP TABLE shared_actions;
+     ALTER TABLE shared_actions_new RENAME TO shared_actions;",
+    "ALTER TABLE entries ADD COLUMN icon_url TEXT;",
+    "CREATE TABLE chat_languages (
+         chat_id                 TEXT PRIMARY KEY,
+         language                TEXT NOT NULL,
+         updated_by              TEXT,
+         updated_at              INTEGER NOT NULL
+     );",
+    "CREATE TABLE leases (
+         name                    TEXT PRIMARY KEY,
+         holder                  TEXT NOT NULL,
+         expires_at              INTEGER NOT NULL
+     );",
+    "UPDATE users SET pubkey = NULL WHERE pubkey IN (SELECT pubkey FROM wallet_links);",
+    "ALTER TABLE transfers ADD COLUMN base_amount INTEGER;
+     ALTER TABLE transfers ADD COLUMN decimals INTEGER;
+     UPDATE transfers SET base_amount = CAST(ROUND(amount * 1000000000) AS INTEGER), decimals = 9
+         WHERE token = 'SOL';",
+];
+
+const PAGE_LIMIT: i64 = 50;
+
 #[derive(Debug, Serialize, Deserialize)]
 struct Transaction {
     id: i64,
@@ -17,6 +152,22 @@ struct Transaction {
     signature: String,
     status: u8,
     message_id: Option<String>,
+    created_at: i64,
+    state: String,
+    reminder_count: u32,
+    last_reminder_at: Option<i64>,
+    expired_at: Option<i64>,
+    title: Option<String>,
+    description: Option<String>,
+    parameters: Option<String>, // JSON list of [label, value] pairs
+    batch_id: Option<String>,   // Shared by the proposals of a split payout
+    icon_url: Option<String>,   // Blink icon shown above the proposal message
+}
+
+#[derive(Serialize)]
+struct TransactionPage {
+    transactions: Vec<Transaction>,
+    total: i64,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -24,6 +175,26 @@ struct CreateTransactionBody {
     transaction_index: i32,
     user_id: String,
     signature: String,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    parameters: Option<String>,
+    #[serde(default)]
+    batch_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GetTransactionsQuery {
+    state: Option<String>, // Comma separated list of states
+    user_id: Option<String>,
+    batch_id: Option<String>,
+    transaction_index: Option<i64>,
+    since: Option<i64>,
+    until: Option<i64>,
+    limit: Option<i64>,
+    offset: Option<i64>,
 }
 
 #[derive(Deserialize)]
@@ -31,6 +202,229 @@ struct UpdateTransactionBody {
     message_id: String,
 }
 
+#[derive(Deserialize)]
+struct UpdateTransactionStateBody {
+    state: String,
+}
+
+#[derive(Deserialize)]
+struct UpdateTransactionIconBody {
+    icon_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct User {
+    user_id: String,
+    name: String,
+    pubkey: Option<String>, // Signer key the bot holds for the user
+    updated_at: i64,
+    wallet: Option<String>, // Linked with /link, from wallet_links
+}
+
+#[derive(Deserialize)]
+struct UpsertUserBody {
+    name: String,
+    pubkey: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TransactionEvent {
+    id: i64,
+    transaction_id: i64,
+    kind: String, // create, approve, reject, execute or cancel
+    user_id: Option<String>,
+    signature: Option<String>,
+    created_at: i64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Transfer {
+    id: i64,
+    transaction_id: i64,
+    token: String, // SOL or the mint address
+    recipient: String,
+    amount: f64,   // In token units, already scaled by the decimals
+    base_amount: Option<i64>, // Exact, None on token transfers recorded before it was kept
+    decimals: Option<i64>,
+    created_at: i64,
+    executed_at: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct CreateTransferBody {
+    token: String,
+    recipient: String,
+    base_amount: u64,
+    decimals: u8,
+}
+
+#[derive(Deserialize)]
+struct GetTransfersQuery {
+    transaction_id: Option<i64>,
+    token: Option<String>,
+    recipient: Option<String>,
+    since: Option<i64>,  // Only transfers executed at or after this time
+    executed: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct AddressBookEntry {
+    chat_id: String,
+    address: String,
+    label: String,
+    added_by: Option<String>,
+    created_at: i64,
+}
+
+#[derive(Deserialize)]
+struct SaveAddressBody {
+    label: String,
+    added_by: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Schedule {
+    id: i64,
+    chat_id: String,
+    user_id: String, // Creator, the proposals are made on their behalf
+    cadence: String, // Cron expression
+    action: String,  // JSON of the transfer or blink to propose
+    title: String,
+    next_run_at: Option<i64>, // None once the schedule has ended
+    ends_at: Option<i64>,
+    paused: bool,
+    last_run_at: Option<i64>,
+    last_transaction_id: Option<i64>,
+    created_at: i64,
+}
+
+/// Blink shared through inline mode, the id goes in the `/start` deep link
+#[derive(Serialize, Deserialize)]
+struct SharedAction {
+    id: i64,
+    url: String,
+    action: String,     // Label of the chosen action, empty for the whole Blink
+    parameters: String, // JSON list of [name, value] pairs filled in beforehand
+    user_id: String,
+    created_at: i64,
+}
+
+#[derive(Deserialize)]
+struct CreateSharedActionBody {
+    url: String,
+    action: String,
+    #[serde(default = "default_shared_parameters")]
+    parameters: String,
+    user_id: String,
+}
+
+fn default_shared_parameters() -> String {
+    "[]".to_string()
+}
+
+#[derive(Deserialize)]
+struct CreateScheduleBody {
+    chat_id: String,
+    user_id: String,
+    cadence: String,
+    action: String,
+    title: String,
+    next_run_at: i64,
+    ends_at: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct GetSchedulesQuery {
+    chat_id: Option<String>,
+    due_before: Option<i64>, // Only active schedules due at or before this time
+}
+
+#[derive(Deserialize)]
+struct UpdateScheduleBody {
+    paused: bool,
+    next_run_at: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct CreateScheduleRunBody {
+    transaction_id: Option<i64>,
+    next_run_at: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WalletLink {
+    user_id: String,
+    pubkey: String,
+    signature: String, // Signature of the link message, kept as proof
+    linked_at: i64,
+}
+
+#[derive(Deserialize)]
+struct LinkWalletBody {
+    pubkey: String,
+    signature: String,
+}
+
+/// Language chosen for a chat, overriding the one of each member's Telegram app
+#[derive(Serialize, Deserialize)]
+struct ChatLanguage {
+    chat_id: String,
+    language: String,
+    updated_by: Option<String>,
+    updated_at: i64,
+}
+
+#[derive(Deserialize)]
+struct SetChatLanguageBody {
+    language: String,
+    updated_by: Option<String>,
+}
+
+/// Claim of a bot replica on a job or a lock, lapses at `expires_at` unless renewed
+#[derive(Serialize, Deserialize)]
+struct Lease {
+    name: String,
+    holder: String,
+    expires_at: i64,
+}
+
+#[derive(Deserialize)]
+struct ClaimLeaseBody {
+    holder: String,
+    ttl: i64, // Seconds
+}
+
+#[derive(Deserialize)]
+struct ReleaseLeaseQuery {
+    holder: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RoleGrant {
+    chat_id: String,
+    user_id: String,
+    role: String, // proposer, voter, executor, admin or viewer
+    granted_by: Option<String>,
+    created_at: i64,
+}
+
+#[derive(Deserialize)]
+struct GetRolesQuery {
+    user_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GrantRoleBody {
+    granted_by: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CreateTransactionEventBody {
+    kind: String,
+    user_id: Option<String>,
+    signature: Option<String>,
+}
+
 struct AppState {
     conn: Mutex<Connection>,
 }
@@ -54,14 +448,70 @@ async fn main() {
         (),
     ).unwrap_or_else(|_| panic!("Failed to create or open table"));
 
+    migrate(&conn);
+
     let app_state = Arc::new(AppState {
         conn: Mutex::new(conn),
     });
 
     let app = Router::new()
         .route("/transactions", post(create_transaction))
+        .route("/transactions", get(get_transactions))
         .route("/transactions/:transaction_id", get(get_transaction))
         .route("/transactions/:transaction_id", patch(update_transaction))
+        .route("/transactions/open", get(get_open_transactions))
+        .route(
+            "/transactions/:transaction_id/state",
+            patch(update_transaction_state),
+        )
+        .route(
+            "/transactions/:transaction_id/icon",
+            patch(update_transaction_icon),
+        )
+        .route(
+            "/transactions/:transaction_id/reminders",
+            post(create_transaction_reminder),
+        )
+        .route(
+            "/transactions/:transaction_id/expire",
+            post(expire_transaction),
+        )
+        .route(
+            "/transactions/:transaction_id/events",
+            get(get_transaction_events),
+        )
+        .route(
+            "/transactions/:transaction_id/events",
+            post(create_transaction_event),
+        )
+        .route(
+            "/transactions/:transaction_id/transfers",
+            post(create_transfers),
+        )
+        .route("/transfers", get(get_transfers))
+        .route("/users", get(get_users))
+        .route("/users/:user_id", put(upsert_user))
+        .route("/users/:user_id/wallet", get(get_wallet_link))
+        .route("/users/:user_id/wallet", put(link_wallet))
+        .route("/groups/:chat_id/roles", get(get_roles))
+        .route("/groups/:chat_id/roles/:user_id/:role", put(grant_role))
+        .route("/groups/:chat_id/roles/:user_id/:role", delete(revoke_role))
+        .route("/groups/:chat_id/addresses", get(get_addresses))
+        .route("/groups/:chat_id/addresses/:address", put(save_address))
+        .route("/groups/:chat_id/addresses/:address", delete(remove_address))
+        .route("/groups/:chat_id/language", get(get_chat_language))
+        .route("/groups/:chat_id/language", put(set_chat_language))
+        .route("/groups/:chat_id/language", delete(reset_chat_language))
+        .route("/leases/:name", put(claim_lease))
+        .route("/leases/:name", delete(release_lease))
+        .route("/schedules", get(get_schedules))
+        .route("/schedules", post(create_schedule))
+        .route("/schedules/:schedule_id", get(get_schedule))
+        .route("/schedules/:schedule_id", patch(update_schedule))
+        .route("/schedules/:schedule_id", delete(delete_schedule))
+        .route("/schedules/:schedule_id/runs", post(create_schedule_run))
+        .route("/shared_actions", post(create_shared_action))
+        .route("/shared_actions/:shared_action_id", get(get_shared_action))
         .with_state(app_state);
 
     let listener = tokio::net::TcpListener::bind("127.0.0.1:3000")
@@ -74,24 +524,219 @@ async fn main() {
         .unwrap_or_else(|_| panic!("Server failed to start"));
 }
 
+fn migrate(conn: &Connection) {
+    let version: usize = conn
+        .query_row("PRAGMA user_version", [], |row| row.get(0))
+        .unwrap_or_else(|_| panic!("Failed to read schema version"));
+
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+        conn.execute_batch(migration)
+            .unwrap_or_else(|e| panic!("Failed to apply migration {}: {}", index + 1, e));
+        conn.pragma_update(None, "user_version", index + 1)
+            .unwrap_or_else(|_| panic!("Failed to update schema version"));
+    }
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+fn read_transaction(row: &Row) -> rusqlite::Result<Transaction> {
+    Ok(Transaction {
+        id: row.get(0)?,
+        transaction_index: row.get(1)?,
+        user_id: row.get(2)?,
+        signature: row.get(3)?,
+        status: row.get(4)?,
+        message_id: row.get(5)?,
+        created_at: row.get(6)?,
+        state: row.get(7)?,
+        reminder_count: row.get(8)?,
+        last_reminder_at: row.get(9)?,
+        expired_at: row.get(10)?,
+        title: row.get(11)?,
+        description: row.get(12)?,
+        parameters: row.get(13)?,
+        batch_id: row.get(14)?,
+        icon_url: row.get(15)?,
+    })
+}
+
+fn read_event(row: &Row) -> rusqlite::Result<TransactionEvent> {
+    Ok(TransactionEvent {
+        id: row.get(0)?,
+        transaction_id: row.get(1)?,
+        kind: row.get(2)?,
+        user_id: row.get(3)?,
+        signature: row.get(4)?,
+        created_at: row.get(5)?,
+    })
+}
+
+fn read_user(row: &Row) -> rusqlite::Result<User> {
+    Ok(User {
+        user_id: row.get(0)?,
+        name: row.get(1)?,
+        pubkey: row.get(2)?,
+        updated_at: row.get(3)?,
+        wallet: row.get(4)?,
+    })
+}
+
+fn read_role(row: &Row) -> rusqlite::Result<RoleGrant> {
+    Ok(RoleGrant {
+        chat_id: row.get(0)?,
+        user_id: row.get(1)?,
+        role: row.get(2)?,
+        granted_by: row.get(3)?,
+        created_at: row.get(4)?,
+    })
+}
+
+fn read_address(row: &Row) -> rusqlite::Result<AddressBookEntry> {
+    Ok(AddressBookEntry {
+        chat_id: row.get(0)?,
+        address: row.get(1)?,
+        label: row.get(2)?,
+        added_by: row.get(3)?,
+        created_at: row.get(4)?,
+    })
+}
+
+fn read_schedule(row: &Row) -> rusqlite::Result<Schedule> {
+    Ok(Schedule {
+        id: row.get(0)?,
+        chat_id: row.get(1)?,
+        user_id: row.get(2)?,
+        cadence: row.get(3)?,
+        action: row.get(4)?,
+        title: row.get(5)?,
+        next_run_at: row.get(6)?,
+        ends_at: row.get(7)?,
+        paused: row.get(8)?,
+        last_run_at: row.get(9)?,
+        last_transaction_id: row.get(10)?,
+        created_at: row.get(11)?,
+    })
+}
+
+fn find_schedule(conn: &Connection, schedule_id: i64) -> rusqlite::Result<Option<Schedule>> {
+    conn.query_row(
+        &format!("SELECT {} FROM schedules WHERE id = ?1", SCHEDULE_COLUMNS),
+        [schedule_id],
+        read_schedule,
+    )
+    .optional()
+}
+
+fn read_shared_action(row: &Row) -> rusqlite::Result<SharedAction> {
+    Ok(SharedAction {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        action: row.get(2)?,
+        parameters: row.get(3)?,
+        user_id: row.get(4)?,
+        created_at: row.get(5)?,
+    })
+}
+
+fn find_chat_language(conn: &Connection, chat_id: &str) -> rusqlite::Result<Option<ChatLanguage>> {
+    conn.query_row(
+        "SELECT chat_id, language, updated_by, updated_at FROM chat_languages WHERE chat_id = ?1",
+        [chat_id],
+        |row| {
+            Ok(ChatLanguage {
+                chat_id: row.get(0)?,
+                language: row.get(1)?,
+                updated_by: row.get(2)?,
+                updated_at: row.get(3)?,
+            })
+        },
+    )
+    .optional()
+}
+
+fn find_wallet_link(conn: &Connection, user_id: &str) -> rusqlite::Result<Option<WalletLink>> {
+    conn.query_row(
+        "SELECT user_id, pubkey, signature, linked_at FROM wallet_links WHERE user_id = ?1",
+        [user_id],
+        |row| {
+            Ok(WalletLink {
+                user_id: row.get(0)?,
+                pubkey: row.get(1)?,
+                signature: row.get(2)?,
+                linked_at: row.get(3)?,
+            })
+        },
+    )
+    .optional()
+}
+
+fn insert_event(
+    conn: &Connection,
+    transaction_id: i64,
+    kind: &str,
+    user_id: Option<&str>,
+    signature: Option<&str>,
+) -> rusqlite::Result<TransactionEvent> {
+    let created_at = now();
+    conn.execute(
+        "INSERT INTO events (transaction_id, kind, user_id, signature, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+        (transaction_id, kind, user_id, signature, created_at),
+    )?;
+
+    Ok(TransactionEvent {
+        id: conn.last_insert_rowid(),
+        transaction_id,
+        kind: kind.to_string(),
+        user_id: user_id.map(str::to_string),
+        signature: signature.map(str::to_string),
+        created_at,
+    })
+}
+
+fn find_transaction(
+    conn: &Connection,
+    transaction_id: i64,
+) -> rusqlite::Result<Option<Transaction>> {
+    conn.query_row(
+        &format!("SELECT {} FROM entries WHERE id = ?1", TRANSACTION_COLUMNS),
+        [transaction_id],
+        read_transaction,
+    )
+    .optional()
+}
+
 async fn create_transaction(
     State(state): State<Arc<AppState>>,
     Json(body): Json<CreateTransactionBody>,
 ) -> AxumResult<AxumJson<Transaction>> {
     let conn = state.conn.lock().await;
+    let created_at = now();
     let result = conn.execute(
-        "INSERT INTO entries (transaction_index, user_id, signature, status, message_id) VALUES (?1, ?2, ?3, ?4, ?5)",
-        (&body.transaction_index, &body.user_id, &body.signature, 0, None::<String>),
+        "INSERT INTO entries (transaction_index, user_id, signature, status, message_id, created_at, title, description, parameters, batch_id) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        (&body.transaction_index, &body.user_id, &body.signature, 0, None::<String>, created_at, &body.title, &body.description, &body.parameters, &body.batch_id),
     );
 
     if let Err(e) = result {
         return Err((
-            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            StatusCode::INTERNAL_SERVER_ERROR,
             format!("Error inserting transaction: {}", e),
         ).into());
     }
 
     let id = conn.last_insert_rowid();
+
+    if let Err(e) = insert_event(&conn, id, "create", Some(&body.user_id), Some(&body.signature)) {
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error inserting transaction event: {}", e),
+        ).into());
+    }
+
     Ok(AxumJson(Transaction {
         id,
         transaction_index: body.transaction_index,
@@ -99,30 +744,101 @@ async fn create_transaction(
         signature: body.signature,
         status: 0,
         message_id: None,
+        created_at,
+        state: "active".to_string(),
+        reminder_count: 0,
+        last_reminder_at: None,
+        expired_at: None,
+        title: body.title,
+        description: body.description,
+        parameters: body.parameters,
+        batch_id: body.batch_id,
+        icon_url: None,
     }))
 }
 
+async fn get_transactions(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<GetTransactionsQuery>,
+) -> AxumResult<AxumJson<TransactionPage>> {
+    let mut conditions = vec!["status = 1".to_string()];
+    let mut params: Vec<Value> = vec![];
+
+    if let Some(states) = &query.state {
+        let placeholders: Vec<String> = states
+            .split(',')
+            .map(|value| {
+                params.push(Value::Text(value.trim().to_string()));
+                format!("?{}", params.len())
+            })
+            .collect();
+        conditions.push(format!("state IN ({})", placeholders.join(", ")));
+    }
+
+    if let Some(user_id) = &query.user_id {
+        params.push(Value::Text(user_id.clone()));
+        conditions.push(format!("user_id = ?{}", params.len()));
+    }
+
+    if let Some(batch_id) = &query.batch_id {
+        params.push(Value::Text(batch_id.clone()));
+        conditions.push(format!("batch_id = ?{}", params.len()));
+    }
+
+    if let Some(transaction_index) = query.transaction_index {
+        params.push(Value::Integer(transaction_index));
+        conditions.push(format!("transaction_index = ?{}", params.len()));
+    }
+
+    if let Some(since) = query.since {
+        params.push(Value::Integer(since));
+        conditions.push(format!("created_at >= ?{}", params.len()));
+    }
+
+    if let Some(until) = query.until {
+        params.push(Value::Integer(until));
+        conditions.push(format!("created_at < ?{}", params.len()));
+    }
+
+    let where_clause = conditions.join(" AND ");
+    let limit = query.limit.unwrap_or(PAGE_LIMIT).clamp(1, PAGE_LIMIT);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    let conn = state.conn.lock().await;
+    let total = conn.query_row(
+        &format!("SELECT COUNT(*) FROM entries WHERE {}", where_clause),
+        params_from_iter(params.iter()),
+        |row| row.get::<_, i64>(0),
+    );
+
+    let transactions = conn
+        .prepare(&format!(
+            "SELECT {} FROM entries WHERE {} ORDER BY id DESC LIMIT {} OFFSET {}",
+            TRANSACTION_COLUMNS, where_clause, limit, offset
+        ))
+        .and_then(|mut stmt| {
+            stmt.query_map(params_from_iter(params.iter()), read_transaction)?
+                .collect::<rusqlite::Result<Vec<Transaction>>>()
+        });
+
+    match (total, transactions) {
+        (Ok(total), Ok(transactions)) => Ok(AxumJson(TransactionPage {
+            transactions,
+            total,
+        })),
+        (Err(e), _) | (_, Err(e)) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching transactions: {}", e),
+        ).into()),
+    }
+}
+
 async fn get_transaction(
     State(state): State<Arc<AppState>>,
-    Path(transaction_id): Path<i32>,
+    Path(transaction_id): Path<i64>,
 ) -> AxumJson<Option<Transaction>> {
     let conn = state.conn.lock().await;
-    let mut stmt = conn
-        .prepare("SELECT id, transaction_index, user_id, signature, status, message_id FROM entries WHERE id = ?1")
-        .unwrap_or_else(|_| panic!("Failed to prepare query"));
-
-    let transaction = stmt
-        .query_row([transaction_id], |row| {
-            Ok(Transaction {
-                id: row.get(0)?,
-                transaction_index: row.get(1)?,
-                user_id: row.get(2)?,
-                signature: row.get(3)?,
-                status: row.get(4)?,
-                message_id: row.get(5)?,
-            })
-        })
-        .optional()
+    let transaction = find_transaction(&conn, transaction_id)
         .unwrap_or_else(|_| panic!("Failed to fetch transaction"));
 
     AxumJson(transaction)
@@ -147,25 +863,874 @@ async fn update_transaction(
             signature: "Error".to_string(),
             status: 0,
             message_id: Some(format!("Failed: {}", e)),
+            created_at: 0,
+            state: "Error".to_string(),
+            reminder_count: 0,
+            last_reminder_at: None,
+            expired_at: None,
+            title: None,
+            description: None,
+            parameters: None,
+            batch_id: None,
+            icon_url: None,
         });
     }
 
+    let transaction = find_transaction(&conn, transaction_id)
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| panic!("Failed to fetch updated transaction"));
+
+    AxumJson(transaction)
+}
+
+async fn get_open_transactions(
+    State(state): State<Arc<AppState>>,
+) -> AxumResult<AxumJson<Vec<Transaction>>> {
+    let conn = state.conn.lock().await;
     let mut stmt = conn
-        .prepare("SELECT id, transaction_index, user_id, signature, status, message_id FROM entries WHERE id = ?1")
+        .prepare(&format!(
+            "SELECT {} FROM entries WHERE status = 1 AND state IN ('active', 'execute_ready') ORDER BY id",
+            TRANSACTION_COLUMNS
+        ))
         .unwrap_or_else(|_| panic!("Failed to prepare query"));
 
-    let transaction = stmt
-        .query_row([transaction_id], |row| {
-            Ok(Transaction {
-                id: row.get(0)?,
-                transaction_index: row.get(1)?,
-                user_id: row.get(2)?,
-                signature: row.get(3)?,
-                status: row.get(4)?,
-                message_id: row.get(5)?,
-            })
-        })
-        .unwrap_or_else(|_| panic!("Failed to fetch updated transaction"));
+    let transactions = stmt
+        .query_map([], read_transaction)
+        .and_then(|rows| rows.collect::<rusqlite::Result<Vec<Transaction>>>());
 
-    AxumJson(transaction)
+    match transactions {
+        Ok(transactions) => Ok(AxumJson(transactions)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching open transactions: {}", e),
+        ).into()),
+    }
+}
+
+async fn update_transaction_state(
+    State(state): State<Arc<AppState>>,
+    Path(transaction_id): Path<i64>,
+    Json(body): Json<UpdateTransactionStateBody>,
+) -> AxumResult<AxumJson<Transaction>> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute(
+        "UPDATE entries SET state = ?1 WHERE id = ?2",
+        (&body.state, &transaction_id),
+    );
+
+    Ok(transaction_after_update(&conn, transaction_id, result)?)
+}
+
+async fn update_transaction_icon(
+    State(state): State<Arc<AppState>>,
+    Path(transaction_id): Path<i64>,
+    Json(body): Json<UpdateTransactionIconBody>,
+) -> AxumResult<AxumJson<Transaction>> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute(
+        "UPDATE entries SET icon_url = ?1 WHERE id = ?2",
+        (&body.icon_url, &transaction_id),
+    );
+
+    Ok(transaction_after_update(&conn, transaction_id, result)?)
+}
+
+async fn create_transaction_reminder(
+    State(state): State<Arc<AppState>>,
+    Path(transaction_id): Path<i64>,
+) -> AxumResult<AxumJson<Transaction>> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute(
+        "UPDATE entries SET reminder_count = reminder_count + 1, last_reminder_at = ?1 WHERE id = ?2",
+        (now(), &transaction_id),
+    );
+
+    Ok(transaction_after_update(&conn, transaction_id, result)?)
+}
+
+async fn expire_transaction(
+    State(state): State<Arc<AppState>>,
+    Path(transaction_id): Path<i64>,
+) -> AxumResult<AxumJson<Transaction>> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute(
+        "UPDATE entries SET expired_at = COALESCE(expired_at, ?1) WHERE id = ?2",
+        (now(), &transaction_id),
+    );
+
+    Ok(transaction_after_update(&conn, transaction_id, result)?)
+}
+
+async fn get_transaction_events(
+    State(state): State<Arc<AppState>>,
+    Path(transaction_id): Path<i64>,
+) -> AxumResult<AxumJson<Vec<TransactionEvent>>> {
+    let conn = state.conn.lock().await;
+    let events = conn
+        .prepare(&format!(
+            "SELECT {} FROM events WHERE transaction_id = ?1 ORDER BY id",
+            EVENT_COLUMNS
+        ))
+        .and_then(|mut stmt| {
+            stmt.query_map([transaction_id], read_event)?
+                .collect::<rusqlite::Result<Vec<TransactionEvent>>>()
+        });
+
+    match events {
+        Ok(events) => Ok(AxumJson(events)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching transaction events: {}", e),
+        ).into()),
+    }
+}
+
+async fn create_transaction_event(
+    State(state): State<Arc<AppState>>,
+    Path(transaction_id): Path<i64>,
+    Json(body): Json<CreateTransactionEventBody>,
+) -> AxumResult<AxumJson<TransactionEvent>> {
+    let conn = state.conn.lock().await;
+    let event = insert_event(
+        &conn,
+        transaction_id,
+        &body.kind,
+        body.user_id.as_deref(),
+        body.signature.as_deref(),
+    );
+
+    match event {
+        Ok(event) => Ok(AxumJson(event)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error inserting transaction event: {}", e),
+        ).into()),
+    }
+}
+
+async fn create_transfers(
+    State(state): State<Arc<AppState>>,
+    Path(transaction_id): Path<i64>,
+    Json(body): Json<Vec<CreateTransferBody>>,
+) -> AxumResult<AxumJson<Vec<Transfer>>> {
+    let conn = state.conn.lock().await;
+    let created_at = now();
+    let mut transfers = vec![];
+
+    for transfer in body {
+        let Ok(base_amount) = i64::try_from(transfer.base_amount) else {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("Transfer amount {} is too large", transfer.base_amount),
+            ).into());
+        };
+        let amount = transfer.base_amount as f64 / 10f64.powi(i32::from(transfer.decimals));
+        let decimals = i64::from(transfer.decimals);
+        let result = conn.execute(
+            "INSERT INTO transfers (transaction_id, token, recipient, amount, base_amount, decimals, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            (transaction_id, &transfer.token, &transfer.recipient, amount, base_amount, decimals, created_at),
+        );
+
+        if let Err(e) = result {
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Error inserting transfer: {}", e),
+            ).into());
+        }
+
+        transfers.push(Transfer {
+            id: conn.last_insert_rowid(),
+            transaction_id,
+            token: transfer.token,
+            recipient: transfer.recipient,
+            amount,
+            base_amount: Some(base_amount),
+            decimals: Some(decimals),
+            created_at,
+            executed_at: None,
+        });
+    }
+
+    Ok(AxumJson(transfers))
+}
+
+async fn get_transfers(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<GetTransfersQuery>,
+) -> AxumResult<AxumJson<Vec<Transfer>>> {
+    let mut conditions = vec![];
+    let mut params: Vec<Value> = vec![];
+
+    if let Some(transaction_id) = query.transaction_id {
+        params.push(Value::Integer(transaction_id));
+        conditions.push(format!("t.transaction_id = ?{}", params.len()));
+    }
+
+    if let Some(token) = &query.token {
+        params.push(Value::Text(token.clone()));
+        conditions.push(format!("t.token = ?{}", params.len()));
+    }
+
+    if let Some(recipient) = &query.recipient {
+        params.push(Value::Text(recipient.clone()));
+        conditions.push(format!("t.recipient = ?{}", params.len()));
+    }
+
+    if let Some(since) = query.since {
+        params.push(Value::Integer(since));
+        conditions.push(format!("e.created_at >= ?{}", params.len()));
+    }
+
+    if query.executed == Some(true) || query.since.is_some() {
+        conditions.push("e.created_at IS NOT NULL".to_string());
+    }
+
+    let where_clause = if conditions.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", conditions.join(" AND "))
+    };
+
+    let conn = state.conn.lock().await;
+    let transfers = conn
+        .prepare(&format!(
+            "SELECT t.id, t.transaction_id, t.token, t.recipient, t.amount, t.created_at, e.created_at,
+                 t.base_amount, t.decimals
+             FROM transfers t
+             LEFT JOIN (SELECT transaction_id, MIN(created_at) AS created_at FROM events WHERE kind = 'execute' GROUP BY transaction_id) e
+             ON e.transaction_id = t.transaction_id
+             {} ORDER BY t.id",
+            where_clause
+        ))
+        .and_then(|mut stmt| {
+            stmt.query_map(params_from_iter(params.iter()), |row| {
+                Ok(Transfer {
+                    id: row.get(0)?,
+                    transaction_id: row.get(1)?,
+                    token: row.get(2)?,
+                    recipient: row.get(3)?,
+                    amount: row.get(4)?,
+                    base_amount: row.get(7)?,
+                    decimals: row.get(8)?,
+                    created_at: row.get(5)?,
+                    executed_at: row.get(6)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<Transfer>>>()
+        });
+
+    match transfers {
+        Ok(transfers) => Ok(AxumJson(transfers)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching transfers: {}", e),
+        ).into()),
+    }
+}
+
+async fn get_users(State(state): State<Arc<AppState>>) -> AxumResult<AxumJson<Vec<User>>> {
+    let conn = state.conn.lock().await;
+    let users = conn
+        .prepare(&format!(
+            "SELECT {} FROM users LEFT JOIN wallet_links ON wallet_links.user_id = users.user_id",
+            USER_COLUMNS
+        ))
+        .and_then(|mut stmt| {
+            stmt.query_map([], read_user)?
+                .collect::<rusqlite::Result<Vec<User>>>()
+        });
+
+    match users {
+        Ok(users) => Ok(AxumJson(users)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching users: {}", e),
+        ).into()),
+    }
+}
+
+async fn upsert_user(
+    State(state): State<Arc<AppState>>,
+    Path(user_id): Path<String>,
+    Json(body): Json<UpsertUserBody>,
+) -> AxumResult<AxumJson<User>> {
+    let conn = state.conn.lock().await;
+    let updated_at = now();
+    let result = conn.execute(
+        "INSERT INTO users (user_id, name, pubkey, updated_at) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT (user_id) DO UPDATE SET name = ?2, pubkey = COALESCE(?3, pubkey), updated_at = ?4",
+        (&user_id, &body.name, &body.pubkey, updated_at),
+    );
+
+    if let Err(e) = result {
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error saving user: {}", e),
+        ).into());
+    }
+
+    let user = conn.query_row(
+        &format!(
+            "SELECT {} FROM users LEFT JOIN wallet_links ON wallet_links.user_id = users.user_id \
+             WHERE users.user_id = ?1",
+            USER_COLUMNS
+        ),
+        [&user_id],
+        read_user,
+    );
+
+    match user {
+        Ok(user) => Ok(AxumJson(user)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching user: {}", e),
+        ).into()),
+    }
+}
+
+async fn get_wallet_link(
+    State(state): State<Arc<AppState>>,
+    Path(user_id): Path<String>,
+) -> AxumResult<AxumJson<Option<WalletLink>>> {
+    let conn = state.conn.lock().await;
+
+    match find_wallet_link(&conn, &user_id) {
+        Ok(link) => Ok(AxumJson(link)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching wallet link: {}", e),
+        ).into()),
+    }
+}
+
+/// The bot verifies the signature before calling this. A wallet can only be linked to
+/// one user, so proving ownership again moves it to the new user.
+async fn link_wallet(
+    State(state): State<Arc<AppState>>,
+    Path(user_id): Path<String>,
+    Json(body): Json<LinkWalletBody>,
+) -> AxumResult<AxumJson<WalletLink>> {
+    let conn = state.conn.lock().await;
+    let result = conn
+        .execute(
+            "DELETE FROM wallet_links WHERE pubkey = ?1 AND user_id != ?2",
+            (&body.pubkey, &user_id),
+        )
+        .and_then(|_| {
+            conn.execute(
+                "INSERT INTO wallet_links (user_id, pubkey, signature, linked_at) VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT (user_id) DO UPDATE SET pubkey = ?2, signature = ?3, linked_at = ?4",
+                (&user_id, &body.pubkey, &body.signature, now()),
+            )
+        });
+
+    if let Err(e) = result {
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error linking wallet: {}", e),
+        ).into());
+    }
+
+    match find_wallet_link(&conn, &user_id) {
+        Ok(Some(link)) => Ok(AxumJson(link)),
+        Ok(None) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Wallet link disappeared".to_string(),
+        ).into()),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching wallet link: {}", e),
+        ).into()),
+    }
+}
+
+async fn get_roles(
+    State(state): State<Arc<AppState>>,
+    Path(chat_id): Path<String>,
+    Query(query): Query<GetRolesQuery>,
+) -> AxumResult<AxumJson<Vec<RoleGrant>>> {
+    let conn = state.conn.lock().await;
+    let roles = conn
+        .prepare(
+            "SELECT chat_id, user_id, role, granted_by, created_at FROM roles
+             WHERE chat_id = ?1 AND (?2 IS NULL OR user_id = ?2) ORDER BY user_id, role",
+        )
+        .and_then(|mut stmt| {
+            stmt.query_map((&chat_id, &query.user_id), read_role)?
+                .collect::<rusqlite::Result<Vec<RoleGrant>>>()
+        });
+
+    match roles {
+        Ok(roles) => Ok(AxumJson(roles)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching roles: {}", e),
+        ).into()),
+    }
+}
+
+async fn grant_role(
+    State(state): State<Arc<AppState>>,
+    Path((chat_id, user_id, role)): Path<(String, String, String)>,
+    Json(body): Json<GrantRoleBody>,
+) -> AxumResult<AxumJson<RoleGrant>> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute(
+        "INSERT OR IGNORE INTO roles (chat_id, user_id, role, granted_by, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+        (&chat_id, &user_id, &role, &body.granted_by, now()),
+    );
+
+    if let Err(e) = result {
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error granting role: {}", e),
+        ).into());
+    }
+
+    let grant = conn.query_row(
+        "SELECT chat_id, user_id, role, granted_by, created_at FROM roles
+         WHERE chat_id = ?1 AND user_id = ?2 AND role = ?3",
+        (&chat_id, &user_id, &role),
+        read_role,
+    );
+
+    match grant {
+        Ok(grant) => Ok(AxumJson(grant)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching role: {}", e),
+        ).into()),
+    }
+}
+
+async fn revoke_role(
+    State(state): State<Arc<AppState>>,
+    Path((chat_id, user_id, role)): Path<(String, String, String)>,
+) -> AxumResult<StatusCode> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute(
+        "DELETE FROM roles WHERE chat_id = ?1 AND user_id = ?2 AND role = ?3",
+        (&chat_id, &user_id, &role),
+    );
+
+    match result {
+        Ok(0) => Ok(StatusCode::NOT_FOUND),
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error revoking role: {}", e),
+        ).into()),
+    }
+}
+
+async fn get_addresses(
+    State(state): State<Arc<AppState>>,
+    Path(chat_id): Path<String>,
+) -> AxumResult<AxumJson<Vec<AddressBookEntry>>> {
+    let conn = state.conn.lock().await;
+    let addresses = conn
+        .prepare(
+            "SELECT chat_id, address, label, added_by, created_at FROM addresses
+             WHERE chat_id = ?1 ORDER BY label COLLATE NOCASE",
+        )
+        .and_then(|mut stmt| {
+            stmt.query_map([&chat_id], read_address)?
+                .collect::<rusqlite::Result<Vec<AddressBookEntry>>>()
+        });
+
+    match addresses {
+        Ok(addresses) => Ok(AxumJson(addresses)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching addresses: {}", e),
+        ).into()),
+    }
+}
+
+/// Adds the address or renames it if it is already saved
+async fn save_address(
+    State(state): State<Arc<AppState>>,
+    Path((chat_id, address)): Path<(String, String)>,
+    Json(body): Json<SaveAddressBody>,
+) -> AxumResult<AxumJson<AddressBookEntry>> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute(
+        "INSERT INTO addresses (chat_id, address, label, added_by, created_at) VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT (chat_id, address) DO UPDATE SET label = ?3",
+        (&chat_id, &address, &body.label, &body.added_by, now()),
+    );
+
+    if let Err(e) = result {
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error saving address: {}", e),
+        ).into());
+    }
+
+    let entry = conn.query_row(
+        "SELECT chat_id, address, label, added_by, created_at FROM addresses
+         WHERE chat_id = ?1 AND address = ?2",
+        (&chat_id, &address),
+        read_address,
+    );
+
+    match entry {
+        Ok(entry) => Ok(AxumJson(entry)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching address: {}", e),
+        ).into()),
+    }
+}
+
+async fn remove_address(
+    State(state): State<Arc<AppState>>,
+    Path((chat_id, address)): Path<(String, String)>,
+) -> AxumResult<StatusCode> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute(
+        "DELETE FROM addresses WHERE chat_id = ?1 AND address = ?2",
+        (&chat_id, &address),
+    );
+
+    match result {
+        Ok(0) => Ok(StatusCode::NOT_FOUND),
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error removing address: {}", e),
+        ).into()),
+    }
+}
+
+async fn get_chat_language(
+    State(state): State<Arc<AppState>>,
+    Path(chat_id): Path<String>,
+) -> AxumResult<AxumJson<Option<ChatLanguage>>> {
+    let conn = state.conn.lock().await;
+
+    match find_chat_language(&conn, &chat_id) {
+        Ok(language) => Ok(AxumJson(language)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching chat language: {}", e),
+        ).into()),
+    }
+}
+
+async fn set_chat_language(
+    State(state): State<Arc<AppState>>,
+    Path(chat_id): Path<String>,
+    Json(body): Json<SetChatLanguageBody>,
+) -> AxumResult<AxumJson<ChatLanguage>> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute(
+        "INSERT INTO chat_languages (chat_id, language, updated_by, updated_at) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT (chat_id) DO UPDATE SET language = ?2, updated_by = ?3, updated_at = ?4",
+        (&chat_id, &body.language, &body.updated_by, now()),
+    );
+
+    if let Err(e) = result {
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error setting chat language: {}", e),
+        ).into());
+    }
+
+    match find_chat_language(&conn, &chat_id) {
+        Ok(Some(language)) => Ok(AxumJson(language)),
+        Ok(None) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Chat language disappeared".to_string(),
+        ).into()),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching chat language: {}", e),
+        ).into()),
+    }
+}
+
+async fn reset_chat_language(
+    State(state): State<Arc<AppState>>,
+    Path(chat_id): Path<String>,
+) -> AxumResult<StatusCode> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute("DELETE FROM chat_languages WHERE chat_id = ?1", [&chat_id]);
+
+    match result {
+        Ok(0) => Ok(StatusCode::NOT_FOUND),
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error resetting chat language: {}", e),
+        ).into()),
+    }
+}
+
+/// Takes or renews the lease, 409 while another holder has it
+async fn claim_lease(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    Json(body): Json<ClaimLeaseBody>,
+) -> AxumResult<AxumJson<Lease>> {
+    let conn = state.conn.lock().await;
+    let now = now();
+    let expires_at = now + body.ttl;
+    let result = conn.execute(
+        "INSERT INTO leases (name, holder, expires_at) VALUES (?1, ?2, ?3)
+         ON CONFLICT (name) DO UPDATE SET holder = ?2, expires_at = ?3
+         WHERE leases.holder = ?2 OR leases.expires_at <= ?4",
+        (&name, &body.holder, expires_at, now),
+    );
+
+    match result {
+        Ok(0) => Err((
+            StatusCode::CONFLICT,
+            format!("Lease {} is held by another replica", name),
+        ).into()),
+        Ok(_) => Ok(AxumJson(Lease {
+            name,
+            holder: body.holder,
+            expires_at,
+        })),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error claiming lease: {}", e),
+        ).into()),
+    }
+}
+
+async fn release_lease(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    Query(query): Query<ReleaseLeaseQuery>,
+) -> AxumResult<StatusCode> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute(
+        "DELETE FROM leases WHERE name = ?1 AND holder = ?2",
+        (&name, &query.holder),
+    );
+
+    match result {
+        Ok(0) => Ok(StatusCode::NOT_FOUND),
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error releasing lease: {}", e),
+        ).into()),
+    }
+}
+
+async fn get_schedules(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<GetSchedulesQuery>,
+) -> AxumResult<AxumJson<Vec<Schedule>>> {
+    let conn = state.conn.lock().await;
+    let schedules = conn
+        .prepare(&format!(
+            "SELECT {} FROM schedules
+             WHERE (?1 IS NULL OR chat_id = ?1)
+             AND (?2 IS NULL OR (paused = 0 AND next_run_at <= ?2))
+             ORDER BY id",
+            SCHEDULE_COLUMNS
+        ))
+        .and_then(|mut stmt| {
+            stmt.query_map((&query.chat_id, &query.due_before), read_schedule)?
+                .collect::<rusqlite::Result<Vec<Schedule>>>()
+        });
+
+    match schedules {
+        Ok(schedules) => Ok(AxumJson(schedules)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching schedules: {}", e),
+        ).into()),
+    }
+}
+
+async fn create_schedule(
+    State(state): State<Arc<AppState>>,
+    Json(body): Json<CreateScheduleBody>,
+) -> AxumResult<AxumJson<Schedule>> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute(
+        "INSERT INTO schedules (chat_id, user_id, cadence, action, title, next_run_at, ends_at, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        (&body.chat_id, &body.user_id, &body.cadence, &body.action, &body.title, body.next_run_at, body.ends_at, now()),
+    );
+
+    if let Err(e) = result {
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error inserting schedule: {}", e),
+        ).into());
+    }
+
+    Ok(schedule_after_update(&conn, conn.last_insert_rowid(), Ok(1))?)
+}
+
+async fn get_schedule(
+    State(state): State<Arc<AppState>>,
+    Path(schedule_id): Path<i64>,
+) -> AxumResult<AxumJson<Schedule>> {
+    let conn = state.conn.lock().await;
+
+    Ok(schedule_after_update(&conn, schedule_id, Ok(0))?)
+}
+
+/// Pauses or resumes a schedule. Resuming sets the next run again since the missed
+/// ones are skipped.
+async fn update_schedule(
+    State(state): State<Arc<AppState>>,
+    Path(schedule_id): Path<i64>,
+    Json(body): Json<UpdateScheduleBody>,
+) -> AxumResult<AxumJson<Schedule>> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute(
+        "UPDATE schedules SET paused = ?1, next_run_at = ?2 WHERE id = ?3",
+        (body.paused, body.next_run_at, schedule_id),
+    );
+
+    Ok(schedule_after_update(&conn, schedule_id, result)?)
+}
+
+async fn delete_schedule(
+    State(state): State<Arc<AppState>>,
+    Path(schedule_id): Path<i64>,
+) -> AxumResult<StatusCode> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute("DELETE FROM schedules WHERE id = ?1", [schedule_id]);
+
+    match result {
+        Ok(0) => Ok(StatusCode::NOT_FOUND),
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error deleting schedule: {}", e),
+        ).into()),
+    }
+}
+
+/// Records a run of the schedule and when the next one is due, if any
+async fn create_schedule_run(
+    State(state): State<Arc<AppState>>,
+    Path(schedule_id): Path<i64>,
+    Json(body): Json<CreateScheduleRunBody>,
+) -> AxumResult<AxumJson<Schedule>> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute(
+        "UPDATE schedules SET last_run_at = ?1, last_transaction_id = COALESCE(?2, last_transaction_id), next_run_at = ?3 WHERE id = ?4",
+        (now(), body.transaction_id, body.next_run_at, schedule_id),
+    );
+
+    Ok(schedule_after_update(&conn, schedule_id, result)?)
+}
+
+fn schedule_after_update(
+    conn: &Connection,
+    schedule_id: i64,
+    result: rusqlite::Result<usize>,
+) -> Result<AxumJson<Schedule>, (StatusCode, String)> {
+    if let Err(e) = result {
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error updating schedule: {}", e),
+        ));
+    }
+
+    match find_schedule(conn, schedule_id) {
+        Ok(Some(schedule)) => Ok(AxumJson(schedule)),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            format!("Schedule {} not found", schedule_id),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching schedule: {}", e),
+        )),
+    }
+}
+
+/// Inline queries repeat while the user types, sharing the same action and presets
+/// again returns the existing row so the deep links stay stable.
+async fn create_shared_action(
+    State(state): State<Arc<AppState>>,
+    Json(body): Json<CreateSharedActionBody>,
+) -> AxumResult<AxumJson<SharedAction>> {
+    let conn = state.conn.lock().await;
+    let result = conn.execute(
+        "INSERT OR IGNORE INTO shared_actions (url, action, parameters, user_id, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+        (&body.url, &body.action, &body.parameters, &body.user_id, now()),
+    );
+
+    if let Err(e) = result {
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error inserting shared action: {}", e),
+        ).into());
+    }
+
+    match conn.query_row(
+        "SELECT id, url, action, parameters, user_id, created_at FROM shared_actions WHERE url = ?1 AND action = ?2 AND parameters = ?3",
+        (&body.url, &body.action, &body.parameters),
+        read_shared_action,
+    ) {
+        Ok(shared_action) => Ok(AxumJson(shared_action)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching shared action: {}", e),
+        ).into()),
+    }
+}
+
+async fn get_shared_action(
+    State(state): State<Arc<AppState>>,
+    Path(shared_action_id): Path<i64>,
+) -> AxumResult<AxumJson<SharedAction>> {
+    let conn = state.conn.lock().await;
+
+    match conn
+        .query_row(
+            "SELECT id, url, action, parameters, user_id, created_at FROM shared_actions WHERE id = ?1",
+            [shared_action_id],
+            read_shared_action,
+        )
+        .optional()
+    {
+        Ok(Some(shared_action)) => Ok(AxumJson(shared_action)),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            format!("Shared action {} not found", shared_action_id),
+        ).into()),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching shared action: {}", e),
+        ).into()),
+    }
+}
+
+fn transaction_after_update(
+    conn: &Connection,
+    transaction_id: i64,
+    result: rusqlite::Result<usize>,
+) -> Result<AxumJson<Transaction>, (StatusCode, String)> {
+    if let Err(e) = result {
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error updating transaction: {}", e),
+        ));
+    }
+
+    match find_transaction(conn, transaction_id) {
+        Ok(Some(transaction)) => Ok(AxumJson(transaction)),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            format!("Transaction {} not found", transaction_id),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Error fetching transaction: {}", e),
+        )),
+    }
 }